@@ -2,7 +2,7 @@ use heck::{ToPascalCase, ToSnakeCase};
 use itertools::Itertools;
 use regex::Regex;
 use serde::{Deserialize, Serialize};
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashSet};
 use std::fs::{self, File};
 use std::io::Write;
 
@@ -23,14 +23,16 @@ fn generate_enum(
     writeln!(f)?;
     writeln!(
         f,
-        "#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, Copy)]"
+        "#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]"
     )?;
     writeln!(f, "#[serde(rename_all = \"snake_case\")]")?;
     writeln!(f, "#[repr(u16)]")?;
     writeln!(f, "pub enum {} {{", enum_name)?;
 
     for variant in variants.sorted() {
-        if variant.chars().any(|c| c.is_ascii_digit()) {
+        if variant.contains('.') {
+            writeln!(f, "\t#[serde(rename = \"{}\")]", variant)?;
+        } else if variant.chars().any(|c| c.is_ascii_digit()) {
             writeln!(
                 f,
                 "\t#[serde(rename = \"{}\")]",
@@ -50,6 +52,11 @@ struct RawEvent {
     id: String,
 }
 
+#[derive(Serialize, Deserialize)]
+struct RawSound {
+    id: String,
+}
+
 #[derive(Serialize, Deserialize)]
 struct RawGameValue {
     id: String,
@@ -68,8 +75,75 @@ struct RawAction {
 
 #[derive(Serialize, Deserialize)]
 struct RawArg {
+    id: String,
     #[serde(rename = "type")]
     arg_type: String,
+    array: Option<u8>,
+}
+
+/// How often actions, value type tags and argument names appear in the corpus modules.
+#[derive(Default)]
+struct Corpus {
+    actions: BTreeMap<String, usize>,
+    tags: BTreeMap<String, usize>,
+    names: BTreeMap<String, usize>,
+}
+
+impl Corpus {
+    fn count(&mut self, json: &serde_json::Value) {
+        match json {
+            serde_json::Value::Object(object) => {
+                if let Some(serde_json::Value::String(action)) = object.get("action") {
+                    *self.actions.entry(action.clone()).or_default() += 1;
+                }
+
+                if let (Some(serde_json::Value::String(name)), Some(value)) =
+                    (object.get("name"), object.get("value"))
+                {
+                    *self.names.entry(name.clone()).or_default() += 1;
+                    self.count_value(value);
+                }
+
+                for value in object.values() {
+                    self.count(value);
+                }
+            }
+            serde_json::Value::Array(values) => {
+                for value in values {
+                    self.count(value);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn count_value(&mut self, value: &serde_json::Value) {
+        if let Some(serde_json::Value::String(tag)) = value.get("type") {
+            *self.tags.entry(tag.clone()).or_default() += 1;
+        }
+
+        if let Some(serde_json::Value::Array(values)) = value.get("values") {
+            for value in values {
+                self.count_value(value);
+            }
+        }
+    }
+}
+
+fn write_counts(
+    f: &mut File,
+    name: &str,
+    counts: &BTreeMap<String, usize>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    writeln!(f)?;
+    writeln!(f, "pub const {name}: &[(&str, usize)] = &[")?;
+
+    for (symbol, count) in counts {
+        writeln!(f, "\t({symbol:?}, {count}),")?;
+    }
+
+    writeln!(f, "];")?;
+    Ok(())
 }
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -104,21 +178,82 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     let mut typs = HashSet::new();
     let mut objs = HashSet::new();
     let mut arg_types = HashSet::new();
+    let mut array_limits = Vec::new();
+    let mut corpus = Corpus::default();
+
+    for entry in fs::read_dir("assets/corpus")? {
+        let module: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(entry?.path())?).unwrap();
+        corpus.count(&module);
+    }
 
     for action in actions {
-        ids.insert(action.id);
-        typs.insert(action.action_type);
-        objs.insert(action.object);
+        // Everything the catalog knows gets a code, even if the corpus never uses it
+        *corpus.actions.entry(action.id.clone()).or_default() += 1;
 
         for arg in action.args {
+            *corpus.names.entry(arg.id.clone()).or_default() += 1;
+
+            if let Some(limit) = arg.array {
+                array_limits.push((action.id.to_pascal_case(), arg.id, limit));
+            }
+
             arg_types.insert(arg.arg_type);
         }
+
+        ids.insert(action.id);
+        typs.insert(action.action_type);
+        objs.insert(action.object);
     }
 
-    generate_enum(&mut f, "ActionIdWants11Bits", ids.into_iter())?;
+    generate_enum(&mut f, "ActionIdWants11Bits", ids.iter().cloned())?;
     generate_enum(&mut f, "ActionType", typs.into_iter())?;
     generate_enum(&mut f, "ActionObject", objs.into_iter())?;
     generate_enum(&mut f, "ArgType", arg_types.into_iter())?;
 
+    writeln!(f)?;
+    writeln!(f, "impl ActionIdWants11Bits {{")?;
+    writeln!(f, "\tpub fn array_limit(self, arg: &str) -> Option<u8> {{")?;
+    writeln!(f, "\t\tmatch (self, arg) {{")?;
+
+    for (action, arg, limit) in array_limits.into_iter().sorted() {
+        writeln!(f, "\t\t\t(Self::{action}, \"{arg}\") => Some({limit}),")?;
+    }
+
+    writeln!(f, "\t\t\t_ => None,")?;
+    writeln!(f, "\t\t}}")?;
+    writeln!(f, "\t}}")?;
+    writeln!(f, "}}")?;
+
+    writeln!(f)?;
+    writeln!(
+        f,
+        "pub const ACTION_COUNTS: &[(ActionIdWants11Bits, usize)] = &["
+    )?;
+
+    for (action, count) in &corpus.actions {
+        if ids.contains(action) {
+            writeln!(
+                f,
+                "\t(ActionIdWants11Bits::{}, {count}),",
+                action.to_pascal_case()
+            )?;
+        }
+    }
+
+    writeln!(f, "];")?;
+
+    write_counts(&mut f, "VALUE_TAG_COUNTS", &corpus.tags)?;
+    write_counts(&mut f, "ARG_NAME_COUNTS", &corpus.names)?;
+
+    let sounds: Vec<RawSound> =
+        serde_json::from_str(&fs::read_to_string("assets/sounds.json")?).unwrap();
+    let bits = (sounds.len() - 1).ilog2() + 1;
+    let name = format!("SoundIdWants{bits}Bits");
+
+    generate_enum(&mut f, &name, sounds.into_iter().map(|sound| sound.id))?;
+    writeln!(f)?;
+    writeln!(f, "pub type SoundId = {name};")?;
+
     Ok(())
 }
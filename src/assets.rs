@@ -1,10 +1,14 @@
-use std::fs::{self};
+use std::{
+    collections::HashMap,
+    fs::{self},
+};
 
 use serde::{Deserialize, Serialize};
 
 use crate::generated::{
-    ActionIdWants11Bits, ActionObject, ActionType, EventId, GameValueId, ValueType,
+    ActionIdWants11Bits, ActionObject, ActionType, ArgType, EventId, GameValueId, ValueType,
 };
+use crate::module::Op;
 
 #[derive(Serialize, Deserialize, Debug)]
 pub struct Event {
@@ -22,20 +26,89 @@ pub struct GameValue {
 #[derive(Serialize, Deserialize, Debug)]
 pub struct Action {
     pub id: ActionIdWants11Bits,
+    pub name: String,
     #[serde(rename = "type")]
     pub action_type: ActionType,
     pub object: ActionObject,
     pub args: Vec<Arg>,
+    #[serde(default)]
+    pub assign: Vec<Arg>,
+    #[serde(default)]
+    pub lambda: Vec<Arg>,
+    #[serde(default)]
+    pub boolean: bool,
+}
+
+impl Action {
+    pub fn arg(&self, id: &str) -> Option<&Arg> {
+        self.args.iter().find(|arg| arg.id == id)
+    }
+
+    /// Whether the op writes the variable passed as `id`, either as a result or as a loop variable.
+    pub fn assigns(&self, id: &str) -> bool {
+        self.assign
+            .iter()
+            .chain(&self.lambda)
+            .any(|arg| arg.id == id)
+    }
 }
 
 #[derive(Serialize, Deserialize, Debug)]
-pub struct Arg;
+pub struct Arg {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub arg_type: ArgType,
+    #[serde(default)]
+    pub values: Vec<String>,
+    pub array: Option<u8>,
+}
 
-pub fn get_assets() -> Result<(Vec<Event>, Vec<GameValue>, Vec<Action>), Box<dyn std::error::Error>>
-{
+pub type Assets = (Vec<Event>, Vec<GameValue>, Vec<Action>);
+
+pub fn get_assets() -> Result<Assets, Box<dyn std::error::Error>> {
     Ok((
         serde_json::from_str(&fs::read_to_string("assets/events.json")?)?,
         serde_json::from_str(&fs::read_to_string("assets/game_values.json")?)?,
         serde_json::from_str(&fs::read_to_string("assets/actions.json")?)?,
     ))
 }
+
+pub struct Catalog {
+    pub events: HashMap<EventId, Event>,
+    pub game_values: HashMap<GameValueId, GameValue>,
+    pub actions: HashMap<ActionIdWants11Bits, Action>,
+}
+
+impl Catalog {
+    pub fn load() -> Result<Self, Box<dyn std::error::Error>> {
+        let (events, game_values, actions) = get_assets()?;
+
+        Ok(Self {
+            events: events.into_iter().map(|event| (event.id, event)).collect(),
+            game_values: game_values
+                .into_iter()
+                .map(|value| (value.id, value))
+                .collect(),
+            actions: actions
+                .into_iter()
+                .map(|action| (action.id, action))
+                .collect(),
+        })
+    }
+
+    pub fn action(&self, id: ActionIdWants11Bits) -> &Action {
+        // Both the enum and the catalog are generated from assets/actions.json
+        &self.actions[&id]
+    }
+
+    /// Finds the action declaring an op argument. Ops like `repeat_while` carry the values of
+    /// their conditional.
+    pub fn arg_owner(&self, op: &Op, id: &str) -> &Action {
+        let action = self.action(op.action);
+
+        match op.conditional {
+            Some(conditional) if action.arg(id).is_none() => self.action(conditional.action),
+            _ => action,
+        }
+    }
+}
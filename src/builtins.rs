@@ -0,0 +1,935 @@
+use std::cmp::Ordering;
+
+use crate::{
+    assets::Action,
+    host::Host,
+    interpreter::{Args, Interpreter, Locals, Val},
+};
+
+/// Longest text `repeat_text` builds, in bytes.
+const MAX_TEXT_LENGTH: usize = 1 << 20;
+
+/// Orders numbers numerically and everything else by its text.
+pub fn compare(a: &Val, b: &Val) -> Ordering {
+    match (a.as_number(), b.as_number()) {
+        (Some(a), Some(b)) => a.total_cmp(&b),
+        _ => a.to_string().cmp(&b.to_string()),
+    }
+}
+
+/// Equality as the game sees it: numbers and their text compare equal.
+pub fn same(a: &Val, b: &Val) -> bool {
+    match (a, b) {
+        (Val::Number(a), Val::Number(b)) => a == b,
+        (Val::Number(_), Val::Text(_)) | (Val::Text(_), Val::Number(_)) => {
+            a.as_number() == b.as_number()
+        }
+        _ => a == b,
+    }
+}
+
+fn index(list_len: usize, index: f64) -> Option<usize> {
+    (index >= 0.0 && (index as usize) < list_len).then_some(index as usize)
+}
+
+fn vector([x, y, z]: [f64; 3]) -> Val {
+    Val::Vector { x, y, z }
+}
+
+fn location([x, y, z, yaw, pitch]: [f64; 5]) -> Val {
+    Val::Location {
+        x,
+        y,
+        z,
+        yaw,
+        pitch,
+    }
+}
+
+fn length([x, y, z]: [f64; 3]) -> f64 {
+    (x * x + y * y + z * z).sqrt()
+}
+
+fn fold(args: &Args, f: impl Fn(f64, f64) -> f64) -> Result<Option<Val>, String> {
+    let numbers = args.numbers("value")?;
+    Ok(numbers.into_iter().reduce(f).map(Val::Number))
+}
+
+/// Digits only go up to `z`.
+fn radix(args: &Args) -> Result<u32, String> {
+    match args.number_or("radix", 10.0)? {
+        radix if (2.0..=36.0).contains(&radix) && radix.fract() == 0.0 => Ok(radix as u32),
+        radix => Err(format!(
+            "radix should be a whole number from 2 to 36, got {radix}"
+        )),
+    }
+}
+
+fn angle(args: &Args, value: f64, inverse: bool) -> f64 {
+    let degrees = args.choice("input", "Degrees") == "Degrees";
+
+    match (degrees, inverse) {
+        (true, false) => value.to_radians(),
+        (true, true) => value.to_degrees(),
+        _ => value,
+    }
+}
+
+fn case_insensitive(args: &Args, text: String) -> String {
+    match args.flag("ignore_case") {
+        true => text.to_lowercase(),
+        false => text,
+    }
+}
+
+impl<H: Host> Interpreter<'_, H> {
+    /// Runs a `set_variable` action and writes its results.
+    pub fn variable_action(
+        &mut self,
+        action: &Action,
+        args: &Args,
+        locals: &mut Locals,
+    ) -> Result<(), String> {
+        let result = match action.name.as_str() {
+            "dummy" => return Ok(()),
+            "remove_list_value_at_index" => {
+                let mut list = args.list("list");
+                let removed = index(list.len(), args.number("index")?).map(|i| list.remove(i));
+                self.assign(args, "removed_value", removed, locals);
+                Some(Val::List(list))
+            }
+            "remove_map_entry" => {
+                let mut map = args.map("map")?;
+                let key = args.value("key")?;
+                let values = args.list("values");
+                let removed = map
+                    .iter()
+                    .position(|(k, v)| {
+                        same(k, key) && (values.is_empty() || values.iter().any(|x| same(x, v)))
+                    })
+                    .map(|i| map.remove(i).1);
+                self.assign(args, "removed_value", removed, locals);
+                Some(Val::Map(map))
+            }
+            "get_all_coordinates" => {
+                let coordinates = args.location("location")?;
+
+                for (name, value) in ["x", "y", "z", "yaw", "pitch"].into_iter().zip(coordinates) {
+                    self.assign(args, name, Some(Val::Number(value)), locals);
+                }
+
+                return Ok(());
+            }
+            "clear_map" => {
+                self.assign(args, "map", Some(Val::Map(Vec::new())), locals);
+                return Ok(());
+            }
+            "purge" => return Err("purging variables is not supported".to_string()),
+            name => self.compute(name, args)?,
+        };
+
+        self.assign(args, "variable", result, locals);
+        Ok(())
+    }
+
+    fn assign(&mut self, args: &Args, name: &str, value: Option<Val>, locals: &mut Locals) {
+        if let Some(target) = args.targets.get(name) {
+            self.set_var(target, value, locals);
+        }
+    }
+
+    /// The value a `set_variable` action assigns to its `variable` argument.
+    pub fn compute(&mut self, name: &str, args: &Args) -> Result<Option<Val>, String> {
+        let number = |value: f64| Ok(Some(Val::Number(value)));
+        let text = |value: String| Ok(Some(Val::Text(value)));
+        let list = |values: Vec<Val>| Ok(Some(Val::List(values)));
+
+        match name {
+            "set_value" => Ok(args.get("value").cloned()),
+
+            // Numbers
+            "add" => fold(args, |a, b| a + b),
+            "subtract" => fold(args, |a, b| a - b),
+            "multiply" => fold(args, |a, b| a * b),
+            "divide" => {
+                let Some(Val::Number(quotient)) = fold(args, |a, b| a / b)? else {
+                    return Ok(None);
+                };
+
+                number(match args.choice("division_mode", "Default").as_str() {
+                    "Ceil" => quotient.ceil(),
+                    "Floor" => quotient.floor(),
+                    "RoundToInt" => quotient.round(),
+                    _ => quotient,
+                })
+            }
+            "min" => fold(args, f64::min),
+            "max" => fold(args, f64::max),
+            "average" => {
+                let numbers = args.numbers("value")?;
+                number(numbers.iter().sum::<f64>() / numbers.len() as f64)
+            }
+            "increment" => {
+                number(args.number_or("variable", 0.0)? + args.number_or("number", 1.0)?)
+            }
+            "decrement" => {
+                number(args.number_or("variable", 0.0)? - args.number_or("number", 1.0)?)
+            }
+            "absolute" => number(args.number("number")?.abs()),
+            "remainder" => {
+                let (dividend, divisor) = (args.number("dividend")?, args.number("divisor")?);
+
+                number(match args.choice("remainder_mode", "Remainder").as_str() {
+                    "Modulo" => dividend.rem_euclid(divisor),
+                    _ => dividend % divisor,
+                })
+            }
+            "clamp" => {
+                let value = args.number("number")?;
+                number(value.max(args.number("min")?).min(args.number("max")?))
+            }
+            "warp" => {
+                let (min, max) = (args.number("min")?, args.number("max")?);
+                number(min + (args.number("number")? - min).rem_euclid(max - min))
+            }
+            "pow" => number(args.number("base")?.powf(args.number("power")?)),
+            "root" => number(
+                args.number("base")?
+                    .powf(1.0 / args.number_or("root", 2.0)?),
+            ),
+            "log" => number(args.number("number")?.log(args.number_or("base", 10.0)?)),
+            "round" => {
+                let scale = 10f64.powf(args.number_or("precision", 0.0)?);
+                let value = args.number("number")? * scale;
+
+                number(
+                    match args.choice("round_type", "Round").as_str() {
+                        "Ceil" => value.ceil(),
+                        "Floor" => value.floor(),
+                        _ => value.round(),
+                    } / scale,
+                )
+            }
+            "random_number" => {
+                let (min, max) = (args.number("min")?, args.number("max")?);
+
+                match args.flag("integer") {
+                    true => number((min + (self.random() * (max - min + 1.0)).floor()).min(max)),
+                    false => number(min + self.random() * (max - min)),
+                }
+            }
+            "lerp_number" => {
+                let (start, stop) = (args.number("start")?, args.number("stop")?);
+                number(start + (stop - start) * args.number("amount")?)
+            }
+            "map_range" => {
+                let (from_start, from_stop) =
+                    (args.number("from_start")?, args.number("from_stop")?);
+                let (to_start, to_stop) = (args.number("to_start")?, args.number("to_stop")?);
+                let t = (args.number("number")? - from_start) / (from_stop - from_start);
+                number(to_start + (to_stop - to_start) * t)
+            }
+            "sine" | "cosine" | "tangent" => {
+                let value = args.number("number")?;
+                let variant = args.choice("variant", "");
+                // Plain, inverse, hyperbolic and inverse hyperbolic variants
+                let [forward, inverse, hyperbolic, inverse_hyperbolic]: [fn(f64) -> f64; 4] =
+                    match name {
+                        "sine" => [f64::sin, f64::asin, f64::sinh, f64::asinh],
+                        "cosine" => [f64::cos, f64::acos, f64::cosh, f64::acosh],
+                        _ => [f64::tan, f64::atan, f64::tanh, f64::atanh],
+                    };
+
+                number(match variant.as_str() {
+                    "Arcsine" | "Arccosine" | "Arctangent" => angle(args, inverse(value), true),
+                    "HyperbolicSine" | "HyperbolicCosine" | "HyperbolicTangent" => {
+                        hyperbolic(value)
+                    }
+                    "HyperbolicArcsine" | "HyperbolicArccosine" | "HyperbolicArctangent" => {
+                        inverse_hyperbolic(value)
+                    }
+                    _ => forward(angle(args, value, false)),
+                })
+            }
+            "atan2" => number(angle(
+                args,
+                args.number("y")?.atan2(args.number("x")?),
+                true,
+            )),
+
+            // Lists
+            "create_list" => list(args.list("values")),
+            "append_value" => {
+                let mut values = args.list("variable");
+                values.extend(args.list("values"));
+                list(values)
+            }
+            "append_list" => {
+                let mut values = args.list("list_1");
+                values.extend(args.list("list_2"));
+                list(values)
+            }
+            "get_list_length" => number(args.list("list").len() as f64),
+            "get_list_value" => {
+                let values = args.list("list");
+                Ok(index(values.len(), args.number("number")?)
+                    .map(|i| values[i].clone())
+                    .or_else(|| args.get("default_value").cloned()))
+            }
+            "set_list_value" | "insert_list_value" => {
+                let mut values = args.list("list");
+                let at = args.number("number")?;
+                let value = args.value("value")?.clone();
+
+                match (name, index(values.len() + 1, at)) {
+                    ("set_list_value", Some(i)) if i < values.len() => values[i] = value,
+                    ("insert_list_value", Some(i)) => values.insert(i, value),
+                    _ => return Err(format!("index {at} is out of bounds")),
+                }
+
+                list(values)
+            }
+            "get_list_index_of_value" => {
+                let values = args.list("list");
+                let value = args.value("value")?;
+                let mut found = values.iter().enumerate().filter(|(_, x)| same(x, value));
+                let found = match args.choice("search_mode", "First").as_str() {
+                    "Last" => found.next_back(),
+                    _ => found.next(),
+                };
+
+                number(found.map_or(-1.0, |(i, _)| i as f64))
+            }
+            "get_list_random_value" => {
+                let values = args.list("list");
+                let i = (self.random() * values.len() as f64) as usize;
+                Ok(values.get(i).cloned())
+            }
+            "random" => {
+                let values = args.list("values");
+                let i = (self.random() * values.len() as f64) as usize;
+                Ok(values.get(i).cloned())
+            }
+            "remove_list_value" => {
+                let mut values = args.list("list");
+                let value = args.value("value")?;
+                let positions: Vec<usize> = values
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, x)| same(x, value))
+                    .map(|(i, _)| i)
+                    .collect();
+                let remove = match args.choice("remove_mode", "All").as_str() {
+                    "First" => positions.first().copied().into_iter().collect(),
+                    "Last" => positions.last().copied().into_iter().collect(),
+                    _ => positions,
+                };
+
+                for i in remove.into_iter().rev() {
+                    values.remove(i);
+                }
+
+                list(values)
+            }
+            "reverse_list" => list(args.list("list").into_iter().rev().collect()),
+            "sort_list" => {
+                let mut values = args.list("list");
+                values.sort_by(compare);
+
+                if args.choice("sort_mode", "Ascending") == "Descending" {
+                    values.reverse();
+                }
+
+                list(values)
+            }
+            "remove_list_duplicates" => {
+                let mut values: Vec<Val> = Vec::new();
+
+                for value in args.list("list") {
+                    if !values.iter().any(|x| same(x, &value)) {
+                        values.push(value);
+                    }
+                }
+
+                list(values)
+            }
+            "flatten_list" => {
+                fn flatten(values: Vec<Val>, out: &mut Vec<Val>) {
+                    for value in values {
+                        match value {
+                            Val::List(values) => flatten(values, out),
+                            value => out.push(value),
+                        }
+                    }
+                }
+
+                let mut values = Vec::new();
+                flatten(args.list("list"), &mut values);
+                list(values)
+            }
+            "randomize_list_order" => {
+                let mut values = args.list("list");
+
+                for i in (1..values.len()).rev() {
+                    let j = (self.random() * (i + 1) as f64) as usize;
+                    values.swap(i, j.min(i));
+                }
+
+                list(values)
+            }
+
+            // Maps
+            "create_map" | "create_map_from_values" => {
+                let keys = args.list("keys");
+                let values = args.list("values");
+
+                if keys.len() != values.len() {
+                    return Err(format!("{} keys but {} values", keys.len(), values.len()));
+                }
+
+                Ok(Some(Val::Map(keys.into_iter().zip(values).collect())))
+            }
+            "set_map_value" => {
+                let mut map = args.map("map")?;
+                let key = args.value("key")?.clone();
+                let value = args.value("value")?.clone();
+
+                match map.iter_mut().find(|(k, _)| same(k, &key)) {
+                    Some(entry) => entry.1 = value,
+                    None => map.push((key, value)),
+                }
+
+                Ok(Some(Val::Map(map)))
+            }
+            "append_map" => {
+                let mut map = args.map("map")?;
+
+                for (key, value) in args.map("other_map")? {
+                    match map.iter_mut().find(|(k, _)| same(k, &key)) {
+                        Some(entry) => entry.1 = value,
+                        None => map.push((key, value)),
+                    }
+                }
+
+                Ok(Some(Val::Map(map)))
+            }
+            "get_map_value" => {
+                let key = args.value("key")?;
+                Ok(args
+                    .map("map")?
+                    .into_iter()
+                    .find(|(k, _)| same(k, key))
+                    .map(|(_, v)| v)
+                    .or_else(|| args.get("default_value").cloned()))
+            }
+            "get_map_size" => number(args.map("map")?.len() as f64),
+            "get_map_keys" => list(args.map("map")?.into_iter().map(|(k, _)| k).collect()),
+            "get_map_values" => list(args.map("map")?.into_iter().map(|(_, v)| v).collect()),
+            "get_map_key_by_index" | "get_map_value_by_index" => {
+                let map = args.map("map")?;
+                Ok(index(map.len(), args.number("index")?)
+                    .map(|i| match name {
+                        "get_map_key_by_index" => map[i].0.clone(),
+                        _ => map[i].1.clone(),
+                    })
+                    .or_else(|| args.get("default_value").cloned()))
+            }
+            "sort_map" => {
+                let mut map = args.map("map")?;
+
+                match args.choice("sort_type", "Keys").as_str() {
+                    "Values" => map.sort_by(|a, b| compare(&a.1, &b.1)),
+                    _ => map.sort_by(|a, b| compare(&a.0, &b.0)),
+                }
+
+                if args.choice("sort_order", "Ascending") == "Descending" {
+                    map.reverse();
+                }
+
+                Ok(Some(Val::Map(map)))
+            }
+
+            // Text
+            "set_text" => {
+                let parts: Vec<String> = args.list("text").iter().map(Val::to_string).collect();
+
+                text(match args.choice("merging", "Concatenation").as_str() {
+                    "Spaces" => parts.join(" "),
+                    "SeparateLines" => parts.join("\n"),
+                    _ => parts.concat(),
+                })
+            }
+            "join_text" => {
+                let values = args.list("list");
+                let limit = args.number_or("limit", -1.0)?;
+                let shown = match limit >= 0.0 {
+                    true => values.len().min(limit as usize),
+                    false => values.len(),
+                };
+                let mut parts: Vec<String> = values[..shown].iter().map(Val::to_string).collect();
+
+                if shown < values.len() {
+                    parts.push(args.text_or("truncated", "..."));
+                }
+
+                text(format!(
+                    "{}{}{}",
+                    args.text_or("prefix", ""),
+                    parts.join(&args.text_or("separator", ", ")),
+                    args.text_or("postfix", "")
+                ))
+            }
+            "split_text" => {
+                let value = args.text("text")?;
+                let delimiter = args.text_or("delimiter", " ");
+
+                list(
+                    value
+                        .split(delimiter.as_str())
+                        .map(|part| Val::Text(part.to_string()))
+                        .collect(),
+                )
+            }
+            "replace_text" => {
+                let value = args.text("text")?;
+                let (from, to) = (args.text("replace")?, args.text_or("replacement", ""));
+
+                text(match args.choice("first", "Any").as_str() {
+                    "First" => value.replacen(&from, &to, 1),
+                    _ => value.replace(&from, &to),
+                })
+            }
+            "remove_text" => {
+                if args.flag("regex") {
+                    return Err("regular expressions are not supported".to_string());
+                }
+
+                let mut value = args.text("text")?;
+
+                for remove in args.list("remove") {
+                    value = value.replace(&remove.to_string(), "");
+                }
+
+                text(value)
+            }
+            "repeat_text" => {
+                let value = args.text("text")?;
+                let times = args.number("repeat")?.max(0.0);
+
+                if value.len() as f64 * times > MAX_TEXT_LENGTH as f64 {
+                    return Err(format!(
+                        "repeated text would be longer than {MAX_TEXT_LENGTH} bytes"
+                    ));
+                }
+
+                text(value.repeat(times as usize))
+            }
+            "set_text_case" => {
+                let value = args.text("text")?;
+
+                text(match args.choice("case_type", "Upper").as_str() {
+                    "Lower" => value.to_lowercase(),
+                    "Proper" => value
+                        .split(' ')
+                        .map(|word| {
+                            let mut chars = word.chars();
+                            chars.next().map_or_else(String::new, |first| {
+                                first
+                                    .to_uppercase()
+                                    .chain(chars.flat_map(char::to_lowercase))
+                                    .collect()
+                            })
+                        })
+                        .collect::<Vec<_>>()
+                        .join(" "),
+                    "Invert" => value
+                        .chars()
+                        .flat_map(|c| match c.is_uppercase() {
+                            true => c.to_lowercase().collect::<Vec<_>>(),
+                            false => c.to_uppercase().collect(),
+                        })
+                        .collect(),
+                    _ => value.to_uppercase(),
+                })
+            }
+            "get_text_length" => number(args.text("text")?.chars().count() as f64),
+            "strip_text" => {
+                let value = args.text("text")?;
+
+                text(match args.choice("strip_type", "All").as_str() {
+                    "Start" => value.trim_start().to_string(),
+                    "End" => value.trim_end().to_string(),
+                    _ => value.trim().to_string(),
+                })
+            }
+            "get_char_at" => {
+                let at = args.number("index")?;
+                let value = args.text("text")?;
+
+                match index(value.chars().count(), at) {
+                    Some(i) => text(value.chars().nth(i).unwrap().to_string()),
+                    None => Err(format!("index {at} is out of bounds")),
+                }
+            }
+            "get_index_of_subtext" => {
+                let value = args.text("text")?;
+                let subtext = args.text("subtext")?;
+                let chars: Vec<char> = value.chars().collect();
+                let start = args.number_or("start_index", 0.0)?.max(0.0) as usize;
+                let found =
+                    |i: &usize| chars[*i..].iter().collect::<String>().starts_with(&subtext);
+                let mut positions = (start.min(chars.len())..chars.len()).filter(found);
+                let found = match args.choice("search_mode", "First").as_str() {
+                    "Last" => positions.next_back(),
+                    _ => positions.next(),
+                };
+
+                number(found.map_or(-1.0, |i| i as f64))
+            }
+            "convert_number_to_text" => {
+                let value = args.number("number")?;
+
+                match radix(args)? {
+                    10 => text(value.to_string()),
+                    radix => {
+                        let mut digits = Vec::new();
+                        let mut rest = value.abs() as u64;
+
+                        loop {
+                            digits.push(
+                                std::char::from_digit((rest % radix as u64) as u32, radix).unwrap(),
+                            );
+                            rest /= radix as u64;
+
+                            if rest == 0 {
+                                break;
+                            }
+                        }
+
+                        if value < 0.0 {
+                            digits.push('-');
+                        }
+
+                        text(digits.into_iter().rev().collect())
+                    }
+                }
+            }
+            "convert_text_to_number" => {
+                let value = args.text("text")?;
+                let parsed = match radix(args)? {
+                    10 => value.trim().parse().ok(),
+                    radix => i64::from_str_radix(value.trim(), radix)
+                        .ok()
+                        .map(|n| n as f64),
+                };
+
+                Ok(parsed.map(Val::Number))
+            }
+            "char_to_number" => match args.text("char")?.chars().next() {
+                Some(c) => number(c as u32 as f64),
+                None => Err("char is empty".to_string()),
+            },
+            "to_char" => match char::from_u32(args.number("number")? as u32) {
+                Some(c) => text(c.to_string()),
+                None => Err("not a character code".to_string()),
+            },
+
+            // Vectors and locations
+            "set_vector" => Ok(Some(vector([
+                args.number_or("x", 0.0)?,
+                args.number_or("y", 0.0)?,
+                args.number_or("z", 0.0)?,
+            ]))),
+            "add_vectors" | "subtract_vectors" => {
+                let mut vectors = args.list("vectors").into_iter().map(|value| match value {
+                    Val::Vector { x, y, z } => Ok([x, y, z]),
+                    value => Err(format!("vectors should hold vectors, got {value}")),
+                });
+                let mut sum = vectors.next().transpose()?.unwrap_or_default();
+                let sign = if name == "add_vectors" { 1.0 } else { -1.0 };
+
+                for other in vectors {
+                    let other = other?;
+
+                    for axis in 0..3 {
+                        sum[axis] += sign * other[axis];
+                    }
+                }
+
+                Ok(Some(vector(sum)))
+            }
+            "multiply_vector" => {
+                let multiplier = args.number("multiplier")?;
+                Ok(Some(vector(args.vector("vector")?.map(|c| c * multiplier))))
+            }
+            "get_vector_length" => {
+                let length = length(args.vector("vector")?);
+
+                number(match args.choice("length_type", "Length").as_str() {
+                    "LengthSquared" => length * length,
+                    _ => length,
+                })
+            }
+            "set_vector_length" => {
+                let value = args.vector("vector")?;
+                let scale = args.number("length")? / length(value);
+                Ok(Some(vector(value.map(|c| c * scale))))
+            }
+            "vector_dot_product" => {
+                let (a, b) = (args.vector("vector_1")?, args.vector("vector_2")?);
+                number(a[0] * b[0] + a[1] * b[1] + a[2] * b[2])
+            }
+            "vector_cross_product" => {
+                let (a, b) = (args.vector("vector_1")?, args.vector("vector_2")?);
+                Ok(Some(vector([
+                    a[1] * b[2] - a[2] * b[1],
+                    a[2] * b[0] - a[0] * b[2],
+                    a[0] * b[1] - a[1] * b[0],
+                ])))
+            }
+            "get_vector_between_locations" => {
+                let end = args.location("end_location")?;
+                let start = args.location("start_location")?;
+                Ok(Some(vector([
+                    end[0] - start[0],
+                    end[1] - start[1],
+                    end[2] - start[2],
+                ])))
+            }
+            "locations_distance" => {
+                let a = args.location("location_1")?;
+                let b = args.location("location_2")?;
+                let d = [a[0] - b[0], a[1] - b[1], a[2] - b[2]];
+
+                number(match args.choice("type", "ThreeD").as_str() {
+                    "TwoD" => (d[0] * d[0] + d[2] * d[2]).sqrt(),
+                    "Altitude" => d[1].abs(),
+                    _ => length(d),
+                })
+            }
+            "get_coordinate" => {
+                let coordinates = args.location("location")?;
+                let axis = ["X", "Y", "Z", "Yaw", "Pitch"]
+                    .iter()
+                    .position(|axis| *axis == args.choice("type", "X"))
+                    .unwrap_or(0);
+                number(coordinates[axis])
+            }
+            "set_coordinate" | "shift_coordinate" => {
+                let mut coordinates = args.location("location")?;
+                let axis = ["X", "Y", "Z", "Yaw", "Pitch"]
+                    .iter()
+                    .position(|axis| *axis == args.choice("type", "X"))
+                    .unwrap_or(0);
+
+                match name {
+                    "set_coordinate" => coordinates[axis] = args.number("coordinate")?,
+                    _ => coordinates[axis] += args.number("distance")?,
+                }
+
+                Ok(Some(location(coordinates)))
+            }
+            "set_all_coordinates" => Ok(Some(location([
+                args.number_or("x", 0.0)?,
+                args.number_or("y", 0.0)?,
+                args.number_or("z", 0.0)?,
+                args.number_or("yaw", 0.0)?,
+                args.number_or("pitch", 0.0)?,
+            ]))),
+            "shift_all_coordinates" => {
+                let mut coordinates = args.location("location")?;
+
+                for (axis, name) in ["x", "y", "z", "yaw", "pitch"].into_iter().enumerate() {
+                    coordinates[axis] += args.number_or(name, 0.0)?;
+                }
+
+                Ok(Some(location(coordinates)))
+            }
+            "shift_location_on_vector" => {
+                let mut coordinates = args.location("location")?;
+                let shift = args.vector("vector")?;
+                let scale = match args.get("length") {
+                    Some(_) => args.number("length")? / length(shift),
+                    None => 1.0,
+                };
+
+                for axis in 0..3 {
+                    coordinates[axis] += shift[axis] * scale;
+                }
+
+                Ok(Some(location(coordinates)))
+            }
+            "center_location" => {
+                let points: Vec<[f64; 5]> = args
+                    .list("locations")
+                    .into_iter()
+                    .filter_map(|value| match value {
+                        Val::Location {
+                            x,
+                            y,
+                            z,
+                            yaw,
+                            pitch,
+                        } => Some([x, y, z, yaw, pitch]),
+                        _ => None,
+                    })
+                    .collect();
+                let mut center = [0.0; 5];
+
+                for point in &points {
+                    for axis in 0..5 {
+                        center[axis] += point[axis] / points.len() as f64;
+                    }
+                }
+
+                Ok(Some(location(center)))
+            }
+
+            // Items
+            "get_item_amount" => match args.value("item")? {
+                Val::Item { amount, .. } => number(*amount),
+                value => Err(format!("item should be an item, got {value}")),
+            },
+            "set_item_amount" => match args.value("item")? {
+                Val::Item { item, tags, .. } => Ok(Some(Val::Item {
+                    item: item.clone(),
+                    amount: args.number("amount")?,
+                    tags: tags.clone(),
+                })),
+                value => Err(format!("item should be an item, got {value}")),
+            },
+            "get_item_custom_tag" => match args.value("item")? {
+                Val::Item { tags, .. } => {
+                    let tag = args.text("tag_name")?;
+                    Ok(tags
+                        .iter()
+                        .find(|(name, _)| *name == tag)
+                        .map(|(_, value)| Val::Text(value.clone()))
+                        .or_else(|| args.get("default_value").cloned()))
+                }
+                value => Err(format!("item should be an item, got {value}")),
+            },
+            "set_item_custom_tag" => match args.value("item")? {
+                Val::Item { item, amount, tags } => {
+                    let tag = args.text("tag_name")?;
+                    let value = args.text("tag_value")?;
+                    let mut tags = tags.clone();
+
+                    match tags.iter_mut().find(|(name, _)| *name == tag) {
+                        Some(entry) => entry.1 = value,
+                        None => tags.push((tag, value)),
+                    }
+
+                    Ok(Some(Val::Item {
+                        item: item.clone(),
+                        amount: *amount,
+                        tags,
+                    }))
+                }
+                value => Err(format!("item should be an item, got {value}")),
+            },
+
+            name => Err(format!(
+                "variable.{name} is not supported by the interpreter"
+            )),
+        }
+    }
+
+    /// Evaluates an `if_variable` condition.
+    pub fn variable_condition(
+        &mut self,
+        action: &Action,
+        args: &Args,
+        locals: &Locals,
+    ) -> Result<bool, String> {
+        let compare_text = |check: fn(&str, &str) -> bool| -> Result<bool, String> {
+            let value = case_insensitive(args, args.text("value")?);
+            Ok(args
+                .list("compare")
+                .iter()
+                .any(|other| check(&value, &case_insensitive(args, other.to_string()))))
+        };
+        let order = |accept: fn(Ordering) -> bool| -> Result<bool, String> {
+            let value = args.number("value")?;
+            let other = args.number("compare")?;
+            Ok(accept(value.total_cmp(&other)))
+        };
+
+        match action.name.as_str() {
+            "is_dummy" => Ok(false),
+            "equals" | "not_equals" => {
+                let value = args.get("value");
+                let equal = args
+                    .list("compare")
+                    .iter()
+                    .any(|other| value.is_some_and(|value| same(value, other)));
+                Ok(equal == (action.name == "equals"))
+            }
+            "greater" => order(Ordering::is_gt),
+            "greater_or_equals" => order(Ordering::is_ge),
+            "less" => order(Ordering::is_lt),
+            "less_or_equals" => order(Ordering::is_le),
+            "in_range" => {
+                let value = args.value("value")?;
+                Ok(compare(value, args.value("min")?).is_ge()
+                    && compare(value, args.value("max")?).is_le())
+            }
+            "exists" => Ok(args
+                .targets
+                .get("variable")
+                .is_some_and(|(scope, name)| self.var(*scope, name, locals).is_some())),
+            "is_type" => {
+                let kind = args.value("value")?.kind();
+                Ok(args.choice("variable_type", "") == kind)
+            }
+            "list_contains_value" => {
+                let values = args.list("list");
+                let contains = |wanted: &Val| values.iter().any(|value| same(value, wanted));
+                let wanted = args.list("values");
+
+                Ok(match args.choice("check_mode", "Any").as_str() {
+                    "All" => wanted.iter().all(contains),
+                    _ => wanted.iter().any(contains),
+                })
+            }
+            "list_is_empty" => Ok(args.list("list").is_empty()),
+            "list_value_equals" => {
+                let values = args.list("list");
+                let Some(found) = index(values.len(), args.number("index")?).map(|i| &values[i])
+                else {
+                    return Ok(false);
+                };
+                Ok(args.list("values").iter().any(|value| same(found, value)))
+            }
+            "map_has_key" => {
+                let key = args.value("key")?;
+                Ok(args.map("map")?.iter().any(|(k, _)| same(k, key)))
+            }
+            "map_value_equals" => {
+                let key = args.value("key")?;
+                let map = args.map("map")?;
+                let Some((_, found)) = map.iter().find(|(k, _)| same(k, key)) else {
+                    return Ok(false);
+                };
+                Ok(args.list("values").iter().any(|value| same(found, value)))
+            }
+            "text_contains" => compare_text(|value, other| value.contains(other)),
+            "text_starts_with" => compare_text(|value, other| value.starts_with(other)),
+            "text_ends_with" => compare_text(|value, other| value.ends_with(other)),
+            "text_matches" => {
+                if args.flag("regular_expressions") {
+                    return Err("regular expressions are not supported".to_string());
+                }
+
+                let value = case_insensitive(args, args.text("match")?);
+                Ok(args
+                    .list("values")
+                    .iter()
+                    .any(|other| value == case_insensitive(args, other.to_string())))
+            }
+            name => Err(format!(
+                "variable condition {name} is not supported by the interpreter"
+            )),
+        }
+    }
+}
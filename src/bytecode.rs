@@ -1,7 +1,4 @@
-use std::{
-    collections::{HashMap, HashSet},
-    fmt::Display,
-};
+use std::{borrow::Borrow, collections::HashMap, fmt::Display, hash::Hash};
 
 use bitvec::{prelude::*, view::BitView};
 use serde::{
@@ -12,22 +9,133 @@ use serde::{
     },
 };
 
-use crate::module::Module;
+use crate::{
+    generated::{ACTION_COUNTS, ARG_NAME_COUNTS, VALUE_TAG_COUNTS},
+    huffman::{Huffman, LENGTH_BITS},
+    module::Module,
+};
+
+const ACTION_ID: &str = "ActionIdWants11Bits";
+/// Width of the coding tag.
+const CODING_BITS: usize = 2;
+
+/// How action ids, value type tags and argument names are written. Its tag comes right
+/// after the string table, ahead of any stored code lengths.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Coding {
+    /// Action ids in 11 bits, tags and names through the string table.
+    #[default]
+    Fixed,
+    /// Huffman codes built from the corpus at build time.
+    Static,
+    /// Huffman codes built for the module, stored before it.
+    Module,
+}
 
-pub fn compile(module: Module) -> String {
+pub fn compile(module: Module, coding: Coding) -> Result<String, Err> {
     let mut jmb = Jmb::new();
-    module.serialize(&mut jmb).unwrap();
+    jmb.bits
+        .extend_from_bitslice(&(coding as u8).view_bits::<Lsb0>()[..CODING_BITS]);
+
+    match coding {
+        Coding::Fixed => {}
+        Coding::Static => jmb.tables = Some(Tables::builtin()),
+        Coding::Module => {
+            // Dry run, only to count the symbols
+            let mut counter = Jmb::new();
+            module.serialize(&mut counter)?;
+            let tables = Tables::count(&counter.symbols);
+            jmb.write_header(&tables)?;
+            jmb.tables = Some(tables);
+        }
+    }
+
+    module.serialize(&mut jmb)?;
     jmb.finalize();
-    String::from_utf8_lossy(&jmb.bits.into_vec()).to_string()
+    Ok(String::from_utf8_lossy(&jmb.bits.into_vec()).to_string())
 }
 
 pub fn decompile() {
     // todo
 }
 
+/// How often each entropy coded symbol was written.
+#[derive(Debug, Clone, Default)]
+pub struct Symbols {
+    pub actions: HashMap<u32, usize>,
+    pub tags: HashMap<String, usize>,
+    pub names: HashMap<String, usize>,
+}
+
+/// Which table a string is coded with.
+#[derive(Debug, Clone, Copy)]
+enum Table {
+    Tags,
+    Names,
+}
+
+#[derive(Debug, Clone)]
+pub struct Tables {
+    pub actions: Huffman<u32>,
+    pub tags: Huffman<String>,
+    pub names: Huffman<String>,
+}
+
+impl Tables {
+    /// Codes from the build time corpus. Anything it lacks is escaped.
+    pub fn builtin() -> Self {
+        let strings = |counts: &[(&str, usize)]| {
+            Huffman::build(
+                (counts.iter()).map(|&(symbol, count)| (symbol.to_string(), count)),
+                1,
+            )
+        };
+
+        Self {
+            actions: Huffman::build(
+                (ACTION_COUNTS.iter()).map(|&(action, count)| (action as u32, count)),
+                1,
+            ),
+            tags: strings(VALUE_TAG_COUNTS),
+            names: strings(ARG_NAME_COUNTS),
+        }
+    }
+
+    /// Codes for exactly the symbols counted, with no escape.
+    pub fn count(symbols: &Symbols) -> Self {
+        Self {
+            actions: Huffman::build(symbols.actions.clone(), 0),
+            tags: Huffman::build(symbols.tags.clone(), 0),
+            names: Huffman::build(symbols.names.clone(), 0),
+        }
+    }
+
+    fn strings(&self, table: Table) -> &Huffman<String> {
+        match table {
+            Table::Tags => &self.tags,
+            Table::Names => &self.names,
+        }
+    }
+}
+
 pub struct Jmb {
     bits: BitVec<u8, Lsb0>,
     strs: HashMap<String, Vec<usize>>,
+    /// Width of the next sequence length, set by a `*WantsNBits` newtype around it.
+    len_bits: Option<usize>,
+    /// Entropy codes, if they replace the fixed encoding.
+    pub tables: Option<Tables>,
+    pub symbols: Symbols,
+    /// Names of the structs being written, innermost last.
+    structs: Vec<&'static str>,
+    /// Table for the string about to be written, set by the field it's in.
+    table: Option<Table>,
+}
+
+impl Default for Jmb {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl Jmb {
@@ -35,7 +143,46 @@ impl Jmb {
         Self {
             bits: BitVec::new(),
             strs: HashMap::new(),
+            len_bits: None,
+            tables: None,
+            symbols: Symbols::default(),
+            structs: Vec::new(),
+            table: None,
+        }
+    }
+
+    /// Stores the tables' code lengths: for each a 16 bit symbol count, then every
+    /// symbol in canonical order with its length.
+    pub fn write_header(&mut self, tables: &Tables) -> Result<(), Err> {
+        let actions: Vec<_> = tables.actions.symbols().collect();
+        self.write_count(actions.len())?;
+
+        for (&action, len) in actions {
+            let bits = wanted_bits(ACTION_ID).unwrap();
+            self.bits
+                .extend_from_bitslice(&action.view_bits::<Lsb0>()[..bits]);
+            self.bits
+                .extend_from_bitslice(&len.view_bits::<Lsb0>()[..LENGTH_BITS]);
         }
+
+        for table in [&tables.tags, &tables.names] {
+            let symbols: Vec<_> = table.symbols().collect();
+            self.write_count(symbols.len())?;
+
+            for (symbol, len) in symbols {
+                (&mut *self).serialize_str(symbol)?;
+                self.bits
+                    .extend_from_bitslice(&len.view_bits::<Lsb0>()[..LENGTH_BITS]);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn write_count(&mut self, len: usize) -> Result<(), Err> {
+        let len = u16::try_from(len).map_err(|_| Err::LengthOverflow { len, bits: 16 })?;
+        self.bits.extend_from_bitslice(len.view_bits::<Lsb0>());
+        Ok(())
     }
 
     pub fn finalize(&mut self) {
@@ -189,6 +336,10 @@ impl Serializer for &mut Jmb {
     type SerializeStruct = Self;
     type SerializeStructVariant = Self;
 
+    fn is_human_readable(&self) -> bool {
+        false
+    }
+
     fn serialize_bool(self, v: bool) -> Result<Self::Ok, Self::Error> {
         log::info!("Serializing {v}");
         self.bits.push(v);
@@ -210,9 +361,7 @@ impl Serializer for &mut Jmb {
     auto_impl!(f32, f64 => where self, v {
         log::info!("Serializing float {v}");
 
-        // SAFETY: We just swiched to unsigned with same bytes and byte order
-        // Nothing special or breaking
-        let v: OtherSide = unsafe { std::mem::transmute(v) };
+        let v: OtherSide = v.to_bits();
         log::info!("Transmuted to: {v}");
         self.bits.extend_from_bitslice(v.view_bits::<Lsb0>());
         Ok(())
@@ -225,14 +374,25 @@ impl Serializer for &mut Jmb {
         Ok(())
     }
 
-    fn serialize_str(mut self, v: &str) -> Result<Self::Ok, Self::Error> {
+    fn serialize_str(self, v: &str) -> Result<Self::Ok, Self::Error> {
         log::info!("Serializing str {v:?}");
-        let len = self.bits.len();
 
-        self.strs
-            .entry(v.to_string())
-            .and_modify(|vec| vec.push(len))
-            .or_default();
+        if let Some(table) = self.table.take() {
+            let counts = match table {
+                Table::Tags => &mut self.symbols.tags,
+                Table::Names => &mut self.symbols.names,
+            };
+            *counts.entry(v.to_string()).or_default() += 1;
+
+            if let Some(tables) = &self.tables
+                && !write_code(&mut self.bits, tables.strings(table), v)?
+            {
+                return Ok(());
+            }
+        }
+
+        let len = self.bits.len();
+        self.strs.entry(v.to_string()).or_default().push(len);
 
         Ok(())
     }
@@ -245,6 +405,7 @@ impl Serializer for &mut Jmb {
 
     fn serialize_none(self) -> Result<Self::Ok, Self::Error> {
         log::info!("Serializing none..");
+        self.bits.push(false);
         Ok(())
     }
 
@@ -253,7 +414,8 @@ impl Serializer for &mut Jmb {
         T: ?Sized + Serialize,
     {
         log::info!("Serializing some");
-        Ok(())
+        self.bits.push(true);
+        v.serialize(self)
     }
 
     fn serialize_unit(self) -> Result<Self::Ok, Self::Error> {
@@ -261,7 +423,7 @@ impl Serializer for &mut Jmb {
         Ok(())
     }
 
-    fn serialize_unit_struct(self, name: &'static str) -> Result<Self::Ok, Self::Error> {
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<Self::Ok, Self::Error> {
         log::info!("Serializing unit struct..");
         Ok(())
     }
@@ -274,15 +436,17 @@ impl Serializer for &mut Jmb {
     ) -> Result<Self::Ok, Self::Error> {
         log::info!("Serializing unit variant {name}::{variant} as {variant_index}");
 
-        if name.ends_with("Bits") && name.contains("Wants") {
-            let bits: usize = name
-                .split_once("Wants")
-                .unwrap()
-                .1
-                .trim_end_matches("Bits")
-                .parse()
-                .unwrap();
+        if name == ACTION_ID {
+            *self.symbols.actions.entry(variant_index).or_default() += 1;
 
+            if let Some(tables) = &self.tables
+                && !write_code(&mut self.bits, &tables.actions, &variant_index)?
+            {
+                return Ok(());
+            }
+        }
+
+        if let Some(bits) = wanted_bits(name) {
             log::info!("Variant wants {bits} bits");
 
             self.bits
@@ -303,6 +467,7 @@ impl Serializer for &mut Jmb {
         T: ?Sized + Serialize,
     {
         log::info!("Serializing newtype struct {name}");
+        self.len_bits = wanted_bits(name);
         value.serialize(self)
     }
 
@@ -318,15 +483,7 @@ impl Serializer for &mut Jmb {
     {
         log::info!("Serializing newtype variant {name}::{variant} as {variant_index}");
 
-        if name.ends_with("Bits") && name.contains("Wants") {
-            let bits: usize = name
-                .split_once("Wants")
-                .unwrap()
-                .1
-                .trim_end_matches("Bits")
-                .parse()
-                .unwrap();
-
+        if let Some(bits) = wanted_bits(name) {
             log::info!("Variant wants {bits} bits");
 
             self.bits
@@ -341,8 +498,18 @@ impl Serializer for &mut Jmb {
 
     fn serialize_seq(self, len: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
         log::info!("Serializing seq");
-        self.bits
-            .extend_from_bitslice(len.unwrap().view_bits::<Lsb0>());
+        let len = len.unwrap();
+
+        match self.len_bits.take() {
+            Some(bits) if len >> bits != 0 => return Err(Err::LengthOverflow { len, bits }),
+            Some(bits) => {
+                log::info!("Length wants {bits} bits");
+                self.bits
+                    .extend_from_bitslice(&len.view_bits::<Lsb0>()[..bits]);
+            }
+            None => self.bits.extend_from_bitslice(len.view_bits::<Lsb0>()),
+        }
+
         Ok(self)
     }
 
@@ -352,7 +519,7 @@ impl Serializer for &mut Jmb {
 
     fn serialize_tuple_struct(
         self,
-        name: &'static str,
+        _name: &'static str,
         len: usize,
     ) -> Result<Self::SerializeTupleStruct, Self::Error> {
         self.serialize_seq(Some(len))
@@ -362,20 +529,12 @@ impl Serializer for &mut Jmb {
         self,
         name: &'static str,
         variant_index: u32,
-        variant: &'static str,
+        _variant: &'static str,
         len: usize,
     ) -> Result<Self::SerializeTupleVariant, Self::Error> {
         self.bits.extend_from_bitslice(len.view_bits::<Lsb0>());
 
-        if name.ends_with("Bits") && name.contains("Wants") {
-            let bits: usize = name
-                .split_once("Wants")
-                .unwrap()
-                .1
-                .trim_end_matches("Bits")
-                .parse()
-                .unwrap();
-
+        if let Some(bits) = wanted_bits(name) {
             log::info!("Variant wants {bits} bits");
 
             self.bits
@@ -398,9 +557,10 @@ impl Serializer for &mut Jmb {
     fn serialize_struct(
         self,
         name: &'static str,
-        len: usize,
+        _len: usize,
     ) -> Result<Self::SerializeStruct, Self::Error> {
         log::info!("Serializing struct");
+        self.structs.push(name);
         Ok(self)
     }
 
@@ -408,20 +568,12 @@ impl Serializer for &mut Jmb {
         self,
         name: &'static str,
         variant_index: u32,
-        variant: &'static str,
-        len: usize,
+        _variant: &'static str,
+        _len: usize,
     ) -> Result<Self::SerializeStructVariant, Self::Error> {
         log::info!("Serializing struct variant");
 
-        if name.ends_with("Bits") && name.contains("Wants") {
-            let bits: usize = name
-                .split_once("Wants")
-                .unwrap()
-                .1
-                .trim_end_matches("Bits")
-                .parse()
-                .unwrap();
-
+        if let Some(bits) = wanted_bits(name) {
             log::info!("Variant wants {bits} bits");
 
             self.bits
@@ -530,10 +682,24 @@ impl SerializeStruct for &mut Jmb {
     where
         T: ?Sized + Serialize,
     {
-        value.serialize(&mut **self)
+        self.table = match (self.structs.last(), key) {
+            (Some(&"Value"), "type") => Some(Table::Tags),
+            (Some(&"NamedValue"), "name") => Some(Table::Names),
+            _ => None,
+        };
+        value.serialize(&mut **self)?;
+        self.table = None;
+        Ok(())
+    }
+
+    /// Skipped fields leave a clear bit where a present one would have its `Some` bit.
+    fn skip_field(&mut self, _key: &'static str) -> Result<(), Self::Error> {
+        self.bits.push(false);
+        Ok(())
     }
 
     fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.structs.pop();
         Ok(())
     }
 }
@@ -542,31 +708,76 @@ impl SerializeStructVariant for &mut Jmb {
     type Ok = ();
     type Error = Err;
 
-    fn serialize_field<T>(&mut self, key: &'static str, value: &T) -> Result<(), Self::Error>
+    fn serialize_field<T>(&mut self, _key: &'static str, value: &T) -> Result<(), Self::Error>
     where
         T: ?Sized + Serialize,
     {
         value.serialize(&mut **self)
     }
 
+    /// Skipped fields leave a clear bit where a present one would have its `Some` bit.
+    fn skip_field(&mut self, _key: &'static str) -> Result<(), Self::Error> {
+        self.bits.push(false);
+        Ok(())
+    }
+
     fn end(self) -> Result<Self::Ok, Self::Error> {
         Ok(())
     }
 }
 
+fn wanted_bits(name: &str) -> Option<usize> {
+    name.strip_suffix("Bits")?
+        .split_once("Wants")?
+        .1
+        .parse()
+        .ok()
+}
+
+/// Writes the symbol's code. Returns whether the symbol still has to be written the fixed
+/// way, which it does after an escape.
+fn write_code<S, Q>(
+    bits: &mut BitVec<u8, Lsb0>,
+    table: &Huffman<S>,
+    symbol: &Q,
+) -> Result<bool, Err>
+where
+    S: Hash + Ord + Clone + Borrow<Q>,
+    Q: Hash + Eq + Display + ?Sized,
+{
+    if let Some(code) = table.code(symbol) {
+        code.write(bits);
+        return Ok(false);
+    }
+
+    let escape = table.escape().ok_or_else(|| Err::Uncoded {
+        symbol: symbol.to_string(),
+    })?;
+    escape.write(bits);
+    Ok(true)
+}
+
 #[derive(Debug)]
 pub enum Err {
     A,
+    LengthOverflow { len: usize, bits: usize },
+    Uncoded { symbol: String },
 }
 
 impl Display for Err {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.write_str("")
+        match self {
+            Self::A => f.write_str(""),
+            Self::LengthOverflow { len, bits } => {
+                write!(f, "length {len} doesn't fit in {bits} bits")
+            }
+            Self::Uncoded { symbol } => write!(f, "{symbol} has no code and there's no escape"),
+        }
     }
 }
 
 impl ser::Error for Err {
-    fn custom<T>(msg: T) -> Self
+    fn custom<T>(_msg: T) -> Self
     where
         T: std::fmt::Display,
     {
@@ -583,3 +794,21 @@ where
 {
     value.view_bits::<Lsb0>()[..2].serialize(serializer)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn coding_comes_first() {
+        for coding in [Coding::Fixed, Coding::Static, Coding::Module] {
+            let module = Module {
+                handlers: Vec::new(),
+                conflicts: Vec::new(),
+            };
+            let bytes = compile(module, coding).unwrap().into_bytes();
+
+            assert_eq!(bytes[0] & 0b11, coding as u8);
+        }
+    }
+}
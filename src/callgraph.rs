@@ -0,0 +1,261 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Write,
+};
+
+use crate::{
+    generated::ActionIdWants11Bits,
+    module::{LineTypeWants2Bits, Module, Op, Value},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CallKind {
+    Function,
+    Process,
+}
+
+impl CallKind {
+    pub fn of(op: &Op) -> Option<(Self, &'static str)> {
+        match op.action {
+            ActionIdWants11Bits::CallFunction => Some((Self::Function, "function_name")),
+            ActionIdWants11Bits::StartProcess => Some((Self::Process, "process_name")),
+            _ => None,
+        }
+    }
+
+    pub fn line_type(self) -> LineTypeWants2Bits {
+        match self {
+            Self::Function => LineTypeWants2Bits::Function,
+            Self::Process => LineTypeWants2Bits::Process,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Call {
+    pub caller: usize,
+    pub kind: CallKind,
+    pub target: String,
+    pub callee: Option<usize>,
+}
+
+impl Call {
+    /// Targets built from placeholders are only known at runtime.
+    pub fn is_dynamic(&self) -> bool {
+        self.target.contains('%')
+    }
+}
+
+#[derive(Debug)]
+pub struct CallGraph<'a> {
+    pub module: &'a Module,
+    pub calls: Vec<Call>,
+}
+
+impl<'a> CallGraph<'a> {
+    pub fn build(module: &'a Module) -> Self {
+        let mut lines = HashMap::new();
+
+        for (index, line) in module.handlers.iter().enumerate() {
+            if let Some(name) = &line.name {
+                lines.insert((line.line_type, name.as_str()), index);
+            }
+        }
+
+        let mut calls = Vec::new();
+
+        for (caller, line) in module.handlers.iter().enumerate() {
+            walk_calls(&line.operations, &mut |kind, target| {
+                calls.push(Call {
+                    caller,
+                    kind,
+                    callee: lines.get(&(kind.line_type(), target)).copied(),
+                    target: target.to_string(),
+                });
+            });
+        }
+
+        Self { module, calls }
+    }
+
+    pub fn find(&self, kind: CallKind, name: &str) -> Option<usize> {
+        self.module.handlers.iter().position(|line| {
+            line.line_type == kind.line_type() && line.name.as_deref() == Some(name)
+        })
+    }
+
+    pub fn callees(&self, line: usize) -> impl Iterator<Item = usize> + '_ {
+        self.calls
+            .iter()
+            .filter(move |call| call.caller == line)
+            .filter_map(|call| call.callee)
+    }
+
+    pub fn callers(&self, line: usize) -> impl Iterator<Item = usize> + '_ {
+        self.calls
+            .iter()
+            .filter(move |call| call.callee == Some(line))
+            .map(|call| call.caller)
+    }
+
+    pub fn undefined_calls(&self) -> impl Iterator<Item = &Call> {
+        self.calls
+            .iter()
+            .filter(|call| call.callee.is_none() && !call.is_dynamic())
+    }
+
+    /// Functions and processes that no other line ever calls.
+    pub fn unused(&self) -> Vec<usize> {
+        self.module
+            .handlers
+            .iter()
+            .enumerate()
+            .filter(|(_, line)| line.line_type != LineTypeWants2Bits::Event)
+            .filter(|(index, _)| self.callers(*index).all(|caller| caller == *index))
+            .map(|(index, _)| index)
+            .collect()
+    }
+
+    pub fn reachable(&self, roots: impl IntoIterator<Item = usize>) -> HashSet<usize> {
+        let mut seen = HashSet::new();
+        let mut stack: Vec<usize> = roots.into_iter().collect();
+
+        while let Some(line) = stack.pop() {
+            if seen.insert(line) {
+                stack.extend(self.callees(line));
+            }
+        }
+
+        seen
+    }
+
+    /// Strongly connected components that contain a cycle, found with Tarjan's algorithm.
+    pub fn cycles(&self) -> Vec<Vec<usize>> {
+        struct Tarjan<'g, 'a> {
+            graph: &'g CallGraph<'a>,
+            index: usize,
+            indices: HashMap<usize, usize>,
+            lowlinks: HashMap<usize, usize>,
+            stack: Vec<usize>,
+            on_stack: HashSet<usize>,
+            cycles: Vec<Vec<usize>>,
+        }
+
+        impl Tarjan<'_, '_> {
+            fn visit(&mut self, line: usize) {
+                self.indices.insert(line, self.index);
+                self.lowlinks.insert(line, self.index);
+                self.index += 1;
+                self.stack.push(line);
+                self.on_stack.insert(line);
+
+                for callee in self.graph.callees(line).collect::<Vec<_>>() {
+                    if !self.indices.contains_key(&callee) {
+                        self.visit(callee);
+                        let low = self.lowlinks[&line].min(self.lowlinks[&callee]);
+                        self.lowlinks.insert(line, low);
+                    } else if self.on_stack.contains(&callee) {
+                        let low = self.lowlinks[&line].min(self.indices[&callee]);
+                        self.lowlinks.insert(line, low);
+                    }
+                }
+
+                if self.lowlinks[&line] == self.indices[&line] {
+                    let mut component = Vec::new();
+
+                    while let Some(member) = self.stack.pop() {
+                        self.on_stack.remove(&member);
+                        component.push(member);
+
+                        if member == line {
+                            break;
+                        }
+                    }
+
+                    if component.len() > 1 || self.graph.callees(line).any(|callee| callee == line)
+                    {
+                        component.sort();
+                        self.cycles.push(component);
+                    }
+                }
+            }
+        }
+
+        let mut tarjan = Tarjan {
+            graph: self,
+            index: 0,
+            indices: HashMap::new(),
+            lowlinks: HashMap::new(),
+            stack: Vec::new(),
+            on_stack: HashSet::new(),
+            cycles: Vec::new(),
+        };
+
+        for line in 0..self.module.handlers.len() {
+            if !tarjan.indices.contains_key(&line) {
+                tarjan.visit(line);
+            }
+        }
+
+        tarjan.cycles
+    }
+
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph calls {\n");
+
+        for (index, line) in self.module.handlers.iter().enumerate() {
+            let shape = match line.line_type {
+                LineTypeWants2Bits::Event => "doublecircle",
+                LineTypeWants2Bits::Function => "box",
+                LineTypeWants2Bits::Process => "hexagon",
+            };
+
+            writeln!(dot, "\tl{index} [label={:?}, shape={shape}];", line.label()).unwrap();
+        }
+
+        let mut undefined = HashMap::new();
+
+        for call in &self.calls {
+            let style = match call.kind {
+                CallKind::Function => "solid",
+                CallKind::Process => "dashed",
+            };
+
+            let target = match call.callee {
+                Some(callee) => format!("l{callee}"),
+                None => {
+                    let next = undefined.len();
+                    let node = *undefined.entry(call.target.as_str()).or_insert(next);
+
+                    if node == next {
+                        writeln!(
+                            dot,
+                            "\tu{node} [label={:?}, shape=box, color=red];",
+                            call.target
+                        )
+                        .unwrap();
+                    }
+
+                    format!("u{node}")
+                }
+            };
+
+            writeln!(dot, "\tl{} -> {target} [style={style}];", call.caller).unwrap();
+        }
+
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+pub fn walk_calls<'o>(ops: &'o [Op], f: &mut impl FnMut(CallKind, &'o str)) {
+    for op in ops {
+        if let Some((kind, arg)) = CallKind::of(op)
+            && let Some(Value::Text { text, .. }) = op.value(arg)
+        {
+            f(kind, text);
+        }
+
+        walk_calls(&op.operations, f);
+    }
+}
@@ -0,0 +1,267 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::{self, Display},
+};
+
+use heck::ToUpperCamelCase;
+
+use crate::{
+    assets::Catalog,
+    callgraph::{CallGraph, CallKind},
+    diagnostic::Diagnostic,
+    generated::{ActionIdWants11Bits, ActionObject},
+    module::{Module, Number, Op, Value, walk_ops},
+};
+
+/// Iterations assumed for loops whose bounds are only known at runtime.
+pub const UNKNOWN_ITERATIONS: f64 = 10.0;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Cost {
+    /// Ops written in the handler, counting each container body once.
+    pub ops: usize,
+    /// Ops expected to run, with loop bodies multiplied out and called functions included.
+    pub estimate: f64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct HandlerCost {
+    pub line: usize,
+    pub cost: Cost,
+    /// Has a `repeat_forever` or `repeat_while` that never waits.
+    pub unbounded: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct Report {
+    /// Most expensive first.
+    pub handlers: Vec<HandlerCost>,
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+impl Report {
+    pub fn display<'a>(&'a self, module: &'a Module) -> impl Display + 'a {
+        DisplayReport {
+            report: self,
+            module,
+        }
+    }
+}
+
+struct DisplayReport<'a> {
+    report: &'a Report,
+    module: &'a Module,
+}
+
+impl Display for DisplayReport<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{:>10} {:>6}  handler", "estimate", "ops")?;
+
+        for handler in &self.report.handlers {
+            let line = &self.module.handlers[handler.line];
+            write!(
+                f,
+                "{:>10.0} {:>6}  {} {}",
+                handler.cost.estimate,
+                handler.cost.ops,
+                line.line_type,
+                line.label()
+            )?;
+
+            if handler.unbounded {
+                write!(f, " (unbounded)")?;
+            }
+
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Estimates the cost of every handler and ranks them. Processes started by a handler run
+/// on their own and aren't added to its cost; recursive calls count as a single op.
+pub fn report(module: &Module, catalog: &Catalog) -> Report {
+    let mut estimator = Estimator {
+        module,
+        catalog,
+        calls: CallGraph::build(module),
+        costs: HashMap::new(),
+        active: HashSet::new(),
+        unbounded: HashSet::new(),
+        diagnostics: Vec::new(),
+    };
+
+    let mut handlers: Vec<_> = (0..module.handlers.len())
+        .map(|line| HandlerCost {
+            line,
+            cost: estimator.line(line),
+            unbounded: false,
+        })
+        .collect();
+
+    for handler in &mut handlers {
+        handler.unbounded = estimator.unbounded.contains(&handler.line);
+    }
+
+    handlers.sort_by(|a, b| b.cost.estimate.total_cmp(&a.cost.estimate));
+
+    Report {
+        handlers,
+        diagnostics: estimator.diagnostics,
+    }
+}
+
+/// How often a repeat runs its body, when the bounds are constant.
+pub fn iterations(op: &Op) -> Option<f64> {
+    let number = |name: &str, default: Option<f64>| match op.value(name) {
+        Some(Value::Number {
+            number: Number::Simple(number),
+        }) => Some(*number),
+        None => default,
+        _ => None,
+    };
+    let location = |name: &str| match op.value(name) {
+        Some(Value::Location { x, y, z, .. }) => Some([*x, *y, *z]),
+        _ => None,
+    };
+
+    Some(match op.action {
+        ActionIdWants11Bits::RepeatMultiTimes => number("amount", None)?.max(0.0).floor(),
+        ActionIdWants11Bits::RepeatOnRange => {
+            let (start, end) = (number("start", None)?, number("end", None)?);
+            let interval = number("interval", Some(1.0))?.abs();
+
+            if interval == 0.0 {
+                return None;
+            }
+
+            ((end - start).abs() / interval).floor() + 1.0
+        }
+        ActionIdWants11Bits::RepeatForEachInList => match op.value("list")? {
+            Value::Array { values } => values.len() as f64,
+            _ => return None,
+        },
+        ActionIdWants11Bits::RepeatOnCircle => number("circle_points", Some(16.0))?.max(1.0),
+        ActionIdWants11Bits::RepeatOnSphere => number("points", Some(16.0))?.max(1.0),
+        ActionIdWants11Bits::RepeatOnGrid => {
+            let (start, end) = (location("start")?, location("end")?);
+
+            (0..3)
+                .map(|axis| (start[axis].floor() - end[axis].floor()).abs() + 1.0)
+                .product()
+        }
+        ActionIdWants11Bits::RepeatAdjacently => {
+            let pattern = match op.value("pattern") {
+                Some(Value::Enum { value }) => value.to_upper_camel_case(),
+                _ => "Adjacent".to_string(),
+            };
+            let include_self =
+                matches!(op.value("include_self"), Some(Value::Enum { value }) if value == "TRUE");
+
+            let around = match pattern.as_str() {
+                "Cardinal" => 4.0,
+                "Square" => 8.0,
+                "Cube" => 26.0,
+                _ => 6.0,
+            };
+            around + if include_self { 1.0 } else { 0.0 }
+        }
+        _ => return None,
+    })
+}
+
+struct Estimator<'a> {
+    module: &'a Module,
+    catalog: &'a Catalog,
+    calls: CallGraph<'a>,
+    costs: HashMap<usize, Cost>,
+    /// Lines being estimated, to stop at recursive calls.
+    active: HashSet<usize>,
+    unbounded: HashSet<usize>,
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl Estimator<'_> {
+    fn line(&mut self, line: usize) -> Cost {
+        if let Some(cost) = self.costs.get(&line) {
+            return *cost;
+        }
+
+        self.active.insert(line);
+        let module = self.module;
+        let cost = self.ops(line, &module.handlers[line].operations, &mut Vec::new());
+        self.active.remove(&line);
+        self.costs.insert(line, cost);
+        cost
+    }
+
+    fn ops(&mut self, line: usize, ops: &[Op], path: &mut Vec<usize>) -> Cost {
+        let mut cost = Cost::default();
+
+        for (index, op) in ops.iter().enumerate() {
+            path.push(index);
+            let body = self.ops(line, &op.operations, path);
+            cost.ops += 1 + body.ops;
+            cost.estimate += 1.0;
+
+            if self.catalog.action(op.action).object == ActionObject::Repeat {
+                cost.estimate += body.estimate * iterations(op).unwrap_or(UNKNOWN_ITERATIONS);
+
+                if matches!(
+                    op.action,
+                    ActionIdWants11Bits::RepeatForever | ActionIdWants11Bits::RepeatWhile
+                ) && !self.waits(&op.operations, &mut HashSet::new())
+                {
+                    self.unbounded.insert(line);
+                    self.diagnostics.push(Diagnostic::warning(
+                        line,
+                        path,
+                        format!(
+                            "repeat {} never waits, so it has to finish within a single tick",
+                            self.catalog.action(op.action).name
+                        ),
+                    ));
+                }
+            } else {
+                cost.estimate += body.estimate;
+            }
+
+            if let Some(callee) = self.callee(op)
+                && !self.active.contains(&callee)
+            {
+                cost.estimate += self.line(callee).estimate;
+            }
+
+            path.pop();
+        }
+
+        cost
+    }
+
+    fn callee(&self, op: &Op) -> Option<usize> {
+        if let Some((CallKind::Function, arg)) = CallKind::of(op)
+            && let Some(Value::Text { text, .. }) = op.value(arg)
+        {
+            return self.calls.find(CallKind::Function, text);
+        }
+
+        None
+    }
+
+    /// Whether the ops wait somewhere, including in the functions they call.
+    fn waits(&self, ops: &[Op], seen: &mut HashSet<usize>) -> bool {
+        let mut waits = false;
+        let mut callees = Vec::new();
+
+        walk_ops(ops, &mut |_, op| {
+            waits |= op.action == ActionIdWants11Bits::ControlWait;
+            callees.extend(self.callee(op));
+        });
+
+        waits
+            || callees.into_iter().any(|callee| {
+                seen.insert(callee) && self.waits(&self.module.handlers[callee].operations, seen)
+            })
+    }
+}
@@ -0,0 +1,236 @@
+use std::{
+    collections::HashMap,
+    fmt::{self, Display},
+};
+
+use crate::{
+    interpreter::{Locals, Val},
+    module::Module,
+};
+
+/// An op as it was about to run, with its arguments resolved.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Step {
+    pub line: usize,
+    pub path: Vec<usize>,
+    /// Functions and processes entered on the way here.
+    pub depth: usize,
+    pub op: String,
+    /// Resolved arguments, sorted by name. Placeholders and `%math` are already expanded.
+    pub args: Vec<(String, Val)>,
+}
+
+impl Step {
+    pub fn display<'a>(&'a self, module: &'a Module) -> impl Display + 'a {
+        DisplayStep { step: self, module }
+    }
+}
+
+struct DisplayStep<'a> {
+    step: &'a Step,
+    module: &'a Module,
+}
+
+impl Display for DisplayStep<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let line = &self.module.handlers[self.step.line];
+        let path: Vec<_> = self.step.path.iter().map(usize::to_string).collect();
+        write!(
+            f,
+            "{}{} {} / op {}: {}",
+            "  ".repeat(self.step.depth),
+            line.line_type,
+            line.label(),
+            path.join("/"),
+            self.step.op
+        )?;
+
+        if !self.step.args.is_empty() {
+            let args: Vec<_> = (self.step.args.iter())
+                .map(|(name, value)| format!("{name} = {value}"))
+                .collect();
+            write!(f, "  [{}]", args.join(", "))?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Where to stop: a handler such as `function DeathPointsGen`, optionally followed by an
+/// op path like `:3/1`. Without a path it stops at the first op of the handler.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Breakpoint {
+    pub line: String,
+    pub path: Vec<usize>,
+}
+
+impl Breakpoint {
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let (line, path) = text.split_once(':').unwrap_or((text, ""));
+        let path = path
+            .split('/')
+            .filter(|index| !index.is_empty())
+            .map(|index| {
+                index
+                    .trim()
+                    .parse()
+                    .map_err(|_| format!("bad op index {index}"))
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self {
+            line: line.trim().to_string(),
+            path,
+        })
+    }
+
+    fn hit(&self, module: &Module, step: &Step) -> bool {
+        let line = &module.handlers[step.line];
+
+        format!("{} {}", line.line_type, line.label()) == self.line
+            && match self.path.is_empty() {
+                true => step.path == [0],
+                false => step.path == self.path,
+            }
+    }
+}
+
+/// What to do after a pause.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Command {
+    /// Stop at the next op, entering called functions.
+    Step,
+    /// Stop at the next op of this function or a caller.
+    Next,
+    /// Stop once the current function returns.
+    Out,
+    /// Run to the next breakpoint.
+    Continue,
+    /// Abort the run.
+    Quit,
+}
+
+/// The state shown while paused.
+pub struct Paused<'a> {
+    pub step: &'a Step,
+    pub locals: &'a Locals,
+    pub globals: &'a HashMap<String, Val>,
+    pub saved: &'a HashMap<String, Val>,
+}
+
+pub type Prompt<'m> = Box<dyn FnMut(&Paused) -> Command + 'm>;
+
+/// Records every executed op and pauses at breakpoints, asking `prompt` how to go on.
+pub struct Debugger<'m> {
+    pub breakpoints: Vec<Breakpoint>,
+    pub trace: Vec<Step>,
+    prompt: Prompt<'m>,
+    command: Command,
+    /// Call depth when the last command was given.
+    depth: usize,
+}
+
+impl<'m> Debugger<'m> {
+    pub fn new(breakpoints: Vec<Breakpoint>, prompt: Prompt<'m>) -> Self {
+        Self {
+            breakpoints,
+            trace: Vec::new(),
+            prompt,
+            command: Command::Continue,
+            depth: 0,
+        }
+    }
+
+    /// Starts paused at the first op.
+    pub fn stepping(mut self) -> Self {
+        self.command = Command::Step;
+        self
+    }
+
+    /// Called before each op. Returns false once the user quits.
+    pub fn before(
+        &mut self,
+        module: &Module,
+        step: Step,
+        locals: &Locals,
+        globals: &HashMap<String, Val>,
+        saved: &HashMap<String, Val>,
+    ) -> bool {
+        let depth = step.depth;
+        let pause = match self.command {
+            Command::Step => true,
+            Command::Next => depth <= self.depth,
+            Command::Out => depth < self.depth,
+            Command::Continue | Command::Quit => false,
+        } || self
+            .breakpoints
+            .iter()
+            .any(|point| point.hit(module, &step));
+
+        if pause {
+            self.command = (self.prompt)(&Paused {
+                step: &step,
+                locals,
+                globals,
+                saved,
+            });
+            self.depth = depth;
+        }
+
+        self.trace.push(step);
+        self.command != Command::Quit
+    }
+}
+
+/// A prompt reading commands from stdin: `s`tep, `n`ext, `o`ut, `c`ontinue, `q`uit and
+/// `p [local|global|save] [name]` to print variables.
+pub fn console(module: &Module) -> Prompt<'_> {
+    Box::new(move |paused| {
+        println!("paused at {}", paused.step.display(module));
+
+        loop {
+            print!("> ");
+            std::io::Write::flush(&mut std::io::stdout()).unwrap();
+            let mut input = String::new();
+
+            if std::io::stdin().read_line(&mut input).unwrap_or(0) == 0 {
+                return Command::Quit;
+            }
+
+            let mut words = input.split_whitespace();
+
+            match words.next().unwrap_or("s") {
+                "s" | "step" => return Command::Step,
+                "n" | "next" => return Command::Next,
+                "o" | "out" => return Command::Out,
+                "c" | "continue" => return Command::Continue,
+                "q" | "quit" => return Command::Quit,
+                "p" | "print" => {
+                    let scope = words.next();
+                    let name = words.next();
+
+                    for (scope_name, variables) in [
+                        ("local", paused.locals),
+                        ("global", paused.globals),
+                        ("save", paused.saved),
+                    ] {
+                        if scope.is_some_and(|scope| scope != scope_name) {
+                            continue;
+                        }
+
+                        let mut names: Vec<_> = variables
+                            .keys()
+                            .filter(|variable| name.is_none_or(|name| name == *variable))
+                            .collect();
+                        names.sort();
+
+                        for variable in names {
+                            println!("{scope_name}.{variable} = {}", variables[variable]);
+                        }
+                    }
+                }
+                other => println!("unknown command {other}, try s, n, o, c, q or p"),
+            }
+        }
+    })
+}
@@ -0,0 +1,68 @@
+use std::fmt::{self, Display};
+
+use crate::module::Module;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    Warning,
+    Error,
+}
+
+#[derive(Debug, Clone)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    pub line: usize,
+    /// Indices of the op inside the line, descending into container bodies.
+    pub path: Vec<usize>,
+    pub message: String,
+}
+
+impl Diagnostic {
+    pub fn warning(line: usize, path: &[usize], message: impl Into<String>) -> Self {
+        Self {
+            severity: Severity::Warning,
+            line,
+            path: path.to_vec(),
+            message: message.into(),
+        }
+    }
+
+    pub fn error(line: usize, path: &[usize], message: impl Into<String>) -> Self {
+        Self {
+            severity: Severity::Error,
+            ..Self::warning(line, path, message)
+        }
+    }
+
+    pub fn display<'a>(&'a self, module: &'a Module) -> impl Display + 'a {
+        DisplayDiagnostic {
+            diagnostic: self,
+            module,
+        }
+    }
+
+    pub fn log(&self, module: &Module) {
+        match self.severity {
+            Severity::Warning => log::warn!("{}", self.display(module)),
+            Severity::Error => log::error!("{}", self.display(module)),
+        }
+    }
+}
+
+struct DisplayDiagnostic<'a> {
+    diagnostic: &'a Diagnostic,
+    module: &'a Module,
+}
+
+impl Display for DisplayDiagnostic<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let line = &self.module.handlers[self.diagnostic.line];
+        write!(f, "{} {}", line.line_type, line.label())?;
+
+        for index in &self.diagnostic.path {
+            write!(f, " / op {index}")?;
+        }
+
+        write!(f, ": {}", self.diagnostic.message)
+    }
+}
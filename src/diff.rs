@@ -0,0 +1,353 @@
+use std::fmt::{self, Display};
+
+use crate::{
+    assets::Catalog,
+    generated::{ActionIdWants11Bits, ActionObject},
+    module::{Conflict, Line, LineTypeWants2Bits, Module, NamedValue, Op, Value},
+    pretty::{render_op, render_value},
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ChangeKind {
+    Added(String),
+    Removed(String),
+    Changed { old: String, new: String },
+}
+
+/// One structural difference, located by a path such as
+/// `function DeathPointsGen / op 3 / repeat body / op 1`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Change {
+    pub location: String,
+    pub kind: ChangeKind,
+}
+
+impl Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            ChangeKind::Added(what) => write!(f, "{}: + {what}", self.location),
+            ChangeKind::Removed(what) => write!(f, "{}: - {what}", self.location),
+            ChangeKind::Changed { old, new } => write!(f, "{}: {old} -> {new}", self.location),
+        }
+    }
+}
+
+impl Change {
+    fn added(location: impl Into<String>, what: String) -> Self {
+        Self {
+            location: location.into(),
+            kind: ChangeKind::Added(what),
+        }
+    }
+
+    fn removed(location: impl Into<String>, what: String) -> Self {
+        Self {
+            location: location.into(),
+            kind: ChangeKind::Removed(what),
+        }
+    }
+
+    fn changed(location: impl Into<String>, old: String, new: String) -> Self {
+        Self {
+            location: location.into(),
+            kind: ChangeKind::Changed { old, new },
+        }
+    }
+}
+
+/// Handlers are matched by kind and name, events by their event.
+pub fn same_line(a: &Line, b: &Line) -> bool {
+    a.line_type == b.line_type
+        && match a.line_type {
+            LineTypeWants2Bits::Event => a.event == b.event,
+            _ => a.name == b.name,
+        }
+}
+
+pub fn line_location(line: &Line) -> String {
+    format!("{} {}", line.line_type, line.label())
+}
+
+/// Compares two modules handler by handler and op by op.
+pub fn diff(old: &Module, new: &Module, catalog: &Catalog) -> Vec<Change> {
+    let mut changes = Vec::new();
+
+    for line in &old.handlers {
+        if !new.handlers.iter().any(|other| same_line(line, other)) {
+            changes.push(Change::removed(
+                line_location(line),
+                format!("{} ops", line.operations.len()),
+            ));
+        }
+    }
+
+    for line in &new.handlers {
+        let location = line_location(line);
+
+        let Some(previous) = old.handlers.iter().find(|other| same_line(other, line)) else {
+            changes.push(Change::added(
+                location,
+                format!("{} ops", line.operations.len()),
+            ));
+            continue;
+        };
+
+        if previous.position != line.position {
+            changes.push(Change::changed(
+                location.clone(),
+                format!("position {}", previous.position),
+                format!("position {}", line.position),
+            ));
+        }
+
+        diff_values(&location, &previous.values, &line.values, &mut changes);
+        diff_ops(
+            catalog,
+            &location,
+            &previous.operations,
+            &line.operations,
+            &mut changes,
+        );
+    }
+
+    for conflict in &old.conflicts {
+        if !new.conflicts.contains(conflict) {
+            changes.push(Change::removed(
+                conflict_location(conflict),
+                "conflict".to_string(),
+            ));
+        }
+    }
+
+    for conflict in &new.conflicts {
+        if !old.conflicts.contains(conflict) {
+            changes.push(Change::added(
+                conflict_location(conflict),
+                "conflict".to_string(),
+            ));
+        }
+    }
+
+    changes
+}
+
+pub fn conflict_location(conflict: &Conflict) -> String {
+    if let Some(value) = &conflict.value {
+        return format!("{} / {value}", conflict.line);
+    }
+
+    let path: Vec<_> = conflict.path.iter().map(usize::to_string).collect();
+    format!("{} / op {}", conflict.line, path.join("/"))
+}
+
+/// Pairs of indices of matching items, in order, found by longest common subsequence.
+pub fn common_subsequence<T>(
+    old: &[T],
+    new: &[T],
+    same: impl Fn(&T, &T) -> bool,
+) -> Vec<(usize, usize)> {
+    let mut lengths = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lengths[i][j] = if same(&old[i], &new[j]) {
+                lengths[i + 1][j + 1] + 1
+            } else {
+                lengths[i + 1][j].max(lengths[i][j + 1])
+            };
+        }
+    }
+
+    let (mut i, mut j) = (0, 0);
+    let mut pairs = Vec::new();
+
+    while i < old.len() && j < new.len() {
+        if same(&old[i], &new[j]) {
+            pairs.push((i, j));
+            i += 1;
+            j += 1;
+        } else if lengths[i + 1][j] >= lengths[i][j + 1] {
+            i += 1;
+        } else {
+            j += 1;
+        }
+    }
+
+    pairs
+}
+
+pub fn diff_ops(
+    catalog: &Catalog,
+    location: &str,
+    old: &[Op],
+    new: &[Op],
+    changes: &mut Vec<Change>,
+) {
+    let anchors = common_subsequence(old, new, Op::eq)
+        .into_iter()
+        .chain([(old.len(), new.len())]);
+    let (mut i, mut j) = (0, 0);
+
+    for (next_i, next_j) in anchors {
+        // Between two equal ops, ops of the same action are edits of each other
+        let mut removed: Vec<usize> = (i..next_i).collect();
+
+        for (j, op) in new.iter().enumerate().take(next_j).skip(j) {
+            let op_location = format!("{location} / op {j}");
+
+            match removed.iter().position(|&i| old[i].action == op.action) {
+                Some(found) => {
+                    let i = removed.remove(found);
+                    diff_op(catalog, &op_location, &old[i], op, changes);
+                }
+                None => changes.push(Change::added(op_location, render_op(op, catalog))),
+            }
+        }
+
+        for i in removed {
+            changes.push(Change::removed(
+                format!("{location} / op {i}"),
+                render_op(&old[i], catalog),
+            ));
+        }
+
+        (i, j) = (next_i + 1, next_j + 1);
+    }
+}
+
+fn diff_op(catalog: &Catalog, location: &str, old: &Op, new: &Op, changes: &mut Vec<Change>) {
+    diff_values(location, &old.values, &new.values, changes);
+
+    if old.selection != new.selection
+        || old.is_inverted != new.is_inverted
+        || old.conditional != new.conditional
+    {
+        changes.push(Change::changed(
+            location,
+            render_op(old, catalog),
+            render_op(new, catalog),
+        ));
+    }
+
+    let body = format!("{location} / {} body", body_name(catalog, new));
+    diff_ops(catalog, &body, &old.operations, &new.operations, changes);
+}
+
+fn diff_values(location: &str, old: &[NamedValue], new: &[NamedValue], changes: &mut Vec<Change>) {
+    for value in old {
+        match find(new, &value.name) {
+            None => changes.push(Change::removed(
+                location,
+                format!("{}: {}", value.name, render_value(&value.value)),
+            )),
+            Some(other) if *other != value.value => changes.push(Change::changed(
+                location,
+                format!("{}: {}", value.name, render_value(&value.value)),
+                format!("{}: {}", value.name, render_value(other)),
+            )),
+            Some(_) => {}
+        }
+    }
+
+    for value in new {
+        if find(old, &value.name).is_none() {
+            changes.push(Change::added(
+                location,
+                format!("{}: {}", value.name, render_value(&value.value)),
+            ));
+        }
+    }
+}
+
+/// The value called `name`, if any.
+pub fn find<'a>(values: &'a [NamedValue], name: &str) -> Option<&'a Value> {
+    values
+        .iter()
+        .find(|value| value.name == name)
+        .map(|value| &value.value)
+}
+
+fn body_name(catalog: &Catalog, op: &Op) -> String {
+    let action = catalog.action(op.action);
+
+    match action.object {
+        _ if op.action == ActionIdWants11Bits::Else => "else".to_string(),
+        ActionObject::Repeat => "repeat".to_string(),
+        _ if action.boolean => "if".to_string(),
+        _ => action.name.clone(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        lang,
+        module::{TextParsingWants2Bits, Value},
+    };
+
+    const OLD: &str = "
+function F {
+    local.a = 1
+    local.b = 2
+    local.c = 3
+}
+";
+
+    fn module(source: &str) -> Module {
+        lang::compile_source(source, &Catalog::load().unwrap()).unwrap()
+    }
+
+    #[test]
+    fn reports_op_edits() {
+        let new = module(
+            "
+function F {
+    local.a = 10
+    local.c = 3
+    call G
+}
+",
+        );
+        let changes = diff(&module(OLD), &new, &Catalog::load().unwrap());
+
+        assert_eq!(
+            changes,
+            [
+                Change::changed("function F / op 0", "value: 1".into(), "value: 10".into()),
+                Change::removed("function F / op 1", "local.b = 2".into()),
+                Change::added("function F / op 2", "call G".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn reports_line_values_and_conflicts() {
+        let old = module(OLD);
+        let mut new = old.clone();
+        new.handlers[0].values.push(NamedValue {
+            name: "description".to_string(),
+            value: Value::Text {
+                text: "counts".to_string(),
+                parsing: TextParsingWants2Bits::Legacy,
+            },
+        });
+        new.conflicts.push(Conflict {
+            line: "function F".to_string(),
+            path: Vec::new(),
+            base: Vec::new(),
+            ours: Vec::new(),
+            theirs: Vec::new(),
+            value: Some("description".to_string()),
+        });
+        let changes = diff(&old, &new, &Catalog::load().unwrap());
+
+        assert_eq!(
+            changes,
+            [
+                Change::added("function F", r#"description: legacy"counts""#.into()),
+                Change::added("function F / description", "conflict".into()),
+            ]
+        );
+    }
+}
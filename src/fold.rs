@@ -0,0 +1,610 @@
+use std::fmt::{self, Display};
+
+use crate::{
+    assets::Catalog,
+    generated::{ActionIdWants11Bits, ActionObject},
+    interpreter::{Interpreter, Locals, Val},
+    module::{Module, NamedValue, Number, Op, Value},
+    optimize::Change,
+};
+
+/// `set_variable` actions that only do arithmetic on their arguments.
+const MATH: &[&str] = &[
+    "add",
+    "subtract",
+    "multiply",
+    "divide",
+    "min",
+    "max",
+    "average",
+    "absolute",
+    "remainder",
+    "clamp",
+    "warp",
+    "pow",
+    "root",
+    "log",
+    "round",
+    "sine",
+    "cosine",
+    "tangent",
+    "lerp_number",
+    "map_range",
+];
+
+/// Folds constant math, drops assignments that don't change their variable and replaces
+/// conditions that are always true or false with the branch that runs.
+pub fn fold(module: &mut Module, catalog: &Catalog) -> Vec<Change> {
+    // Constant arguments never look at variables or the world
+    let empty = Module {
+        handlers: Vec::new(),
+        conflicts: Vec::new(),
+    };
+    let mut folder = Folder {
+        catalog,
+        interpreter: Interpreter::new(&empty, catalog),
+        changes: Vec::new(),
+    };
+
+    for (index, line) in module.handlers.iter_mut().enumerate() {
+        folder.ops(index, &mut line.operations, &mut Vec::new());
+    }
+
+    folder.changes
+}
+
+struct Folder<'a> {
+    catalog: &'a Catalog,
+    interpreter: Interpreter<'a>,
+    changes: Vec<Change>,
+}
+
+impl Folder<'_> {
+    fn ops(&mut self, line: usize, ops: &mut Vec<Op>, path: &mut Vec<usize>) {
+        let mut old = std::mem::take(ops).into_iter().enumerate().peekable();
+
+        while let Some((index, mut op)) = old.next() {
+            path.push(index);
+            self.values(line, path, &mut op.values);
+            let name = &self.catalog.action(op.action).name;
+
+            if let Some(result) = self.condition(&op) {
+                self.changes.push(Change::new(
+                    line,
+                    path,
+                    format!("{name} is always {result}"),
+                ));
+                let otherwise = old.next_if(|(_, next)| next.action == ActionIdWants11Bits::Else);
+
+                let body = match (result, otherwise) {
+                    (true, _) => Some(op.operations),
+                    (false, Some((index, otherwise))) => {
+                        *path.last_mut().unwrap() = index;
+                        Some(otherwise.operations)
+                    }
+                    (false, None) => None,
+                };
+
+                if let Some(mut body) = body {
+                    self.ops(line, &mut body, path);
+                    ops.extend(body);
+                }
+            } else if self.no_op(&op) {
+                self.changes.push(Change::new(
+                    line,
+                    path,
+                    format!("removed {name}, it leaves its variable as it is"),
+                ));
+            } else {
+                self.ops(line, &mut op.operations, path);
+
+                if let Some(number) = self.math(&op) {
+                    self.changes.push(Change::new(
+                        line,
+                        path,
+                        format!("folded {name} into {number}"),
+                    ));
+                    op = set_value(&op, number);
+                }
+
+                ops.push(op);
+            }
+
+            path.pop();
+        }
+    }
+
+    fn values(&mut self, line: usize, path: &[usize], values: &mut [NamedValue]) {
+        for value in values {
+            self.value(line, path, &mut value.value);
+        }
+    }
+
+    fn value(&mut self, line: usize, path: &[usize], value: &mut Value) {
+        match value {
+            Value::Array { values } => {
+                for value in values {
+                    self.value(line, path, value);
+                }
+            }
+            Value::Number { number } => {
+                let Number::Calc(expression) = &*number else {
+                    return;
+                };
+                let simplified = simplify(expression);
+
+                if simplified != *expression {
+                    self.changes.push(Change::new(
+                        line,
+                        path,
+                        format!("simplified {expression} to {simplified}"),
+                    ));
+                    *number = match simplified.trim().parse() {
+                        Ok(constant) => Number::Simple(constant),
+                        Err(_) => Number::Calc(simplified),
+                    };
+                }
+            }
+            Value::Text { text, .. } => {
+                let simplified = simplify(text);
+
+                if simplified != *text {
+                    self.changes.push(Change::new(
+                        line,
+                        path,
+                        format!("simplified {text} to {simplified}"),
+                    ));
+                    *text = simplified;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// The outcome of an `if_variable` condition whose arguments are all constant.
+    fn condition(&mut self, op: &Op) -> Option<bool> {
+        let action = self.catalog.action(op.action);
+
+        if !action.boolean
+            || action.object != ActionObject::Variable
+            || !op.values.iter().all(|value| constant(&value.value))
+        {
+            return None;
+        }
+
+        let args = self.interpreter.args(op, &Locals::new()).ok()?;
+        let result = (self.interpreter)
+            .variable_condition(action, &args, &Locals::new())
+            .ok()?;
+
+        Some(result != op.is_inverted)
+    }
+
+    /// Assignments such as `x = x`, `x += 0` or `x *= 1`. Arithmetic assumes the variable
+    /// already holds a number.
+    fn no_op(&self, op: &Op) -> bool {
+        let action = self.catalog.action(op.action);
+        let Some(target @ Value::Variable { .. }) = op.value("variable") else {
+            return false;
+        };
+
+        if action.object != ActionObject::Variable {
+            return false;
+        }
+
+        let is = |value: &Value, expected: f64| {
+            *value
+                == Value::Number {
+                    number: Number::Simple(expected),
+                }
+        };
+
+        match action.name.as_str() {
+            "set_value" => op.value("value") == Some(target),
+            "increment" | "decrement" => op.value("number").is_some_and(|value| is(value, 0.0)),
+            "add" | "subtract" | "multiply" | "divide" => {
+                let identity = match action.name.as_str() {
+                    "add" | "subtract" => 0.0,
+                    _ => 1.0,
+                };
+                let values: Vec<_> = (op.values.iter())
+                    .filter(|value| value.name == "value")
+                    .flat_map(|value| match &value.value {
+                        Value::Array { values } => values.iter().collect(),
+                        value => vec![value],
+                    })
+                    .filter(|value| !value.is_empty_slot())
+                    .collect();
+
+                let rounds = match op.value("division_mode") {
+                    Some(Value::Enum { value }) => value != "DEFAULT",
+                    _ => false,
+                };
+
+                values.first() == Some(&target)
+                    && values[1..].iter().all(|value| is(value, identity))
+                    && !rounds
+            }
+            _ => false,
+        }
+    }
+
+    /// The result of arithmetic on constants.
+    fn math(&mut self, op: &Op) -> Option<f64> {
+        let action = self.catalog.action(op.action);
+
+        if action.object != ActionObject::Variable
+            || !MATH.contains(&action.name.as_str())
+            || !matches!(op.value("variable"), Some(Value::Variable { .. }))
+            || !(op.values.iter())
+                .filter(|value| value.name != "variable")
+                .all(|value| constant(&value.value))
+        {
+            return None;
+        }
+
+        let args = self.interpreter.args(op, &Locals::new()).ok()?;
+
+        match self.interpreter.compute(&action.name, &args) {
+            Ok(Some(Val::Number(number))) if number.is_finite() => Some(number),
+            _ => None,
+        }
+    }
+}
+
+fn set_value(op: &Op, number: f64) -> Op {
+    Op {
+        action: ActionIdWants11Bits::SetVariableValue,
+        values: vec![
+            NamedValue {
+                name: "variable".to_string(),
+                value: op.value("variable").unwrap().clone(),
+            },
+            NamedValue {
+                name: "value".to_string(),
+                value: Value::Number {
+                    number: Number::Simple(number),
+                },
+            },
+        ],
+        selection: None,
+        is_inverted: false,
+        conditional: None,
+        operations: Vec::new(),
+    }
+}
+
+/// Values that are the same every time the op runs. The empty slots arrays are padded
+/// with don't count, the interpreter skips them.
+fn constant(value: &Value) -> bool {
+    match value {
+        Value::Array { values } => (values.iter())
+            .filter(|value| !value.is_empty_slot())
+            .all(constant),
+        Value::Number {
+            number: Number::Simple(_),
+        }
+        | Value::Location { .. }
+        | Value::Vector { .. }
+        | Value::Enum { .. }
+        | Value::Item { .. } => true,
+        Value::Text { text, .. } => !text.contains('%'),
+        _ => false,
+    }
+}
+
+/// Folds the constant parts of every `%math(..)` in a text, including ones nested in other
+/// placeholders. A `%math` that is entirely constant becomes its result.
+pub fn simplify(text: &str) -> String {
+    let mut out = String::new();
+    let mut rest = text;
+
+    while let Some(start) = rest.find('%') {
+        out.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        match placeholder(rest) {
+            Some((name, Some(inner), len)) => {
+                let inner = simplify(inner);
+
+                match (name, parse(&inner)) {
+                    ("math", Some(expr)) => {
+                        let mut folded = false;
+
+                        match expr.fold(&mut folded) {
+                            Expr::Number(number) => out.push_str(&number.to_string()),
+                            expr if folded => out.push_str(&format!("%math({expr})")),
+                            _ => out.push_str(&format!("%math({inner})")),
+                        }
+                    }
+                    _ => out.push_str(&format!("%{name}({inner})")),
+                }
+
+                rest = &rest[len..];
+            }
+            Some((_, None, len)) => {
+                out.push_str(&rest[..len]);
+                rest = &rest[len..];
+            }
+            None => {
+                out.push('%');
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    out.push_str(rest);
+    out
+}
+
+/// A placeholder at the start of `text`, like `%var_local(x)` or `%random%`: its name, what
+/// is inside the parentheses and its length.
+fn placeholder(text: &str) -> Option<(&str, Option<&str>, usize)> {
+    let rest = text.strip_prefix('%')?;
+    let name_len = rest
+        .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+        .unwrap_or(rest.len());
+    let name = &rest[..name_len];
+
+    if !name.starts_with(|c: char| c.is_ascii_alphabetic()) {
+        return None;
+    }
+
+    match rest[name_len..].chars().next()? {
+        '(' => {
+            let mut depth = 0;
+            let end = rest[name_len..].char_indices().find_map(|(i, c)| {
+                match c {
+                    '(' => depth += 1,
+                    ')' => depth -= 1,
+                    _ => {}
+                }
+                (depth == 0).then_some(name_len + i)
+            })?;
+
+            Some((name, Some(&rest[name_len + 1..end]), end + 2))
+        }
+        '%' if name == "random" => Some((name, None, name_len + 2)),
+        _ => None,
+    }
+}
+
+/// A `%math` expression, with the grammar of [`crate::interpreter::eval_math`].
+#[derive(Debug, Clone, PartialEq)]
+enum Expr {
+    Number(f64),
+    /// Only known at runtime, kept as written.
+    Placeholder(String),
+    Neg(Box<Expr>),
+    Binary(char, Box<Expr>, Box<Expr>),
+}
+
+impl Expr {
+    fn precedence(&self) -> u8 {
+        match self {
+            Self::Binary('+' | '-', ..) => 1,
+            Self::Binary('^', ..) => 3,
+            Self::Binary(..) => 2,
+            Self::Neg(_) => 4,
+            Self::Number(number) if *number < 0.0 => 4,
+            Self::Number(_) | Self::Placeholder(_) => 5,
+        }
+    }
+
+    fn fold(self, folded: &mut bool) -> Self {
+        match self {
+            Self::Neg(expr) => match expr.fold(folded) {
+                Self::Number(number) => {
+                    *folded = true;
+                    Self::Number(-number)
+                }
+                expr => Self::Neg(Box::new(expr)),
+            },
+            Self::Binary(op, left, right) => {
+                let (left, right) = (left.fold(folded), right.fold(folded));
+
+                if let (Self::Number(a), Self::Number(b)) = (&left, &right) {
+                    let result = match op {
+                        '+' => a + b,
+                        '-' => a - b,
+                        '*' => a * b,
+                        '/' => a / b,
+                        '%' => a % b,
+                        _ => a.powf(*b),
+                    };
+
+                    if result.is_finite() {
+                        *folded = true;
+                        return Self::Number(result);
+                    }
+                }
+
+                let identity = |expr: &Self, value: f64| *expr == Self::Number(value);
+
+                match op {
+                    '+' if identity(&left, 0.0) => {
+                        *folded = true;
+                        right
+                    }
+                    '+' | '-' if identity(&right, 0.0) => {
+                        *folded = true;
+                        left
+                    }
+                    '*' if identity(&left, 1.0) => {
+                        *folded = true;
+                        right
+                    }
+                    '*' | '/' | '^' if identity(&right, 1.0) => {
+                        *folded = true;
+                        left
+                    }
+                    _ => Self::Binary(op, Box::new(left), Box::new(right)),
+                }
+            }
+            expr => expr,
+        }
+    }
+}
+
+impl Display for Expr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let wrap = |f: &mut fmt::Formatter<'_>, expr: &Self, parens: bool| match parens {
+            true => write!(f, "({expr})"),
+            false => write!(f, "{expr}"),
+        };
+
+        match self {
+            Self::Number(number) => write!(f, "{number}"),
+            Self::Placeholder(text) => f.write_str(text),
+            Self::Neg(expr) => {
+                f.write_str("-")?;
+                wrap(f, expr, expr.precedence() < 4)
+            }
+            Self::Binary(op, left, right) => {
+                let precedence = self.precedence();
+                // `^` groups to the right, everything else to the left
+                let (left_parens, right_parens) = match op {
+                    '^' => (
+                        left.precedence() <= precedence,
+                        right.precedence() < precedence,
+                    ),
+                    _ => (
+                        left.precedence() < precedence,
+                        right.precedence() <= precedence,
+                    ),
+                };
+
+                wrap(f, left, left_parens)?;
+                write!(f, "{op}")?;
+                wrap(f, right, right_parens)
+            }
+        }
+    }
+}
+
+fn parse(expression: &str) -> Option<Expr> {
+    struct Parser<'a> {
+        rest: &'a str,
+    }
+
+    impl Parser<'_> {
+        fn eat(&mut self, c: char) -> bool {
+            self.rest = self.rest.trim_start();
+
+            // A `%` that starts a placeholder isn't the remainder operator
+            if c == '%' && placeholder(self.rest).is_some() {
+                return false;
+            }
+
+            match self.rest.strip_prefix(c) {
+                Some(rest) => {
+                    self.rest = rest;
+                    true
+                }
+                None => false,
+            }
+        }
+
+        fn binary(op: char, left: Expr, right: Expr) -> Expr {
+            Expr::Binary(op, Box::new(left), Box::new(right))
+        }
+
+        fn sum(&mut self) -> Option<Expr> {
+            let mut expr = self.product()?;
+
+            loop {
+                let op = ['+', '-'].into_iter().find(|op| self.eat(*op));
+                let Some(op) = op else {
+                    return Some(expr);
+                };
+                expr = Self::binary(op, expr, self.product()?);
+            }
+        }
+
+        fn product(&mut self) -> Option<Expr> {
+            let mut expr = self.power()?;
+
+            loop {
+                let op = ['*', '/', '%'].into_iter().find(|op| self.eat(*op));
+                let Some(op) = op else {
+                    return Some(expr);
+                };
+                expr = Self::binary(op, expr, self.power()?);
+            }
+        }
+
+        fn power(&mut self) -> Option<Expr> {
+            let base = self.unary()?;
+
+            match self.eat('^') {
+                true => Some(Self::binary('^', base, self.power()?)),
+                false => Some(base),
+            }
+        }
+
+        fn unary(&mut self) -> Option<Expr> {
+            if self.eat('-') {
+                return Some(Expr::Neg(Box::new(self.unary()?)));
+            }
+
+            if self.eat('(') {
+                let expr = self.sum()?;
+                return self.eat(')').then_some(expr);
+            }
+
+            self.rest = self.rest.trim_start();
+
+            if let Some((_, _, len)) = placeholder(self.rest) {
+                let (text, rest) = self.rest.split_at(len);
+                self.rest = rest;
+                return Some(Expr::Placeholder(text.to_string()));
+            }
+
+            let len = self
+                .rest
+                .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+                .unwrap_or(self.rest.len());
+            let (number, rest) = self.rest.split_at(len);
+            self.rest = rest;
+            number.parse().ok().map(Expr::Number)
+        }
+    }
+
+    let mut parser = Parser { rest: expression };
+    let expr = parser.sum()?;
+    parser.rest.trim().is_empty().then_some(expr)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lang;
+
+    fn folded(ops: &str) -> Vec<Op> {
+        let catalog = Catalog::load().unwrap();
+        let source = format!("function F {{\n{ops}\n}}\n");
+        let mut module = lang::compile_source(&source, &catalog).unwrap();
+        fold(&mut module, &catalog);
+        module.handlers.remove(0).operations
+    }
+
+    #[test]
+    fn folds_math_in_padded_arrays() {
+        let ops = folded("    local.x = variable.add(value: [1, 2, ?, ?])");
+
+        assert_eq!(ops[0].action, ActionIdWants11Bits::SetVariableValue);
+        assert_eq!(
+            ops[0].value("value"),
+            Some(&Value::Number {
+                number: Number::Simple(3.0)
+            })
+        );
+    }
+
+    #[test]
+    fn drops_no_ops_in_padded_arrays() {
+        let ops = folded("    local.z = variable.subtract(value: [local.z, 0, ?, ?])");
+
+        assert!(ops.is_empty());
+    }
+}
@@ -0,0 +1,106 @@
+use heck::ToShoutySnakeCase;
+
+use crate::{
+    assets::Catalog,
+    module::{Module, Op, Value},
+};
+
+/// Rewrites a module into canonical form so that equal modules export to equal JSON.
+pub fn canonicalize(module: &mut Module, catalog: &Catalog) {
+    module.handlers.sort_by_key(|line| line.position);
+
+    for line in &mut module.handlers {
+        canonicalize_ops(&mut line.operations, catalog);
+    }
+
+    for conflict in &mut module.conflicts {
+        canonicalize_ops(&mut conflict.base, catalog);
+        canonicalize_ops(&mut conflict.ours, catalog);
+        canonicalize_ops(&mut conflict.theirs, catalog);
+    }
+}
+
+fn canonicalize_ops(ops: &mut [Op], catalog: &Catalog) {
+    for op in ops {
+        // Values go in the order the catalog declares the arguments, unknown ones last
+        let mut values = std::mem::take(&mut op.values);
+        values.sort_by_key(|value| {
+            let action = catalog.arg_owner(op, &value.name);
+            let own = action.id == op.action;
+
+            (
+                !own,
+                action
+                    .args
+                    .iter()
+                    .position(|arg| arg.id == value.name)
+                    .unwrap_or(usize::MAX),
+            )
+        });
+        op.values = values;
+
+        for value in &mut op.values {
+            canonicalize_value(&mut value.value);
+        }
+
+        canonicalize_ops(&mut op.operations, catalog);
+    }
+}
+
+fn canonicalize_value(value: &mut Value) {
+    match value {
+        Value::Array { values } => values.iter_mut().for_each(canonicalize_value),
+        Value::Enum { value } => *value = value.to_shouty_snake_case(),
+        // Fields in a fixed order and without whitespace. Selections that don't parse are
+        // left for the editor to deal with.
+        Value::GameValue { selection, .. } => {
+            if let Ok(parsed) = serde_json::from_str::<serde_json::Value>(selection) {
+                *selection = parsed.to_string();
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Canonical pretty JSON for a module, ending with a newline.
+pub fn format(mut module: Module, catalog: &Catalog) -> String {
+    canonicalize(&mut module, catalog);
+    let mut out = serde_json::to_string_pretty(&module).unwrap();
+    out.push('\n');
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn canonical(value: serde_json::Value) -> serde_json::Value {
+        let mut value = serde_json::from_value(value).unwrap();
+        canonicalize_value(&mut value);
+        serde_json::to_value(value).unwrap()
+    }
+
+    #[test]
+    fn enums_in_screaming_snake_case() {
+        for (exported, canonical_enum) in [
+            ("notched_20", "NOTCHED_20"),
+            ("NOTCHED_20", "NOTCHED_20"),
+            ("MULTIPLY_SCALAR_1", "MULTIPLY_SCALAR_1"),
+            ("Descending", "DESCENDING"),
+        ] {
+            let value = canonical(serde_json::json!({ "type": "enum", "enum": exported }));
+            assert_eq!(value["enum"], canonical_enum);
+        }
+    }
+
+    #[test]
+    fn selections_keep_their_fields() {
+        let game_value = |selection: &str| serde_json::json!({ "type": "game_value", "game_value": "current_health", "selection": selection });
+
+        let value = canonical(game_value(r#"{ "type": "last_entity", "id": 3 }"#));
+        assert_eq!(value["selection"], r#"{"id":3,"type":"last_entity"}"#);
+
+        let value = canonical(game_value("{broken"));
+        assert_eq!(value["selection"], "{broken");
+    }
+}
@@ -1,1265 +1,3972 @@
-#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, Copy)]
+
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[serde(rename_all = "snake_case")]
 #[repr(u16)]
 pub enum EventId {
-    BellRing,
-    BlockBurn,
-    BlockDamage,
-    BlockDamageAbort,
-    BlockExplode,
-    BlockFade,
-    BlockFall,
-    BlockFertilize,
-    BlockFlow,
-    BlockForm,
-    BlockFormByEntity,
-    BlockGrow,
-    BlockIgnite,
-    BlockPistonExtend,
-    BlockPistonRetract,
-    BlockSpread,
-    BrewComplete,
-    DispenserDispenseItem,
-    DispenserEquipArmor,
-    DispenserShearSheep,
-    ElderGuardianAppearsAtPlayer,
-    EndermanAttackPlayer,
-    EndermanEscape,
-    EntityBellRing,
-    EntityDamageEntity,
-    EntityDamagePlayer,
-    EntityDeath,
-    EntityDropItem,
-    EntityDummy,
-    EntityExplode,
-    EntityExplosion,
-    EntityHeal,
-    EntityInteract,
-    EntityKillEntity,
-    EntityKnockback,
-    EntityLoadCrossbow,
-    EntityPickupItem,
-    EntityRemovedFromWorld,
-    EntityResurrect,
-    EntityShotBow,
-    EntitySpawn,
-    EntitySpellCast,
-    EntityTakeDamage,
-    EntityTeleport,
-    EntityTransform,
-    FallingBlockLand,
-    FireworkExplode,
-    FluidLevelChange,
-    FurnaceBurn,
-    FurnaceSmelt,
-    FurnaceStartSmelt,
-    GoatRamEntity,
-    HangingBreak,
-    HopperPickupItem,
-    ItemDespawn,
-    ItemMerge,
-    ItemMovedIntoContainer,
-    LeavesDecay,
-    MobKillPlayer,
-    NotePlay,
-    PiglinBarter,
-    PlayerAnvilRenameInput,
-    PlayerArmSwing,
-    PlayerBreakBlock,
-    PlayerBreakItem,
-    PlayerChangeSlot,
-    PlayerChat,
-    PlayerClickInventory,
-    PlayerClickOwnInventory,
-    PlayerCloseAdvancementsMenu,
-    PlayerCloseInventory,
-    PlayerCommand,
-    PlayerConsumeItem,
-    PlayerCraftItem,
-    PlayerDamageEntity,
-    PlayerDamagePlayer,
-    PlayerDeath,
-    PlayerDismount,
-    PlayerDragInventory,
-    PlayerDropItem,
-    PlayerDummy,
-    PlayerEditBook,
-    PlayerExhaustion,
-    PlayerFailMove,
-    PlayerFish,
-    PlayerFoodLevelChange,
-    PlayerFurnaceExtract,
-    PlayerHeal,
-    PlayerHorseJump,
-    PlayerImbuePotionCloud,
-    PlayerInteract,
-    PlayerItemMend,
-    PlayerJoin,
-    PlayerJump,
-    PlayerKillMob,
-    PlayerKillPlayer,
-    PlayerKnockback,
-    PlayerLaunchProjectile,
-    PlayerLeashEntity,
-    PlayerLeftClick,
-    PlayerLoadCrossbow,
-    PlayerLocationChange,
-    PlayerMove,
-    PlayerOpenAdvancementsTab,
-    PlayerOpenInventory,
-    PlayerPickItem,
-    PlayerPickupExperience,
-    PlayerPickupItem,
-    PlayerPickupProjectile,
-    PlayerPlaceBlock,
-    PlayerPreAttackEntity,
-    PlayerProjectileHit,
-    PlayerQueryBlockInfo,
-    PlayerQueryEntityInfo,
-    PlayerQuit,
-    PlayerRejoin,
-    PlayerRespawn,
-    PlayerResurrect,
-    PlayerRightClick,
-    PlayerRightClickEntity,
-    PlayerRightClickPlayer,
-    PlayerRiptide,
-    PlayerRotate,
-    PlayerShotBow,
-    PlayerSignChange,
-    PlayerSneak,
-    PlayerStartFlight,
-    PlayerStartSpectatingEntity,
-    PlayerStartSprint,
-    PlayerStopFlight,
-    PlayerStopSpectatingEntity,
-    PlayerStopSprint,
-    PlayerStopUsingItem,
-    PlayerStructureGrow,
-    PlayerSwapHands,
-    PlayerTakeDamage,
-    PlayerTameEntity,
-    PlayerTeleport,
-    PlayerUnsneak,
-    PlayerVehicleJump,
-    PlayerVehicleMove,
-    PlayerVelocity,
-    PortalCreate,
-    ProjectileDamageEntity,
-    ProjectileDamagePlayer,
-    ProjectileHit,
-    ProjectileKillEntity,
-    ProjectileLaunch,
-    ProjectiveCollide,
-    RedstoneLevelChange,
-    SculkBloom,
-    SheepRegrowWool,
-    SpongeAbsorb,
-    StructureGrow,
-    TimeSkip,
-    TntPrime,
-    VehicleTakeDamage,
-    WitchThrowPotion,
-    WorldDummy,
-    WorldStart,
-    WorldStop,
-    WorldWebException,
-    WorldWebResponse,
+	BellRing,
+	BlockBurn,
+	BlockDamage,
+	BlockDamageAbort,
+	BlockExplode,
+	BlockFade,
+	BlockFall,
+	BlockFertilize,
+	BlockFlow,
+	BlockForm,
+	BlockFormByEntity,
+	BlockGrow,
+	BlockIgnite,
+	BlockPistonExtend,
+	BlockPistonRetract,
+	BlockSpread,
+	BrewComplete,
+	DispenserDispenseItem,
+	DispenserEquipArmor,
+	DispenserShearSheep,
+	ElderGuardianAppearsAtPlayer,
+	EndermanAttackPlayer,
+	EndermanEscape,
+	EntityBellRing,
+	EntityDamageEntity,
+	EntityDamagePlayer,
+	EntityDeath,
+	EntityDropItem,
+	EntityDummy,
+	EntityExplode,
+	EntityExplosion,
+	EntityHeal,
+	EntityInteract,
+	EntityKillEntity,
+	EntityKnockback,
+	EntityLoadCrossbow,
+	EntityPickupItem,
+	EntityRemovedFromWorld,
+	EntityResurrect,
+	EntityShotBow,
+	EntitySpawn,
+	EntitySpellCast,
+	EntityTakeDamage,
+	EntityTeleport,
+	EntityTransform,
+	FallingBlockLand,
+	FireworkExplode,
+	FluidLevelChange,
+	FurnaceBurn,
+	FurnaceSmelt,
+	FurnaceStartSmelt,
+	GoatRamEntity,
+	HangingBreak,
+	HopperPickupItem,
+	ItemDespawn,
+	ItemMerge,
+	ItemMovedIntoContainer,
+	LeavesDecay,
+	MobKillPlayer,
+	NotePlay,
+	PiglinBarter,
+	PlayerAnvilRenameInput,
+	PlayerArmSwing,
+	PlayerBreakBlock,
+	PlayerBreakItem,
+	PlayerChangeSlot,
+	PlayerChat,
+	PlayerClickInventory,
+	PlayerClickOwnInventory,
+	PlayerCloseAdvancementsMenu,
+	PlayerCloseInventory,
+	PlayerCommand,
+	PlayerConsumeItem,
+	PlayerCraftItem,
+	PlayerDamageEntity,
+	PlayerDamagePlayer,
+	PlayerDeath,
+	PlayerDismount,
+	PlayerDragInventory,
+	PlayerDropItem,
+	PlayerDummy,
+	PlayerEditBook,
+	PlayerExhaustion,
+	PlayerFailMove,
+	PlayerFish,
+	PlayerFoodLevelChange,
+	PlayerFurnaceExtract,
+	PlayerHeal,
+	PlayerHorseJump,
+	PlayerImbuePotionCloud,
+	PlayerInteract,
+	PlayerItemMend,
+	PlayerJoin,
+	PlayerJump,
+	PlayerKillMob,
+	PlayerKillPlayer,
+	PlayerKnockback,
+	PlayerLaunchProjectile,
+	PlayerLeashEntity,
+	PlayerLeftClick,
+	PlayerLoadCrossbow,
+	PlayerLocationChange,
+	PlayerMove,
+	PlayerOpenAdvancementsTab,
+	PlayerOpenInventory,
+	PlayerPickItem,
+	PlayerPickupExperience,
+	PlayerPickupItem,
+	PlayerPickupProjectile,
+	PlayerPlaceBlock,
+	PlayerPreAttackEntity,
+	PlayerProjectileHit,
+	PlayerQueryBlockInfo,
+	PlayerQueryEntityInfo,
+	PlayerQuit,
+	PlayerRejoin,
+	PlayerRespawn,
+	PlayerResurrect,
+	PlayerRightClick,
+	PlayerRightClickEntity,
+	PlayerRightClickPlayer,
+	PlayerRiptide,
+	PlayerRotate,
+	PlayerShotBow,
+	PlayerSignChange,
+	PlayerSneak,
+	PlayerStartFlight,
+	PlayerStartSpectatingEntity,
+	PlayerStartSprint,
+	PlayerStopFlight,
+	PlayerStopSpectatingEntity,
+	PlayerStopSprint,
+	PlayerStopUsingItem,
+	PlayerStructureGrow,
+	PlayerSwapHands,
+	PlayerTakeDamage,
+	PlayerTameEntity,
+	PlayerTeleport,
+	PlayerUnsneak,
+	PlayerVehicleJump,
+	PlayerVehicleMove,
+	PlayerVelocity,
+	PortalCreate,
+	ProjectileDamageEntity,
+	ProjectileDamagePlayer,
+	ProjectileHit,
+	ProjectileKillEntity,
+	ProjectileLaunch,
+	ProjectiveCollide,
+	RedstoneLevelChange,
+	SculkBloom,
+	SheepRegrowWool,
+	SpongeAbsorb,
+	StructureGrow,
+	TimeSkip,
+	TntPrime,
+	VehicleTakeDamage,
+	WitchThrowPotion,
+	WorldDummy,
+	WorldStart,
+	WorldStop,
+	WorldWebException,
+	WorldWebResponse,
 }
 
-#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, Copy)]
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[serde(rename_all = "snake_case")]
 #[repr(u16)]
 pub enum GameValueId {
-    AbsorptionHealth,
-    ActionCountPerTick,
-    Age,
-    ArmorItems,
-    ArmorPoints,
-    ArmorToughness,
-    ArrowsInBody,
-    AttachedLeads,
-    AttackCooldownStrength,
-    AttackCooldownTicks,
-    AttackDamage,
-    AttackSpeed,
-    BlockBeneath,
-    BlocksBeneath,
-    BodyYaw,
-    CapeLocation,
-    CardinalDirection,
-    ChatVisibility,
-    ClientBrandName,
-    ClientViewDistance,
-    CpuUsage,
-    CurrentHealth,
-    CursorItem,
-    CustomInventoryItems,
-    DirectionOfView,
-    DisplayEntityLeftRotation,
-    DisplayEntityRightRotation,
-    DisplayEntityScale,
-    DisplayEntityTranslation,
-    DisplayName,
-    EntityFuseTicks,
-    EntityHeight,
-    EntityItem,
-    EntityTicksLived,
-    EntityType,
-    EntityWidthX,
-    EntityWidthZ,
-    EventAddedItems,
-    EventAdvancementTabName,
-    EventAnvilRenameInput,
-    EventBlockFace,
-    EventBlockLocation,
-    EventBlocksInvolved,
-    EventChatMessage,
-    EventCloseInventoryCause,
-    EventDamage,
-    EventDamageCause,
-    EventDragType,
-    EventEquipmentSlot,
-    EventExhaustionAmount,
-    EventExhaustionReason,
-    EventExperience,
-    EventFailMoveReason,
-    EventFinalDamage,
-    EventFishState,
-    EventFoodLevel,
-    EventFromLocation,
-    EventHangingBreakCause,
-    EventHealAmount,
-    EventHealCause,
-    EventHotbarSlot,
-    EventInteraction,
-    EventInventoryAction,
-    EventInventoryClickType,
-    EventItem,
-    EventItems,
-    EventKnockbackCause,
-    EventKnockbackEventType,
-    EventKnockbackVector,
-    EventMessage,
-    EventNewLocation,
-    EventNewPotionEffect,
-    EventPower,
-    EventProjectileItem,
-    EventQueryInfo,
-    EventReplacedBlock,
-    EventSignLines,
-    EventSignSide,
-    EventSlot,
-    EventSlotType,
-    EventSlotsInvolved,
-    EventTeleportCause,
-    EventTicksHeldFor,
-    EventTimeSkipAmount,
-    EventTimeSkipReason,
-    EventTransformReason,
-    EventTransformedEntities,
-    EventTreeType,
-    EventVelocity,
-    ExperienceLevel,
-    ExperienceProgress,
-    EyeLocation,
-    FallDistance,
-    FireTicks,
-    FlyingSpeed,
-    FoodExhaustion,
-    FoodLevel,
-    FoodSaturation,
-    FreezeTicks,
-    Gamemode,
-    HeldSlot,
-    HitboxMidpointLocation,
-    HotbarItems,
-    InventoryItems,
-    InvulnerabilityTicks,
-    ItemUsageProgress,
-    LastDamageCause,
-    LeadHolder,
-    Location,
-    MainHand,
-    MainHandItem,
-    MaxHealth,
-    MaxInvulnerabilityTicks,
-    MerchantRecipeCount,
-    Name,
-    OffHandItem,
-    OpenInventorySize,
-    OpenInventoryTitle,
-    OpenInventoryType,
-    Origin,
-    OwnerUuid,
-    ParticleCpuUsage,
-    Passengers,
-    Ping,
-    Pitch,
-    PlayerCount,
-    Pose,
-    PotionEffects,
-    ProjectileOwnerUuid,
-    ProjectilePower,
-    ProtocolVersion,
-    RemainingAir,
-    SaddleItem,
-    SelectionSize,
-    SelectionTargetNames,
-    SelectionTargetUuids,
-    ServerCurrentTick,
-    ServerStoppedTime,
-    ServerTps,
-    SpawnLocation,
-    SpawnReason,
-    SteerForward,
-    SteerSideways,
-    TargetBlockFace,
-    TargetBlockLocation,
-    TargetFluidLocation,
-    TargetedEntity,
-    Timestamp,
-    Url,
-    UrlResponse,
-    UrlResponseCode,
-    UserLocale,
-    Uuid,
-    Vehicle,
-    Velocity,
-    WalkingSpeed,
-    WorldId,
-    WorldSize,
-    WorldTime,
-    WorldWeather,
-    XCoordinate,
-    YCoordinate,
-    Yaw,
-    ZCoordinate,
+	AbsorptionHealth,
+	ActionCountPerTick,
+	Age,
+	ArmorItems,
+	ArmorPoints,
+	ArmorToughness,
+	ArrowsInBody,
+	AttachedLeads,
+	AttackCooldownStrength,
+	AttackCooldownTicks,
+	AttackDamage,
+	AttackSpeed,
+	BlockBeneath,
+	BlocksBeneath,
+	BodyYaw,
+	CapeLocation,
+	CardinalDirection,
+	ChatVisibility,
+	ClientBrandName,
+	ClientViewDistance,
+	CpuUsage,
+	CurrentHealth,
+	CursorItem,
+	CustomInventoryItems,
+	DirectionOfView,
+	DisplayEntityLeftRotation,
+	DisplayEntityRightRotation,
+	DisplayEntityScale,
+	DisplayEntityTranslation,
+	DisplayName,
+	EntityFuseTicks,
+	EntityHeight,
+	EntityItem,
+	EntityTicksLived,
+	EntityType,
+	EntityWidthX,
+	EntityWidthZ,
+	EventAddedItems,
+	EventAdvancementTabName,
+	EventAnvilRenameInput,
+	EventBlockFace,
+	EventBlockLocation,
+	EventBlocksInvolved,
+	EventChatMessage,
+	EventCloseInventoryCause,
+	EventDamage,
+	EventDamageCause,
+	EventDragType,
+	EventEquipmentSlot,
+	EventExhaustionAmount,
+	EventExhaustionReason,
+	EventExperience,
+	EventFailMoveReason,
+	EventFinalDamage,
+	EventFishState,
+	EventFoodLevel,
+	EventFromLocation,
+	EventHangingBreakCause,
+	EventHealAmount,
+	EventHealCause,
+	EventHotbarSlot,
+	EventInteraction,
+	EventInventoryAction,
+	EventInventoryClickType,
+	EventItem,
+	EventItems,
+	EventKnockbackCause,
+	EventKnockbackEventType,
+	EventKnockbackVector,
+	EventMessage,
+	EventNewLocation,
+	EventNewPotionEffect,
+	EventPower,
+	EventProjectileItem,
+	EventQueryInfo,
+	EventReplacedBlock,
+	EventSignLines,
+	EventSignSide,
+	EventSlot,
+	EventSlotType,
+	EventSlotsInvolved,
+	EventTeleportCause,
+	EventTicksHeldFor,
+	EventTimeSkipAmount,
+	EventTimeSkipReason,
+	EventTransformReason,
+	EventTransformedEntities,
+	EventTreeType,
+	EventVelocity,
+	ExperienceLevel,
+	ExperienceProgress,
+	EyeLocation,
+	FallDistance,
+	FireTicks,
+	FlyingSpeed,
+	FoodExhaustion,
+	FoodLevel,
+	FoodSaturation,
+	FreezeTicks,
+	Gamemode,
+	HeldSlot,
+	HitboxMidpointLocation,
+	HotbarItems,
+	InventoryItems,
+	InvulnerabilityTicks,
+	ItemUsageProgress,
+	LastDamageCause,
+	LeadHolder,
+	Location,
+	MainHand,
+	MainHandItem,
+	MaxHealth,
+	MaxInvulnerabilityTicks,
+	MerchantRecipeCount,
+	Name,
+	OffHandItem,
+	OpenInventorySize,
+	OpenInventoryTitle,
+	OpenInventoryType,
+	Origin,
+	OwnerUuid,
+	ParticleCpuUsage,
+	Passengers,
+	Ping,
+	Pitch,
+	PlayerCount,
+	Pose,
+	PotionEffects,
+	ProjectileOwnerUuid,
+	ProjectilePower,
+	ProtocolVersion,
+	RemainingAir,
+	SaddleItem,
+	SelectionSize,
+	SelectionTargetNames,
+	SelectionTargetUuids,
+	ServerCurrentTick,
+	ServerStoppedTime,
+	ServerTps,
+	SpawnLocation,
+	SpawnReason,
+	SteerForward,
+	SteerSideways,
+	TargetBlockFace,
+	TargetBlockLocation,
+	TargetFluidLocation,
+	TargetedEntity,
+	Timestamp,
+	Url,
+	UrlResponse,
+	UrlResponseCode,
+	UserLocale,
+	Uuid,
+	Vehicle,
+	Velocity,
+	WalkingSpeed,
+	WorldId,
+	WorldSize,
+	WorldTime,
+	WorldWeather,
+	XCoordinate,
+	YCoordinate,
+	Yaw,
+	ZCoordinate,
 }
 
-#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, Copy)]
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[serde(rename_all = "snake_case")]
 #[repr(u16)]
 pub enum ValueType {
-    Array,
-    Item,
-    Location,
-    Map,
-    Number,
-    Potion,
-    Text,
-    Vector,
+	Array,
+	Item,
+	Location,
+	Map,
+	Number,
+	Potion,
+	Text,
+	Vector,
 }
 
-#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, Copy)]
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[serde(rename_all = "snake_case")]
 #[repr(u16)]
 pub enum ActionIdWants11Bits {
-    CallFunction,
-    ControlCallException,
-    ControlDummy,
-    ControlEndThread,
-    ControlReturnFunction,
-    ControlSkipIteration,
-    ControlStopRepeat,
-    ControlWait,
-    ControllerAsyncRun,
-    ControllerException,
-    ControllerMeasureTime,
-    Else,
-    EntityAttachLead,
-    EntityCelarPotionEffects,
-    EntityClearMerchantRecipes,
-    EntityDamage,
-    EntityDisguiseAsBlock,
-    EntityDisguiseAsEntity,
-    EntityDisguiseAsItem,
-    EntityDisguiseAsPlayer,
-    EntityDummy,
-    EntityEatGrass,
-    EntityEatTarget,
-    EntityExplode,
-    EntityFaceLocation,
-    EntityGetCustomTag,
-    EntityGivePotionEffects,
-    EntityHeal,
-    EntityIgniteCreeper,
-    EntityJump,
-    EntityLaunchForward,
-    EntityLaunchProjectile,
-    EntityLaunchToLocation,
-    EntityLaunchUp,
-    EntityLeaveVehicle,
-    EntityModifyPiglinBarterMaterials,
-    EntityModifyPiglinInterestedMaterials,
-    EntityMoveToLocation,
-    EntityMoveToLocationStop,
-    EntityPlayDamageAnimation,
-    EntityPlayHurtAnimation,
-    EntityRamTarget,
-    EntityRemove,
-    EntityRemoveCustomTag,
-    EntityRemoveDisguise,
-    EntityRemoveMerchantRecipe,
-    EntityRemovePotionEffect,
-    EntityResetDisplayBrightness,
-    EntityResetDisplayGlowColor,
-    EntityResetTextDisplayBackground,
-    EntityRideEntity,
-    EntitySetAbsorptionHealth,
-    EntitySetAi,
-    EntitySetAllayDancing,
-    EntitySetAngry,
-    EntitySetAnimalAge,
-    EntitySetArmorItems,
-    EntitySetArmorStandParts,
-    EntitySetArmorStandPose,
-    EntitySetArrowHitSound,
-    EntitySetArrowPierce,
-    EntitySetAttribute,
-    EntitySetAware,
-    EntitySetAxolotlType,
-    EntitySetBaby,
-    EntitySetBaseArrowDamage,
-    EntitySetBeeHasStinger,
-    EntitySetBeeNectar,
-    EntitySetBlockDisplayBlock,
-    EntitySetCamelDashing,
-    EntitySetCarryingChest,
-    EntitySetCatLyingDown,
-    EntitySetCatType,
-    EntitySetCelebrating,
-    EntitySetCollidable,
-    EntitySetCreeperCharge,
-    EntitySetCreeperFuse,
-    EntitySetCurrentHealth,
-    EntitySetCustomName,
-    EntitySetCustomNameVisibility,
-    EntitySetCustomTag,
-    EntitySetDeathDrops,
-    EntitySetDeathTime,
-    EntitySetDefaultVisible,
-    EntitySetDespawning,
-    EntitySetDisplayBillboard,
-    EntitySetDisplayBrightness,
-    EntitySetDisplayCullingSuze,
-    EntitySetDisplayGlowColor,
-    EntitySetDisplayInterpolation,
-    EntitySetDisplayRotationFromAxisAngle,
-    EntitySetDisplayRotationFromEulerAngles,
-    EntitySetDisplayScale,
-    EntitySetDisplayShadow,
-    EntitySetDisplayTeleportDuration,
-    EntitySetDisplayTransformationMatrix,
-    EntitySetDisplayTranslation,
-    EntitySetDisplayViewRange,
-    EntitySetDragonPhase,
-    EntitySetDyeColor,
-    EntitySetEndCrystalBeam,
-    EntitySetEndermanBlock,
-    EntitySetEquipmentItem,
-    EntitySetExplosivePower,
-    EntitySetFallDistance,
-    EntitySetFallingBlockType,
-    EntitySetFireTicks,
-    EntitySetFishingWait,
-    EntitySetFoxLeaping,
-    EntitySetFoxType,
-    EntitySetFreezeTicks,
-    EntitySetFriction,
-    EntitySetFrogType,
-    EntitySetFuseTicks,
-    EntitySetGliding,
-    EntitySetGlowSquidDark,
-    EntitySetGlowing,
-    EntitySetGoatScreaming,
-    EntitySetGravity,
-    EntitySetHorseJump,
-    EntitySetHorsePattern,
-    EntitySetImmuneToZombification,
-    EntitySetInteractionResponsive,
-    EntitySetInteractionSize,
-    EntitySetInvisible,
-    EntitySetInvulnerabilityTicks,
-    EntitySetInvulnerable,
-    EntitySetItem,
-    EntitySetItemDisplayItem,
-    EntitySetItemDisplayModelType,
-    EntitySetItemInFrame,
-    EntitySetLlamaType,
-    EntitySetLocation,
-    EntitySetMarker,
-    EntitySetMaxHealth,
-    EntitySetMerchantRecipe,
-    EntitySetMinecartBlock,
-    EntitySetMobAggressive,
-    EntitySetMushroomCowType,
-    EntitySetNoPhysics,
-    EntitySetPandaGene,
-    EntitySetPandaOnBack,
-    EntitySetPandaRolling,
-    EntitySetPandaSadTicks,
-    EntitySetParrotType,
-    EntitySetPersistence,
-    EntitySetPickup,
-    EntitySetPickupDelay,
-    EntitySetPiglinAbleToHunt,
-    EntitySetPiglinChargingCrossbow,
-    EntitySetPiglinDancing,
-    EntitySetPose,
-    EntitySetPotionCloudRadius,
-    EntitySetPrimedTntBlock,
-    EntitySetProjectileDisplayItem,
-    EntitySetProjectilePower,
-    EntitySetProjectileShooter,
-    EntitySetRabbitType,
-    EntitySetRearing,
-    EntitySetRiptiding,
-    EntitySetRotation,
-    EntitySetRotationByVector,
-    EntitySetSheepSheared,
-    EntitySetShulkerBulletTarget,
-    EntitySetShulkerPeek,
-    EntitySetSilenced,
-    EntitySetSitting,
-    EntitySetSize,
-    EntitySetSnifferState,
-    EntitySetSnowmanPumpkin,
-    EntitySetTame,
-    EntitySetTarget,
-    EntitySetTextDisplayAlignment,
-    EntitySetTextDisplayBackground,
-    EntitySetTextDisplayLineWidth,
-    EntitySetTextDisplayOpacity,
-    EntitySetTextDisplaySeeThrough,
-    EntitySetTextDisplayText,
-    EntitySetTextDisplayTextShadow,
-    EntitySetTropicalFishPattern,
-    EntitySetVexCharging,
-    EntitySetVexLimitedLifetimeTicks,
-    EntitySetVillagerBiome,
-    EntitySetVillagerExperience,
-    EntitySetVillagerProfession,
-    EntitySetVisualFire,
-    EntitySetWardenAngerLevel,
-    EntitySetWardenDigging,
-    EntitySetWearingSaddle,
-    EntitySetWitherInvulnerabilityTicks,
-    EntitySetWolfType,
-    EntitySetZombieArmsRaised,
-    EntityShear,
-    EntityShearSheep,
-    EntitySleep,
-    EntitySwingHand,
-    EntityTeleport,
-    EntityUseItem,
-    GameBlockGrowth,
-    GameBloomSkulkCatalyst,
-    GameBoneMealBlock,
-    GameBreakBlock,
-    GameCancelEvent,
-    GameClearContainer,
-    GameClearContainerItems,
-    GameClearExplodedBlocks,
-    GameClearRegion,
-    GameClearScoreboardScores,
-    GameCloneRegion,
-    GameCreateExplosion,
-    GameCreateScoreboard,
-    GameDummy,
-    GameFillContainer,
-    GameGenerateTree,
-    GameHideEventMessage,
-    GameLaunchFirework,
-    GameLaunchProjectile,
-    GameRandomTickBlock,
-    GameRemoveContainerItems,
-    GameRemoveScoreboard,
-    GameRemoveScoreboardScoreByName,
-    GameRemoveScoreboardScoreByScore,
-    GameReplaceBlocksInRegion,
-    GameReplaceContainerItems,
-    GameSendWebRequest,
-    GameSetAge,
-    GameSetBlock,
-    GameSetBlockAnaloguePower,
-    GameSetBlockCustomTag,
-    GameSetBlockData,
-    GameSetBlockDropsEnabled,
-    GameSetBlockPowered,
-    GameSetBlockSingleData,
-    GameSetBrushableBlockItem,
-    GameSetCampfireItem,
-    GameSetContainer,
-    GameSetContainerLock,
-    GameSetContainerName,
-    GameSetDecoratePotSherd,
-    GameSetEventDamage,
-    GameSetEventExhaustion,
-    GameSetEventExperience,
-    GameSetEventHeal,
-    GameSetEventItem,
-    GameSetEventItems,
-    GameSetEventKnockbackVector,
-    GameSetEventMoveAllowed,
-    GameSetEventProjectile,
-    GameSetEventSound,
-    GameSetEventSourceSlot,
-    GameSetEventTargetSlot,
-    GameSetEventUeryInfo,
-    GameSetEventVelocity,
-    GameSetFurnaceCookTime,
-    GameSetItemInContainerSlot,
-    GameSetLecternBook,
-    GameSetPlayerHead,
-    GameSetRegion,
-    GameSetScoreboardLine,
-    GameSetScoreboardLineDisplay,
-    GameSetScoreboardLineFormat,
-    GameSetScoreboardNumberFormat,
-    GameSetScoreboardScore,
-    GameSetScoreboardTitle,
-    GameSetSculkShriekerCanSummon,
-    GameSetSculkShriekerShrieking,
-    GameSetSculkShriekerWarningLevel,
-    GameSetSignText,
-    GameSetSignTextColor,
-    GameSetSignWaxed,
-    GameSetSpawnerEntity,
-    GameSetWorldDifficulty,
-    GameSetWorldGamerule,
-    GameSetWorldSimulationDistance,
-    GameSetWorldTime,
-    GameSetWorldWeather,
-    GameSpawnArmorStand,
-    GameSpawnBlockDisplay,
-    GameSpawnEffectCloud,
-    GameSpawnEndCrystal,
-    GameSpawnEvokerFangs,
-    GameSpawnExperienceOrb,
-    GameSpawnEyeOfEnder,
-    GameSpawnFallingBlock,
-    GameSpawnInteractionEntity,
-    GameSpawnItem,
-    GameSpawnItemDisplay,
-    GameSpawnLightningBolt,
-    GameSpawnMob,
-    GameSpawnPrimedTnt,
-    GameSpawnShulkerBullet,
-    GameSpawnTextDisplay,
-    GameSpawnVehicle,
-    GameUncancelEvent,
-    GameUpdateBlock,
-    IfEntityCollidesAtLocation,
-    IfEntityCollidesUsingHitbox,
-    IfEntityCollidesWithEntity,
-    IfEntityDummy,
-    IfEntityExists,
-    IfEntityHasCustomTag,
-    IfEntityHasPotionEffect,
-    IfEntityInArea,
-    IfEntityIsDisguised,
-    IfEntityIsGrounded,
-    IfEntityIsItem,
-    IfEntityIsMob,
-    IfEntityIsNearLocation,
-    IfEntityIsProjectile,
-    IfEntityIsRidingEntity,
-    IfEntityIsStandingOnBlock,
-    IfEntityIsType,
-    IfEntityIsUndead,
-    IfEntityIsVehicle,
-    IfEntityNameEquals,
-    IfEntitySpawnReasonEquals,
-    IfGameBlockEquals,
-    IfGameBlockPowered,
-    IfGameChunkIsLoaded,
-    IfGameContainerHas,
-    IfGameContainerHasRoomForItem,
-    IfGameDamageCauseEquals,
-    IfGameDummy,
-    IfGameEventAttackIsCritical,
-    IfGameEventBlockEquals,
-    IfGameEventIsCanceled,
-    IfGameEventItemEquals,
-    IfGameHasPlayer,
-    IfGameHealCauseEquals,
-    IfGameIgniteCauseEquals,
-    IfGameInstrumentEquals,
-    IfGameLocationInBlock,
-    IfGameSignContains,
-    IfPlayerChatColorsEnabled,
-    IfPlayerChatMessageEquals,
-    IfPlayerCollidesAtLocation,
-    IfPlayerCollidesUsingHitbox,
-    IfPlayerCollidesWithEntity,
-    IfPlayerCursorItemEquals,
-    IfPlayerDummy,
-    IfPlayerGamemodeEquals,
-    IfPlayerHasItem,
-    IfPlayerHasItemAtLeast,
-    IfPlayerHasItemInSlot,
-    IfPlayerHasPotionEffect,
-    IfPlayerHasPrivilege,
-    IfPlayerHasRoomForItem,
-    IfPlayerHotbarSlotEquals,
-    IfPlayerInArea,
-    IfPlayerInventoryMenuSlotEquals,
-    IfPlayerInventoryTypeOpen,
-    IfPlayerIsAllowServerListing,
-    IfPlayerIsBlocking,
-    IfPlayerIsDisguised,
-    IfPlayerIsFlying,
-    IfPlayerIsGliding,
-    IfPlayerIsHolding,
-    IfPlayerIsLookingAtBlock,
-    IfPlayerIsNear,
-    IfPlayerIsOnGround,
-    IfPlayerIsOnlineMode,
-    IfPlayerIsRidingEntity,
-    IfPlayerIsSelfDisguised,
-    IfPlayerIsSleeping,
-    IfPlayerIsSneaking,
-    IfPlayerIsSprinting,
-    IfPlayerIsStandingOnBlock,
-    IfPlayerIsSwimming,
-    IfPlayerIsUsingItem,
-    IfPlayerIsWearingItem,
-    IfPlayerItemIsNotOnCooldown,
-    IfPlayerNameEquals,
-    IfPlayerTextFilteringEnabled,
-    IfVariableBlockIsSolid,
-    IfVariableDummy,
-    IfVariableEquals,
-    IfVariableExists,
-    IfVariableGreater,
-    IfVariableGreaterOrEquals,
-    IfVariableInRange,
-    IfVariableIsType,
-    IfVariableItemEquals,
-    IfVariableItemHasEnchantment,
-    IfVariableItemHasTag,
-    IfVariableItemIsBlock,
-    IfVariableLess,
-    IfVariableLessOrEquals,
-    IfVariableListContainsValue,
-    IfVariableListIsEmpty,
-    IfVariableListValueEquals,
-    IfVariableLocationInRange,
-    IfVariableLocationIsNear,
-    IfVariableMapHasKey,
-    IfVariableMapValueEquals,
-    IfVariableNotEquals,
-    IfVariableRangeIntersectsRange,
-    IfVariableTextContains,
-    IfVariableTextEndsWith,
-    IfVariableTextMatches,
-    IfVariableTextStartsWith,
-    PlayerAddInventoryMenuRow,
-    PlayerAllowPlacingBreakingBlocks,
-    PlayerBoostElytra,
-    PlayerClearChat,
-    PlayerClearDebugMarkers,
-    PlayerClearEnderChestContents,
-    PlayerClearInventory,
-    PlayerClearItems,
-    PlayerClearPotionEffects,
-    PlayerCloseInventory,
-    PlayerDamage,
-    PlayerDisguiseAsBlock,
-    PlayerDisguiseAsEntity,
-    PlayerDisguiseAsItem,
-    PlayerDisplayBellRing,
-    PlayerDisplayBlock,
-    PlayerDisplayEndGatewayBeam,
-    PlayerDisplayHologram,
-    PlayerDisplayLightning,
-    PlayerDisplayParticle,
-    PlayerDisplayParticleCircle,
-    PlayerDisplayParticleCube,
-    PlayerDisplayParticleLine,
-    PlayerDisplayParticleRay,
-    PlayerDisplayParticleSphere,
-    PlayerDisplayParticleSpiral,
-    PlayerDisplayPickUpAnimation,
-    PlayerDisplaySignText,
-    PlayerDisplayVibration,
-    PlayerDummy,
-    PlayerExpandInventoryMenu,
-    PlayerFaceLocation,
-    PlayerForceFlightMode,
-    PlayerGiveExperience,
-    PlayerGiveItems,
-    PlayerGivePotionEffect,
-    PlayerGiveRandomItem,
-    PlayerHeal,
-    PlayerHideEntity,
-    PlayerHideScoreboard,
-    PlayerKick,
-    PlayerLaunchForward,
-    PlayerLaunchProjectile,
-    PlayerLaunchToLocation,
-    PlayerLaunchUp,
-    PlayerLeaveVehicle,
-    PlayerLoadInventory,
-    PlayerOpenBook,
-    PlayerOpenContainerInventory,
-    PlayerPlayAnimationAction,
-    PlayerPlayHurtAnimation,
-    PlayerPlaySound,
-    PlayerPlaySoundFromEntity,
-    PlayerPlaySoundSequence,
-    PlayerRandomizedTeleport,
-    PlayerRedirectWorld,
-    PlayerRemoveBossBar,
-    PlayerRemoveDisguise,
-    PlayerRemoveDisplayBlocks,
-    PlayerRemoveInventoryMenuRow,
-    PlayerRemoveItems,
-    PlayerRemovePose,
-    PlayerRemovePotionEffect,
-    PlayerRemoveSelfDisguise,
-    PlayerRemoveSkin,
-    PlayerRemoveWorldBorder,
-    PlayerReplaceItems,
-    PlayerResetWeather,
-    PlayerRideEntity,
-    PlayerSaveInventory,
-    PlayerSelfDisguiseAsBlock,
-    PlayerSelfDisguiseAsEntity,
-    PlayerSelfDisguiseAsItem,
-    PlayerSendActionBar,
-    PlayerSendAdvancement,
-    PlayerSendBreakAnimation,
-    PlayerSendDialogue,
-    PlayerSendHover,
-    PlayerSendMessage,
-    PlayerSendMinimessage,
-    PlayerSendTitle,
-    PlayerSetAbsorptionHealth,
-    PlayerSetAirTicks,
-    PlayerSetAllowFlying,
-    PlayerSetArmor,
-    PlayerSetArrowsInBody,
-    PlayerSetAttackSpeed,
-    PlayerSetAttribute,
-    PlayerSetBeeStingersInBody,
-    PlayerSetBlockOpenedState,
-    PlayerSetBossBar,
-    PlayerSetChatCompletions,
-    PlayerSetCollidable,
-    PlayerSetCompassTarget,
-    PlayerSetCursorItem,
-    PlayerSetDeathDrops,
-    PlayerSetDefaultVisible,
-    PlayerSetEnderChestContents,
-    PlayerSetEntityGlowing,
-    PlayerSetEquipment,
-    PlayerSetExhaustion,
-    PlayerSetExperience,
-    PlayerSetFallDistance,
-    PlayerSetFireTicks,
-    PlayerSetFlying,
-    PlayerSetFogDistance,
-    PlayerSetFood,
-    PlayerSetFreezeTicks,
-    PlayerSetGamemode,
-    PlayerSetGliding,
-    PlayerSetHealth,
-    PlayerSetHotbarSlot,
-    PlayerSetInstantRespawn,
-    PlayerSetInventoryKept,
-    PlayerSetInventoryMenuItem,
-    PlayerSetInventoryMenuName,
-    PlayerSetInvulnerabilityTicks,
-    PlayerSetItemCooldown,
-    PlayerSetItems,
-    PlayerSetMaxHealth,
-    PlayerSetMovementSpeed,
-    PlayerSetNametagVisible,
-    PlayerSetPlayerListInfo,
-    PlayerSetPose,
-    PlayerSetPvp,
-    PlayerSetRainLevel,
-    PlayerSetRotation,
-    PlayerSetRotationByVector,
-    PlayerSetSaturation,
-    PlayerSetSimulationDistance,
-    PlayerSetSkin,
-    PlayerSetSlotItem,
-    PlayerSetSpawnPoint,
-    PlayerSetThunderLevel,
-    PlayerSetTickRate,
-    PlayerSetTime,
-    PlayerSetVelocity,
-    PlayerSetVisualFire,
-    PlayerSetWeather,
-    PlayerSetWorldBorder,
-    PlayerShiftWorldBorder,
-    PlayerShowDebugMarker,
-    PlayerShowDemoScreen,
-    PlayerShowInventoryMenu,
-    PlayerShowScoreboard,
-    PlayerShowWinScreen,
-    PlayerSpectateTarget,
-    PlayerStopSound,
-    PlayerStopSoundsBySource,
-    PlayerSwingHand,
-    PlayerTeleport,
-    PlayerTeleportSequence,
-    RepeatAdjacently,
-    RepeatDummy,
-    RepeatForEachInList,
-    RepeatForEachMapEntry,
-    RepeatForever,
-    RepeatMultiTimes,
-    RepeatOnCircle,
-    RepeatOnGrid,
-    RepeatOnPath,
-    RepeatOnRange,
-    RepeatOnSphere,
-    RepeatWhile,
-    SelectAddAllEntities,
-    SelectAddAllMobs,
-    SelectAddAllPlayers,
-    SelectAddEntityByConditional,
-    SelectAddEntityByName,
-    SelectAddEventTarget,
-    SelectAddLastEntity,
-    SelectAddLastMob,
-    SelectAddMobByName,
-    SelectAddPlayerByConditional,
-    SelectAddPlayerByName,
-    SelectAddRandomEntity,
-    SelectAddRandomMob,
-    SelectAddRandomPlayer,
-    SelectAllEntities,
-    SelectAllMobs,
-    SelectAllPlayers,
-    SelectDummy,
-    SelectEntityByConditional,
-    SelectEntityByName,
-    SelectEventTarget,
-    SelectFilterByConditional,
-    SelectFilterByDistance,
-    SelectFilterByRaycast,
-    SelectFilterRandomly,
-    SelectInvert,
-    SelectLastEntity,
-    SelectLastMob,
-    SelectMobByName,
-    SelectPlayerByConditional,
-    SelectPlayerByName,
-    SelectRandomEntity,
-    SelectRandomMob,
-    SelectRandomPlayer,
-    SelectReset,
-    SetVariableAbsolute,
-    SetVariableAdd,
-    SetVariableAddItemEnchantment,
-    SetVariableAddItemPotionEffects,
-    SetVariableAddVectors,
-    SetVariableAlignLocation,
-    SetVariableAlignToAxisVector,
-    SetVariableAppendComponent,
-    SetVariableAppendList,
-    SetVariableAppendMap,
-    SetVariableAppendValue,
-    #[serde(rename = "set_variable_atan2")]
-    SetVariableAtan2,
-    SetVariableAverage,
-    SetVariableBitwiseOperation,
-    SetVariableCenterLocation,
-    SetVariableChangeComponentParsing,
-    SetVariableCharToNumber,
-    SetVariableClamp,
-    SetVariableClearColorCodes,
-    SetVariableClearMap,
-    SetVariableCompactComponent,
-    SetVariableComponentOfChildren,
-    SetVariableConvertNumberToText,
-    SetVariableConvertTextToNumber,
-    SetVariableCosine,
-    SetVariableCotangent,
-    SetVariableCreateKeybindComponent,
-    SetVariableCreateList,
-    SetVariableCreateMap,
-    SetVariableCreateMapFromValues,
-    SetVariableCreateTranslatableComponent,
-    SetVariableDecrement,
-    SetVariableDivide,
-    SetVariableDivideVector,
-    SetVariableDummy,
-    SetVariableFaceLocation,
-    SetVariableFlattenList,
-    SetVariableFormatTimestamp,
-    SetVariableGaussianDistribution,
-    SetVariableGetAllBlockData,
-    SetVariableGetAllCoordinates,
-    SetVariableGetAngleBetweenVectors,
-    SetVariableGetBlockCustomTag,
-    SetVariableGetBlockData,
-    SetVariableGetBlockGrowth,
-    SetVariableGetBlockMaterial,
-    SetVariableGetBlockMaterialProperty,
-    SetVariableGetBlockPower,
-    SetVariableGetBlockSound,
-    SetVariableGetBookText,
-    SetVariableGetBrushableBlockItem,
-    SetVariableGetBundleItems,
-    SetVariableGetCharAt,
-    SetVariableGetColorChannels,
-    SetVariableGetCompassLodestone,
-    SetVariableGetComponentChildren,
-    SetVariableGetComponentDecorations,
-    SetVariableGetComponentHexColor,
-    SetVariableGetComponentParsing,
-    SetVariableGetContainerContents,
-    SetVariableGetContainerLock,
-    SetVariableGetContainerName,
-    SetVariableGetCoordinate,
-    SetVariableGetDecoratePotSherd,
-    SetVariableGetIndexOfSubtext,
-    SetVariableGetItemAmount,
-    SetVariableGetItemAttribute,
-    SetVariableGetItemColor,
-    SetVariableGetItemCustomModelData,
-    SetVariableGetItemCustomTag,
-    SetVariableGetItemCustomTags,
-    SetVariableGetItemDestroyableBlocks,
-    SetVariableGetItemDurability,
-    SetVariableGetItemEnchantments,
-    SetVariableGetItemLore,
-    SetVariableGetItemLoreLine,
-    SetVariableGetItemMaxStackSize,
-    SetVariableGetItemName,
-    SetVariableGetItemNbtTags,
-    SetVariableGetItemPlaceableBlocks,
-    SetVariableGetItemPotionEffects,
-    SetVariableGetItemRarity,
-    SetVariableGetItemType,
-    SetVariableGetLecternBook,
-    SetVariableGetLecternPage,
-    SetVariableGetLightLevel,
-    SetVariableGetListIndexOfValue,
-    SetVariableGetListLength,
-    SetVariableGetListRandomValue,
-    SetVariableGetListValue,
-    SetVariableGetListVariables,
-    SetVariableGetLocationDirection,
-    SetVariableGetMapKeyByIndex,
-    SetVariableGetMapKeys,
-    SetVariableGetMapKeysByValue,
-    SetVariableGetMapSize,
-    SetVariableGetMapValue,
-    SetVariableGetMapValueByIndex,
-    SetVariableGetMapValues,
-    SetVariableGetMidpointBetweenVectors,
-    SetVariableGetParticleAmount,
-    SetVariableGetParticleColor,
-    SetVariableGetParticleMaterial,
-    SetVariableGetParticleOffset,
-    SetVariableGetParticleSize,
-    SetVariableGetParticleSpread,
-    SetVariableGetParticleType,
-    SetVariableGetPlayerHead,
-    SetVariableGetPlayerHeadOwner,
-    SetVariableGetPlayerHeadValue,
-    SetVariableGetPotionEffectAmplifier,
-    SetVariableGetPotionEffectDuration,
-    SetVariableGetPotionEffectType,
-    SetVariableGetSculkShriekerWarningLevel,
-    SetVariableGetSignText,
-    SetVariableGetSoundPitch,
-    SetVariableGetSoundSource,
-    SetVariableGetSoundType,
-    SetVariableGetSoundVariation,
-    SetVariableGetSoundVariations,
-    SetVariableGetSoundVolumeAction,
-    SetVariableGetTemplateCode,
-    SetVariableGetTextWidth,
-    SetVariableGetVectorAllComponents,
-    SetVariableGetVectorBetweenLocations,
-    SetVariableGetVectorComponent,
-    SetVariableGetVectorFromBlockFace,
-    SetVariableGetVectorLength,
-    SetVariableHash,
-    SetVariableIncrement,
-    SetVariableInsertListValue,
-    SetVariableJoinText,
-    SetVariableLerpNumber,
-    SetVariableLocationRelative,
-    SetVariableLocationsDistance,
-    SetVariableLog,
-    SetVariableMapRange,
-    SetVariableMax,
-    SetVariableMin,
-    SetVariableMultiply,
-    SetVariableMultiplyVector,
-    SetVariableParseJson,
-    SetVariableParseToComponent,
-    #[serde(rename = "set_variable_perlin_noise_3d")]
-    SetVariablePerlinNoise3d,
-    SetVariablePow,
-    SetVariablePurge,
-    SetVariableRandom,
-    SetVariableRandomLocation,
-    SetVariableRandomNumber,
-    SetVariableRandomizeListOrder,
-    SetVariableRayTraceResult,
-    SetVariableReflectVectorProduct,
-    SetVariableRegexReplaceText,
-    SetVariableRemainder,
-    SetVariableRemoveCompassLodestone,
-    SetVariableRemoveEnchantment,
-    SetVariableRemoveItemAttribute,
-    SetVariableRemoveItemCustomModelData,
-    SetVariableRemoveItemCustomTag,
-    SetVariableRemoveItemLoreLine,
-    SetVariableRemoveItemPotionEffects,
-    SetVariableRemoveListDuplicates,
-    SetVariableRemoveListValue,
-    SetVariableRemoveListValueAtIndex,
-    SetVariableRemoveMapEntry,
-    SetVariableRemoveText,
-    SetVariableRepeatText,
-    SetVariableReplaceText,
-    SetVariableReverseList,
-    SetVariableRoot,
-    SetVariableRotateVectorAroundAxis,
-    SetVariableRotateVectorAroundVector,
-    SetVariableRound,
-    SetVariableSetAllCoordinates,
-    SetVariableSetArmorTrim,
-    SetVariableSetBookPage,
-    SetVariableSetBookPages,
-    SetVariableSetBundleItems,
-    SetVariableSetCompassLodestone,
-    SetVariableSetComponentChildren,
-    SetVariableSetComponentClick,
-    SetVariableSetComponentDecorations,
-    SetVariableSetComponentEntityHover,
-    SetVariableSetComponentFont,
-    SetVariableSetComponentHexColor,
-    SetVariableSetComponentHover,
-    SetVariableSetComponentInsertion,
-    SetVariableSetComponentItemHover,
-    SetVariableSetCoordinate,
-    SetVariableSetItemAmount,
-    SetVariableSetItemAttribute,
-    SetVariableSetItemColor,
-    SetVariableSetItemComponent,
-    SetVariableSetItemCustomModelData,
-    SetVariableSetItemCustomTag,
-    SetVariableSetItemDestroyableBlocks,
-    SetVariableSetItemDurability,
-    SetVariableSetItemEnchantments,
-    SetVariableSetItemLore,
-    SetVariableSetItemLoreLine,
-    SetVariableSetItemMaxStackSize,
-    SetVariableSetItemName,
-    SetVariableSetItemPlaceableBlocks,
-    SetVariableSetItemType,
-    SetVariableSetItemUnbreakable,
-    SetVariableSetItemVisibilityFlags,
-    SetVariableSetListValue,
-    SetVariableSetLocationDirection,
-    SetVariableSetMapValue,
-    SetVariableSetParticleAmount,
-    SetVariableSetParticleColor,
-    SetVariableSetParticleMaterial,
-    SetVariableSetParticleOffset,
-    SetVariableSetParticleSize,
-    SetVariableSetParticleSpread,
-    SetVariableSetParticleType,
-    SetVariableSetPotionEffectAmplifier,
-    SetVariableSetPotionEffectDuration,
-    SetVariableSetPotionEffectType,
-    SetVariableSetSoundPitch,
-    SetVariableSetSoundSource,
-    SetVariableSetSoundType,
-    SetVariableSetSoundVariation,
-    SetVariableSetSoundVolumeAction,
-    SetVariableSetTemplateCode,
-    SetVariableSetTextureToMap,
-    SetVariableSetVectorComponent,
-    SetVariableSetVectorLength,
-    SetVariableShiftAllCoordinates,
-    SetVariableShiftCoordinate,
-    SetVariableShiftLocationInDirection,
-    SetVariableShiftLocationOnVector,
-    SetVariableShiftLocationTowardsLocation,
-    #[serde(rename = "set_variable_simplex_noise_3d")]
-    SetVariableSimplexNoise3d,
-    SetVariableSine,
-    SetVariableSortAnyList,
-    SetVariableSortAnyMap,
-    SetVariableSplitText,
-    SetVariableStripText,
-    SetVariableSubtract,
-    SetVariableSubtractVectors,
-    SetVariableTangent,
-    SetVariableText,
-    SetVariableTextCase,
-    SetVariableTextLength,
-    SetVariableToChar,
-    SetVariableToHsb,
-    SetVariableToHsl,
-    SetVariableToJson,
-    SetVariableToRgb,
-    SetVariableTrimList,
-    SetVariableTrimText,
-    SetVariableValue,
-    SetVariableVector,
-    SetVariableVectorCrossProduct,
-    SetVariableVectorDotProduct,
-    SetVariableVectorToDirectionName,
-    #[serde(rename = "set_variable_voronoi_noise_3d")]
-    SetVariableVoronoiNoise3d,
-    SetVariableWarp,
-    StartProcess,
+	CallFunction,
+	ControlCallException,
+	ControlDummy,
+	ControlEndThread,
+	ControlReturnFunction,
+	ControlSkipIteration,
+	ControlStopRepeat,
+	ControlWait,
+	ControllerAsyncRun,
+	ControllerException,
+	ControllerMeasureTime,
+	Else,
+	EntityAttachLead,
+	EntityCelarPotionEffects,
+	EntityClearMerchantRecipes,
+	EntityDamage,
+	EntityDisguiseAsBlock,
+	EntityDisguiseAsEntity,
+	EntityDisguiseAsItem,
+	EntityDisguiseAsPlayer,
+	EntityDummy,
+	EntityEatGrass,
+	EntityEatTarget,
+	EntityExplode,
+	EntityFaceLocation,
+	EntityGetCustomTag,
+	EntityGivePotionEffects,
+	EntityHeal,
+	EntityIgniteCreeper,
+	EntityJump,
+	EntityLaunchForward,
+	EntityLaunchProjectile,
+	EntityLaunchToLocation,
+	EntityLaunchUp,
+	EntityLeaveVehicle,
+	EntityModifyPiglinBarterMaterials,
+	EntityModifyPiglinInterestedMaterials,
+	EntityMoveToLocation,
+	EntityMoveToLocationStop,
+	EntityPlayDamageAnimation,
+	EntityPlayHurtAnimation,
+	EntityRamTarget,
+	EntityRemove,
+	EntityRemoveCustomTag,
+	EntityRemoveDisguise,
+	EntityRemoveMerchantRecipe,
+	EntityRemovePotionEffect,
+	EntityResetDisplayBrightness,
+	EntityResetDisplayGlowColor,
+	EntityResetTextDisplayBackground,
+	EntityRideEntity,
+	EntitySetAbsorptionHealth,
+	EntitySetAi,
+	EntitySetAllayDancing,
+	EntitySetAngry,
+	EntitySetAnimalAge,
+	EntitySetArmorItems,
+	EntitySetArmorStandParts,
+	EntitySetArmorStandPose,
+	EntitySetArrowHitSound,
+	EntitySetArrowPierce,
+	EntitySetAttribute,
+	EntitySetAware,
+	EntitySetAxolotlType,
+	EntitySetBaby,
+	EntitySetBaseArrowDamage,
+	EntitySetBeeHasStinger,
+	EntitySetBeeNectar,
+	EntitySetBlockDisplayBlock,
+	EntitySetCamelDashing,
+	EntitySetCarryingChest,
+	EntitySetCatLyingDown,
+	EntitySetCatType,
+	EntitySetCelebrating,
+	EntitySetCollidable,
+	EntitySetCreeperCharge,
+	EntitySetCreeperFuse,
+	EntitySetCurrentHealth,
+	EntitySetCustomName,
+	EntitySetCustomNameVisibility,
+	EntitySetCustomTag,
+	EntitySetDeathDrops,
+	EntitySetDeathTime,
+	EntitySetDefaultVisible,
+	EntitySetDespawning,
+	EntitySetDisplayBillboard,
+	EntitySetDisplayBrightness,
+	EntitySetDisplayCullingSuze,
+	EntitySetDisplayGlowColor,
+	EntitySetDisplayInterpolation,
+	EntitySetDisplayRotationFromAxisAngle,
+	EntitySetDisplayRotationFromEulerAngles,
+	EntitySetDisplayScale,
+	EntitySetDisplayShadow,
+	EntitySetDisplayTeleportDuration,
+	EntitySetDisplayTransformationMatrix,
+	EntitySetDisplayTranslation,
+	EntitySetDisplayViewRange,
+	EntitySetDragonPhase,
+	EntitySetDyeColor,
+	EntitySetEndCrystalBeam,
+	EntitySetEndermanBlock,
+	EntitySetEquipmentItem,
+	EntitySetExplosivePower,
+	EntitySetFallDistance,
+	EntitySetFallingBlockType,
+	EntitySetFireTicks,
+	EntitySetFishingWait,
+	EntitySetFoxLeaping,
+	EntitySetFoxType,
+	EntitySetFreezeTicks,
+	EntitySetFriction,
+	EntitySetFrogType,
+	EntitySetFuseTicks,
+	EntitySetGliding,
+	EntitySetGlowSquidDark,
+	EntitySetGlowing,
+	EntitySetGoatScreaming,
+	EntitySetGravity,
+	EntitySetHorseJump,
+	EntitySetHorsePattern,
+	EntitySetImmuneToZombification,
+	EntitySetInteractionResponsive,
+	EntitySetInteractionSize,
+	EntitySetInvisible,
+	EntitySetInvulnerabilityTicks,
+	EntitySetInvulnerable,
+	EntitySetItem,
+	EntitySetItemDisplayItem,
+	EntitySetItemDisplayModelType,
+	EntitySetItemInFrame,
+	EntitySetLlamaType,
+	EntitySetLocation,
+	EntitySetMarker,
+	EntitySetMaxHealth,
+	EntitySetMerchantRecipe,
+	EntitySetMinecartBlock,
+	EntitySetMobAggressive,
+	EntitySetMushroomCowType,
+	EntitySetNoPhysics,
+	EntitySetPandaGene,
+	EntitySetPandaOnBack,
+	EntitySetPandaRolling,
+	EntitySetPandaSadTicks,
+	EntitySetParrotType,
+	EntitySetPersistence,
+	EntitySetPickup,
+	EntitySetPickupDelay,
+	EntitySetPiglinAbleToHunt,
+	EntitySetPiglinChargingCrossbow,
+	EntitySetPiglinDancing,
+	EntitySetPose,
+	EntitySetPotionCloudRadius,
+	EntitySetPrimedTntBlock,
+	EntitySetProjectileDisplayItem,
+	EntitySetProjectilePower,
+	EntitySetProjectileShooter,
+	EntitySetRabbitType,
+	EntitySetRearing,
+	EntitySetRiptiding,
+	EntitySetRotation,
+	EntitySetRotationByVector,
+	EntitySetSheepSheared,
+	EntitySetShulkerBulletTarget,
+	EntitySetShulkerPeek,
+	EntitySetSilenced,
+	EntitySetSitting,
+	EntitySetSize,
+	EntitySetSnifferState,
+	EntitySetSnowmanPumpkin,
+	EntitySetTame,
+	EntitySetTarget,
+	EntitySetTextDisplayAlignment,
+	EntitySetTextDisplayBackground,
+	EntitySetTextDisplayLineWidth,
+	EntitySetTextDisplayOpacity,
+	EntitySetTextDisplaySeeThrough,
+	EntitySetTextDisplayText,
+	EntitySetTextDisplayTextShadow,
+	EntitySetTropicalFishPattern,
+	EntitySetVexCharging,
+	EntitySetVexLimitedLifetimeTicks,
+	EntitySetVillagerBiome,
+	EntitySetVillagerExperience,
+	EntitySetVillagerProfession,
+	EntitySetVisualFire,
+	EntitySetWardenAngerLevel,
+	EntitySetWardenDigging,
+	EntitySetWearingSaddle,
+	EntitySetWitherInvulnerabilityTicks,
+	EntitySetWolfType,
+	EntitySetZombieArmsRaised,
+	EntityShear,
+	EntityShearSheep,
+	EntitySleep,
+	EntitySwingHand,
+	EntityTeleport,
+	EntityUseItem,
+	GameBlockGrowth,
+	GameBloomSkulkCatalyst,
+	GameBoneMealBlock,
+	GameBreakBlock,
+	GameCancelEvent,
+	GameClearContainer,
+	GameClearContainerItems,
+	GameClearExplodedBlocks,
+	GameClearRegion,
+	GameClearScoreboardScores,
+	GameCloneRegion,
+	GameCreateExplosion,
+	GameCreateScoreboard,
+	GameDummy,
+	GameFillContainer,
+	GameGenerateTree,
+	GameHideEventMessage,
+	GameLaunchFirework,
+	GameLaunchProjectile,
+	GameRandomTickBlock,
+	GameRemoveContainerItems,
+	GameRemoveScoreboard,
+	GameRemoveScoreboardScoreByName,
+	GameRemoveScoreboardScoreByScore,
+	GameReplaceBlocksInRegion,
+	GameReplaceContainerItems,
+	GameSendWebRequest,
+	GameSetAge,
+	GameSetBlock,
+	GameSetBlockAnaloguePower,
+	GameSetBlockCustomTag,
+	GameSetBlockData,
+	GameSetBlockDropsEnabled,
+	GameSetBlockPowered,
+	GameSetBlockSingleData,
+	GameSetBrushableBlockItem,
+	GameSetCampfireItem,
+	GameSetContainer,
+	GameSetContainerLock,
+	GameSetContainerName,
+	GameSetDecoratePotSherd,
+	GameSetEventDamage,
+	GameSetEventExhaustion,
+	GameSetEventExperience,
+	GameSetEventHeal,
+	GameSetEventItem,
+	GameSetEventItems,
+	GameSetEventKnockbackVector,
+	GameSetEventMoveAllowed,
+	GameSetEventProjectile,
+	GameSetEventSound,
+	GameSetEventSourceSlot,
+	GameSetEventTargetSlot,
+	GameSetEventUeryInfo,
+	GameSetEventVelocity,
+	GameSetFurnaceCookTime,
+	GameSetItemInContainerSlot,
+	GameSetLecternBook,
+	GameSetPlayerHead,
+	GameSetRegion,
+	GameSetScoreboardLine,
+	GameSetScoreboardLineDisplay,
+	GameSetScoreboardLineFormat,
+	GameSetScoreboardNumberFormat,
+	GameSetScoreboardScore,
+	GameSetScoreboardTitle,
+	GameSetSculkShriekerCanSummon,
+	GameSetSculkShriekerShrieking,
+	GameSetSculkShriekerWarningLevel,
+	GameSetSignText,
+	GameSetSignTextColor,
+	GameSetSignWaxed,
+	GameSetSpawnerEntity,
+	GameSetWorldDifficulty,
+	GameSetWorldGamerule,
+	GameSetWorldSimulationDistance,
+	GameSetWorldTime,
+	GameSetWorldWeather,
+	GameSpawnArmorStand,
+	GameSpawnBlockDisplay,
+	GameSpawnEffectCloud,
+	GameSpawnEndCrystal,
+	GameSpawnEvokerFangs,
+	GameSpawnExperienceOrb,
+	GameSpawnEyeOfEnder,
+	GameSpawnFallingBlock,
+	GameSpawnInteractionEntity,
+	GameSpawnItem,
+	GameSpawnItemDisplay,
+	GameSpawnLightningBolt,
+	GameSpawnMob,
+	GameSpawnPrimedTnt,
+	GameSpawnShulkerBullet,
+	GameSpawnTextDisplay,
+	GameSpawnVehicle,
+	GameUncancelEvent,
+	GameUpdateBlock,
+	IfEntityCollidesAtLocation,
+	IfEntityCollidesUsingHitbox,
+	IfEntityCollidesWithEntity,
+	IfEntityDummy,
+	IfEntityExists,
+	IfEntityHasCustomTag,
+	IfEntityHasPotionEffect,
+	IfEntityInArea,
+	IfEntityIsDisguised,
+	IfEntityIsGrounded,
+	IfEntityIsItem,
+	IfEntityIsMob,
+	IfEntityIsNearLocation,
+	IfEntityIsProjectile,
+	IfEntityIsRidingEntity,
+	IfEntityIsStandingOnBlock,
+	IfEntityIsType,
+	IfEntityIsUndead,
+	IfEntityIsVehicle,
+	IfEntityNameEquals,
+	IfEntitySpawnReasonEquals,
+	IfGameBlockEquals,
+	IfGameBlockPowered,
+	IfGameChunkIsLoaded,
+	IfGameContainerHas,
+	IfGameContainerHasRoomForItem,
+	IfGameDamageCauseEquals,
+	IfGameDummy,
+	IfGameEventAttackIsCritical,
+	IfGameEventBlockEquals,
+	IfGameEventIsCanceled,
+	IfGameEventItemEquals,
+	IfGameHasPlayer,
+	IfGameHealCauseEquals,
+	IfGameIgniteCauseEquals,
+	IfGameInstrumentEquals,
+	IfGameLocationInBlock,
+	IfGameSignContains,
+	IfPlayerChatColorsEnabled,
+	IfPlayerChatMessageEquals,
+	IfPlayerCollidesAtLocation,
+	IfPlayerCollidesUsingHitbox,
+	IfPlayerCollidesWithEntity,
+	IfPlayerCursorItemEquals,
+	IfPlayerDummy,
+	IfPlayerGamemodeEquals,
+	IfPlayerHasItem,
+	IfPlayerHasItemAtLeast,
+	IfPlayerHasItemInSlot,
+	IfPlayerHasPotionEffect,
+	IfPlayerHasPrivilege,
+	IfPlayerHasRoomForItem,
+	IfPlayerHotbarSlotEquals,
+	IfPlayerInArea,
+	IfPlayerInventoryMenuSlotEquals,
+	IfPlayerInventoryTypeOpen,
+	IfPlayerIsAllowServerListing,
+	IfPlayerIsBlocking,
+	IfPlayerIsDisguised,
+	IfPlayerIsFlying,
+	IfPlayerIsGliding,
+	IfPlayerIsHolding,
+	IfPlayerIsLookingAtBlock,
+	IfPlayerIsNear,
+	IfPlayerIsOnGround,
+	IfPlayerIsOnlineMode,
+	IfPlayerIsRidingEntity,
+	IfPlayerIsSelfDisguised,
+	IfPlayerIsSleeping,
+	IfPlayerIsSneaking,
+	IfPlayerIsSprinting,
+	IfPlayerIsStandingOnBlock,
+	IfPlayerIsSwimming,
+	IfPlayerIsUsingItem,
+	IfPlayerIsWearingItem,
+	IfPlayerItemIsNotOnCooldown,
+	IfPlayerNameEquals,
+	IfPlayerTextFilteringEnabled,
+	IfVariableBlockIsSolid,
+	IfVariableDummy,
+	IfVariableEquals,
+	IfVariableExists,
+	IfVariableGreater,
+	IfVariableGreaterOrEquals,
+	IfVariableInRange,
+	IfVariableIsType,
+	IfVariableItemEquals,
+	IfVariableItemHasEnchantment,
+	IfVariableItemHasTag,
+	IfVariableItemIsBlock,
+	IfVariableLess,
+	IfVariableLessOrEquals,
+	IfVariableListContainsValue,
+	IfVariableListIsEmpty,
+	IfVariableListValueEquals,
+	IfVariableLocationInRange,
+	IfVariableLocationIsNear,
+	IfVariableMapHasKey,
+	IfVariableMapValueEquals,
+	IfVariableNotEquals,
+	IfVariableRangeIntersectsRange,
+	IfVariableTextContains,
+	IfVariableTextEndsWith,
+	IfVariableTextMatches,
+	IfVariableTextStartsWith,
+	PlayerAddInventoryMenuRow,
+	PlayerAllowPlacingBreakingBlocks,
+	PlayerBoostElytra,
+	PlayerClearChat,
+	PlayerClearDebugMarkers,
+	PlayerClearEnderChestContents,
+	PlayerClearInventory,
+	PlayerClearItems,
+	PlayerClearPotionEffects,
+	PlayerCloseInventory,
+	PlayerDamage,
+	PlayerDisguiseAsBlock,
+	PlayerDisguiseAsEntity,
+	PlayerDisguiseAsItem,
+	PlayerDisplayBellRing,
+	PlayerDisplayBlock,
+	PlayerDisplayEndGatewayBeam,
+	PlayerDisplayHologram,
+	PlayerDisplayLightning,
+	PlayerDisplayParticle,
+	PlayerDisplayParticleCircle,
+	PlayerDisplayParticleCube,
+	PlayerDisplayParticleLine,
+	PlayerDisplayParticleRay,
+	PlayerDisplayParticleSphere,
+	PlayerDisplayParticleSpiral,
+	PlayerDisplayPickUpAnimation,
+	PlayerDisplaySignText,
+	PlayerDisplayVibration,
+	PlayerDummy,
+	PlayerExpandInventoryMenu,
+	PlayerFaceLocation,
+	PlayerForceFlightMode,
+	PlayerGiveExperience,
+	PlayerGiveItems,
+	PlayerGivePotionEffect,
+	PlayerGiveRandomItem,
+	PlayerHeal,
+	PlayerHideEntity,
+	PlayerHideScoreboard,
+	PlayerKick,
+	PlayerLaunchForward,
+	PlayerLaunchProjectile,
+	PlayerLaunchToLocation,
+	PlayerLaunchUp,
+	PlayerLeaveVehicle,
+	PlayerLoadInventory,
+	PlayerOpenBook,
+	PlayerOpenContainerInventory,
+	PlayerPlayAnimationAction,
+	PlayerPlayHurtAnimation,
+	PlayerPlaySound,
+	PlayerPlaySoundFromEntity,
+	PlayerPlaySoundSequence,
+	PlayerRandomizedTeleport,
+	PlayerRedirectWorld,
+	PlayerRemoveBossBar,
+	PlayerRemoveDisguise,
+	PlayerRemoveDisplayBlocks,
+	PlayerRemoveInventoryMenuRow,
+	PlayerRemoveItems,
+	PlayerRemovePose,
+	PlayerRemovePotionEffect,
+	PlayerRemoveSelfDisguise,
+	PlayerRemoveSkin,
+	PlayerRemoveWorldBorder,
+	PlayerReplaceItems,
+	PlayerResetWeather,
+	PlayerRideEntity,
+	PlayerSaveInventory,
+	PlayerSelfDisguiseAsBlock,
+	PlayerSelfDisguiseAsEntity,
+	PlayerSelfDisguiseAsItem,
+	PlayerSendActionBar,
+	PlayerSendAdvancement,
+	PlayerSendBreakAnimation,
+	PlayerSendDialogue,
+	PlayerSendHover,
+	PlayerSendMessage,
+	PlayerSendMinimessage,
+	PlayerSendTitle,
+	PlayerSetAbsorptionHealth,
+	PlayerSetAirTicks,
+	PlayerSetAllowFlying,
+	PlayerSetArmor,
+	PlayerSetArrowsInBody,
+	PlayerSetAttackSpeed,
+	PlayerSetAttribute,
+	PlayerSetBeeStingersInBody,
+	PlayerSetBlockOpenedState,
+	PlayerSetBossBar,
+	PlayerSetChatCompletions,
+	PlayerSetCollidable,
+	PlayerSetCompassTarget,
+	PlayerSetCursorItem,
+	PlayerSetDeathDrops,
+	PlayerSetDefaultVisible,
+	PlayerSetEnderChestContents,
+	PlayerSetEntityGlowing,
+	PlayerSetEquipment,
+	PlayerSetExhaustion,
+	PlayerSetExperience,
+	PlayerSetFallDistance,
+	PlayerSetFireTicks,
+	PlayerSetFlying,
+	PlayerSetFogDistance,
+	PlayerSetFood,
+	PlayerSetFreezeTicks,
+	PlayerSetGamemode,
+	PlayerSetGliding,
+	PlayerSetHealth,
+	PlayerSetHotbarSlot,
+	PlayerSetInstantRespawn,
+	PlayerSetInventoryKept,
+	PlayerSetInventoryMenuItem,
+	PlayerSetInventoryMenuName,
+	PlayerSetInvulnerabilityTicks,
+	PlayerSetItemCooldown,
+	PlayerSetItems,
+	PlayerSetMaxHealth,
+	PlayerSetMovementSpeed,
+	PlayerSetNametagVisible,
+	PlayerSetPlayerListInfo,
+	PlayerSetPose,
+	PlayerSetPvp,
+	PlayerSetRainLevel,
+	PlayerSetRotation,
+	PlayerSetRotationByVector,
+	PlayerSetSaturation,
+	PlayerSetSimulationDistance,
+	PlayerSetSkin,
+	PlayerSetSlotItem,
+	PlayerSetSpawnPoint,
+	PlayerSetThunderLevel,
+	PlayerSetTickRate,
+	PlayerSetTime,
+	PlayerSetVelocity,
+	PlayerSetVisualFire,
+	PlayerSetWeather,
+	PlayerSetWorldBorder,
+	PlayerShiftWorldBorder,
+	PlayerShowDebugMarker,
+	PlayerShowDemoScreen,
+	PlayerShowInventoryMenu,
+	PlayerShowScoreboard,
+	PlayerShowWinScreen,
+	PlayerSpectateTarget,
+	PlayerStopSound,
+	PlayerStopSoundsBySource,
+	PlayerSwingHand,
+	PlayerTeleport,
+	PlayerTeleportSequence,
+	RepeatAdjacently,
+	RepeatDummy,
+	RepeatForEachInList,
+	RepeatForEachMapEntry,
+	RepeatForever,
+	RepeatMultiTimes,
+	RepeatOnCircle,
+	RepeatOnGrid,
+	RepeatOnPath,
+	RepeatOnRange,
+	RepeatOnSphere,
+	RepeatWhile,
+	SelectAddAllEntities,
+	SelectAddAllMobs,
+	SelectAddAllPlayers,
+	SelectAddEntityByConditional,
+	SelectAddEntityByName,
+	SelectAddEventTarget,
+	SelectAddLastEntity,
+	SelectAddLastMob,
+	SelectAddMobByName,
+	SelectAddPlayerByConditional,
+	SelectAddPlayerByName,
+	SelectAddRandomEntity,
+	SelectAddRandomMob,
+	SelectAddRandomPlayer,
+	SelectAllEntities,
+	SelectAllMobs,
+	SelectAllPlayers,
+	SelectDummy,
+	SelectEntityByConditional,
+	SelectEntityByName,
+	SelectEventTarget,
+	SelectFilterByConditional,
+	SelectFilterByDistance,
+	SelectFilterByRaycast,
+	SelectFilterRandomly,
+	SelectInvert,
+	SelectLastEntity,
+	SelectLastMob,
+	SelectMobByName,
+	SelectPlayerByConditional,
+	SelectPlayerByName,
+	SelectRandomEntity,
+	SelectRandomMob,
+	SelectRandomPlayer,
+	SelectReset,
+	SetVariableAbsolute,
+	SetVariableAdd,
+	SetVariableAddItemEnchantment,
+	SetVariableAddItemPotionEffects,
+	SetVariableAddVectors,
+	SetVariableAlignLocation,
+	SetVariableAlignToAxisVector,
+	SetVariableAppendComponent,
+	SetVariableAppendList,
+	SetVariableAppendMap,
+	SetVariableAppendValue,
+	#[serde(rename = "set_variable_atan2")]
+	SetVariableAtan2,
+	SetVariableAverage,
+	SetVariableBitwiseOperation,
+	SetVariableCenterLocation,
+	SetVariableChangeComponentParsing,
+	SetVariableCharToNumber,
+	SetVariableClamp,
+	SetVariableClearColorCodes,
+	SetVariableClearMap,
+	SetVariableCompactComponent,
+	SetVariableComponentOfChildren,
+	SetVariableConvertNumberToText,
+	SetVariableConvertTextToNumber,
+	SetVariableCosine,
+	SetVariableCotangent,
+	SetVariableCreateKeybindComponent,
+	SetVariableCreateList,
+	SetVariableCreateMap,
+	SetVariableCreateMapFromValues,
+	SetVariableCreateTranslatableComponent,
+	SetVariableDecrement,
+	SetVariableDivide,
+	SetVariableDivideVector,
+	SetVariableDummy,
+	SetVariableFaceLocation,
+	SetVariableFlattenList,
+	SetVariableFormatTimestamp,
+	SetVariableGaussianDistribution,
+	SetVariableGetAllBlockData,
+	SetVariableGetAllCoordinates,
+	SetVariableGetAngleBetweenVectors,
+	SetVariableGetBlockCustomTag,
+	SetVariableGetBlockData,
+	SetVariableGetBlockGrowth,
+	SetVariableGetBlockMaterial,
+	SetVariableGetBlockMaterialProperty,
+	SetVariableGetBlockPower,
+	SetVariableGetBlockSound,
+	SetVariableGetBookText,
+	SetVariableGetBrushableBlockItem,
+	SetVariableGetBundleItems,
+	SetVariableGetCharAt,
+	SetVariableGetColorChannels,
+	SetVariableGetCompassLodestone,
+	SetVariableGetComponentChildren,
+	SetVariableGetComponentDecorations,
+	SetVariableGetComponentHexColor,
+	SetVariableGetComponentParsing,
+	SetVariableGetContainerContents,
+	SetVariableGetContainerLock,
+	SetVariableGetContainerName,
+	SetVariableGetCoordinate,
+	SetVariableGetDecoratePotSherd,
+	SetVariableGetIndexOfSubtext,
+	SetVariableGetItemAmount,
+	SetVariableGetItemAttribute,
+	SetVariableGetItemColor,
+	SetVariableGetItemCustomModelData,
+	SetVariableGetItemCustomTag,
+	SetVariableGetItemCustomTags,
+	SetVariableGetItemDestroyableBlocks,
+	SetVariableGetItemDurability,
+	SetVariableGetItemEnchantments,
+	SetVariableGetItemLore,
+	SetVariableGetItemLoreLine,
+	SetVariableGetItemMaxStackSize,
+	SetVariableGetItemName,
+	SetVariableGetItemNbtTags,
+	SetVariableGetItemPlaceableBlocks,
+	SetVariableGetItemPotionEffects,
+	SetVariableGetItemRarity,
+	SetVariableGetItemType,
+	SetVariableGetLecternBook,
+	SetVariableGetLecternPage,
+	SetVariableGetLightLevel,
+	SetVariableGetListIndexOfValue,
+	SetVariableGetListLength,
+	SetVariableGetListRandomValue,
+	SetVariableGetListValue,
+	SetVariableGetListVariables,
+	SetVariableGetLocationDirection,
+	SetVariableGetMapKeyByIndex,
+	SetVariableGetMapKeys,
+	SetVariableGetMapKeysByValue,
+	SetVariableGetMapSize,
+	SetVariableGetMapValue,
+	SetVariableGetMapValueByIndex,
+	SetVariableGetMapValues,
+	SetVariableGetMidpointBetweenVectors,
+	SetVariableGetParticleAmount,
+	SetVariableGetParticleColor,
+	SetVariableGetParticleMaterial,
+	SetVariableGetParticleOffset,
+	SetVariableGetParticleSize,
+	SetVariableGetParticleSpread,
+	SetVariableGetParticleType,
+	SetVariableGetPlayerHead,
+	SetVariableGetPlayerHeadOwner,
+	SetVariableGetPlayerHeadValue,
+	SetVariableGetPotionEffectAmplifier,
+	SetVariableGetPotionEffectDuration,
+	SetVariableGetPotionEffectType,
+	SetVariableGetSculkShriekerWarningLevel,
+	SetVariableGetSignText,
+	SetVariableGetSoundPitch,
+	SetVariableGetSoundSource,
+	SetVariableGetSoundType,
+	SetVariableGetSoundVariation,
+	SetVariableGetSoundVariations,
+	SetVariableGetSoundVolumeAction,
+	SetVariableGetTemplateCode,
+	SetVariableGetTextWidth,
+	SetVariableGetVectorAllComponents,
+	SetVariableGetVectorBetweenLocations,
+	SetVariableGetVectorComponent,
+	SetVariableGetVectorFromBlockFace,
+	SetVariableGetVectorLength,
+	SetVariableHash,
+	SetVariableIncrement,
+	SetVariableInsertListValue,
+	SetVariableJoinText,
+	SetVariableLerpNumber,
+	SetVariableLocationRelative,
+	SetVariableLocationsDistance,
+	SetVariableLog,
+	SetVariableMapRange,
+	SetVariableMax,
+	SetVariableMin,
+	SetVariableMultiply,
+	SetVariableMultiplyVector,
+	SetVariableParseJson,
+	SetVariableParseToComponent,
+	#[serde(rename = "set_variable_perlin_noise_3d")]
+	SetVariablePerlinNoise3d,
+	SetVariablePow,
+	SetVariablePurge,
+	SetVariableRandom,
+	SetVariableRandomLocation,
+	SetVariableRandomNumber,
+	SetVariableRandomizeListOrder,
+	SetVariableRayTraceResult,
+	SetVariableReflectVectorProduct,
+	SetVariableRegexReplaceText,
+	SetVariableRemainder,
+	SetVariableRemoveCompassLodestone,
+	SetVariableRemoveEnchantment,
+	SetVariableRemoveItemAttribute,
+	SetVariableRemoveItemCustomModelData,
+	SetVariableRemoveItemCustomTag,
+	SetVariableRemoveItemLoreLine,
+	SetVariableRemoveItemPotionEffects,
+	SetVariableRemoveListDuplicates,
+	SetVariableRemoveListValue,
+	SetVariableRemoveListValueAtIndex,
+	SetVariableRemoveMapEntry,
+	SetVariableRemoveText,
+	SetVariableRepeatText,
+	SetVariableReplaceText,
+	SetVariableReverseList,
+	SetVariableRoot,
+	SetVariableRotateVectorAroundAxis,
+	SetVariableRotateVectorAroundVector,
+	SetVariableRound,
+	SetVariableSetAllCoordinates,
+	SetVariableSetArmorTrim,
+	SetVariableSetBookPage,
+	SetVariableSetBookPages,
+	SetVariableSetBundleItems,
+	SetVariableSetCompassLodestone,
+	SetVariableSetComponentChildren,
+	SetVariableSetComponentClick,
+	SetVariableSetComponentDecorations,
+	SetVariableSetComponentEntityHover,
+	SetVariableSetComponentFont,
+	SetVariableSetComponentHexColor,
+	SetVariableSetComponentHover,
+	SetVariableSetComponentInsertion,
+	SetVariableSetComponentItemHover,
+	SetVariableSetCoordinate,
+	SetVariableSetItemAmount,
+	SetVariableSetItemAttribute,
+	SetVariableSetItemColor,
+	SetVariableSetItemComponent,
+	SetVariableSetItemCustomModelData,
+	SetVariableSetItemCustomTag,
+	SetVariableSetItemDestroyableBlocks,
+	SetVariableSetItemDurability,
+	SetVariableSetItemEnchantments,
+	SetVariableSetItemLore,
+	SetVariableSetItemLoreLine,
+	SetVariableSetItemMaxStackSize,
+	SetVariableSetItemName,
+	SetVariableSetItemPlaceableBlocks,
+	SetVariableSetItemType,
+	SetVariableSetItemUnbreakable,
+	SetVariableSetItemVisibilityFlags,
+	SetVariableSetListValue,
+	SetVariableSetLocationDirection,
+	SetVariableSetMapValue,
+	SetVariableSetParticleAmount,
+	SetVariableSetParticleColor,
+	SetVariableSetParticleMaterial,
+	SetVariableSetParticleOffset,
+	SetVariableSetParticleSize,
+	SetVariableSetParticleSpread,
+	SetVariableSetParticleType,
+	SetVariableSetPotionEffectAmplifier,
+	SetVariableSetPotionEffectDuration,
+	SetVariableSetPotionEffectType,
+	SetVariableSetSoundPitch,
+	SetVariableSetSoundSource,
+	SetVariableSetSoundType,
+	SetVariableSetSoundVariation,
+	SetVariableSetSoundVolumeAction,
+	SetVariableSetTemplateCode,
+	SetVariableSetTextureToMap,
+	SetVariableSetVectorComponent,
+	SetVariableSetVectorLength,
+	SetVariableShiftAllCoordinates,
+	SetVariableShiftCoordinate,
+	SetVariableShiftLocationInDirection,
+	SetVariableShiftLocationOnVector,
+	SetVariableShiftLocationTowardsLocation,
+	#[serde(rename = "set_variable_simplex_noise_3d")]
+	SetVariableSimplexNoise3d,
+	SetVariableSine,
+	SetVariableSortAnyList,
+	SetVariableSortAnyMap,
+	SetVariableSplitText,
+	SetVariableStripText,
+	SetVariableSubtract,
+	SetVariableSubtractVectors,
+	SetVariableTangent,
+	SetVariableText,
+	SetVariableTextCase,
+	SetVariableTextLength,
+	SetVariableToChar,
+	SetVariableToHsb,
+	SetVariableToHsl,
+	SetVariableToJson,
+	SetVariableToRgb,
+	SetVariableTrimList,
+	SetVariableTrimText,
+	SetVariableValue,
+	SetVariableVector,
+	SetVariableVectorCrossProduct,
+	SetVariableVectorDotProduct,
+	SetVariableVectorToDirectionName,
+	#[serde(rename = "set_variable_voronoi_noise_3d")]
+	SetVariableVoronoiNoise3d,
+	SetVariableWarp,
+	StartProcess,
 }
 
-#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, Copy)]
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[serde(rename_all = "snake_case")]
 #[repr(u16)]
 pub enum ActionType {
-    Basic,
-    BasicWithConditional,
-    Container,
-    ContainerWithConditional,
+	Basic,
+	BasicWithConditional,
+	Container,
+	ContainerWithConditional,
 }
 
-#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, Copy)]
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[serde(rename_all = "snake_case")]
 #[repr(u16)]
 pub enum ActionObject {
-    Code,
-    Controller,
-    Entity,
-    Player,
-    Repeat,
-    Select,
-    Variable,
-    World,
+	Code,
+	Controller,
+	Entity,
+	Player,
+	Repeat,
+	Select,
+	Variable,
+	World,
 }
 
-#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, Copy)]
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[serde(rename_all = "snake_case")]
 #[repr(u16)]
 pub enum ArgType {
-    Any,
-    Array,
-    Block,
-    Enum,
-    Item,
-    Location,
-    Map,
-    Number,
-    Particle,
-    Potion,
-    Sound,
-    Text,
-    Variable,
-    Vector,
+	Any,
+	Array,
+	Block,
+	Enum,
+	Item,
+	Location,
+	Map,
+	Number,
+	Particle,
+	Potion,
+	Sound,
+	Text,
+	Variable,
+	Vector,
+}
+
+impl ActionIdWants11Bits {
+	pub fn array_limit(self, arg: &str) -> Option<u8> {
+		match (self, arg) {
+			(Self::EntityGivePotionEffects, "potions") => Some(14),
+			(Self::EntityModifyPiglinBarterMaterials, "materials") => Some(18),
+			(Self::EntityModifyPiglinInterestedMaterials, "materials") => Some(18),
+			(Self::EntityRemovePotionEffect, "effects") => Some(18),
+			(Self::EntitySetDisplayTransformationMatrix, "row_major_matrix") => Some(16),
+			(Self::EntitySetTextDisplayText, "displayed_text") => Some(27),
+			(Self::GameBreakBlock, "locations") => Some(16),
+			(Self::GameClearContainerItems, "items") => Some(35),
+			(Self::GameClearExplodedBlocks, "location") => Some(27),
+			(Self::GameFillContainer, "items") => Some(14),
+			(Self::GameRemoveContainerItems, "items") => Some(35),
+			(Self::GameReplaceBlocksInRegion, "old_block") => Some(10),
+			(Self::GameReplaceContainerItems, "items") => Some(10),
+			(Self::GameSetBlock, "locations") => Some(14),
+			(Self::GameSetContainer, "items") => Some(27),
+			(Self::GameSetContainer, "location") => Some(9),
+			(Self::GameSetEventItems, "items") => Some(36),
+			(Self::GameSpawnEffectCloud, "effects") => Some(6),
+			(Self::GameSpawnMob, "potion_effects") => Some(15),
+			(Self::GameSpawnTextDisplay, "displayed_text") => Some(28),
+			(Self::IfEntityHasPotionEffect, "potions") => Some(18),
+			(Self::IfEntityIsRidingEntity, "entity_ids") => Some(18),
+			(Self::IfEntityIsStandingOnBlock, "blocks") => Some(20),
+			(Self::IfEntityIsStandingOnBlock, "locations") => Some(20),
+			(Self::IfEntityIsType, "entity_types") => Some(18),
+			(Self::IfEntityNameEquals, "names_or_uuids") => Some(18),
+			(Self::IfGameBlockEquals, "blocks") => Some(26),
+			(Self::IfGameBlockPowered, "locations") => Some(18),
+			(Self::IfGameContainerHas, "items") => Some(16),
+			(Self::IfGameContainerHasRoomForItem, "items") => Some(16),
+			(Self::IfGameEventBlockEquals, "blocks") => Some(20),
+			(Self::IfGameEventBlockEquals, "locations") => Some(20),
+			(Self::IfGameEventItemEquals, "items") => Some(18),
+			(Self::IfGameHasPlayer, "names_or_uuids") => Some(18),
+			(Self::IfGameSignContains, "texts") => Some(16),
+			(Self::IfPlayerChatMessageEquals, "chat_messages") => Some(18),
+			(Self::IfPlayerCursorItemEquals, "items") => Some(18),
+			(Self::IfPlayerHasItem, "items") => Some(18),
+			(Self::IfPlayerHasItemInSlot, "items") => Some(12),
+			(Self::IfPlayerHasItemInSlot, "slots") => Some(12),
+			(Self::IfPlayerHasPotionEffect, "potions") => Some(18),
+			(Self::IfPlayerHasRoomForItem, "items") => Some(18),
+			(Self::IfPlayerInventoryMenuSlotEquals, "items") => Some(12),
+			(Self::IfPlayerInventoryMenuSlotEquals, "slots") => Some(12),
+			(Self::IfPlayerIsHolding, "items") => Some(18),
+			(Self::IfPlayerIsLookingAtBlock, "blocks") => Some(15),
+			(Self::IfPlayerIsLookingAtBlock, "locations") => Some(15),
+			(Self::IfPlayerIsRidingEntity, "entity_ids") => Some(18),
+			(Self::IfPlayerIsStandingOnBlock, "blocks") => Some(20),
+			(Self::IfPlayerIsStandingOnBlock, "locations") => Some(20),
+			(Self::IfPlayerIsUsingItem, "items") => Some(18),
+			(Self::IfPlayerIsWearingItem, "items") => Some(18),
+			(Self::IfPlayerItemIsNotOnCooldown, "items") => Some(18),
+			(Self::IfPlayerNameEquals, "names_or_uuids") => Some(18),
+			(Self::IfVariableEquals, "compare") => Some(21),
+			(Self::IfVariableItemEquals, "compare") => Some(21),
+			(Self::IfVariableListContainsValue, "values") => Some(21),
+			(Self::IfVariableListValueEquals, "values") => Some(21),
+			(Self::IfVariableLocationIsNear, "check") => Some(18),
+			(Self::IfVariableMapValueEquals, "values") => Some(21),
+			(Self::IfVariableNotEquals, "compare") => Some(21),
+			(Self::IfVariableTextContains, "compare") => Some(21),
+			(Self::IfVariableTextEndsWith, "compare") => Some(21),
+			(Self::IfVariableTextMatches, "values") => Some(21),
+			(Self::IfVariableTextStartsWith, "compare") => Some(21),
+			(Self::PlayerAddInventoryMenuRow, "items") => Some(9),
+			(Self::PlayerAllowPlacingBreakingBlocks, "blocks") => Some(18),
+			(Self::PlayerClearItems, "items") => Some(18),
+			(Self::PlayerDisplayBlock, "location") => Some(27),
+			(Self::PlayerDisplayParticle, "location") => Some(20),
+			(Self::PlayerDisplayParticle, "particle") => Some(20),
+			(Self::PlayerExpandInventoryMenu, "items") => Some(27),
+			(Self::PlayerGiveItems, "items") => Some(17),
+			(Self::PlayerGivePotionEffect, "potions") => Some(18),
+			(Self::PlayerGiveRandomItem, "items") => Some(27),
+			(Self::PlayerHideEntity, "name_or_uuid") => Some(14),
+			(Self::PlayerPlaySoundFromEntity, "sounds") => Some(21),
+			(Self::PlayerPlaySoundSequence, "sounds") => Some(43),
+			(Self::PlayerRandomizedTeleport, "locations") => Some(18),
+			(Self::PlayerRemoveItems, "items") => Some(18),
+			(Self::PlayerRemovePotionEffect, "potions") => Some(18),
+			(Self::PlayerReplaceItems, "items") => Some(28),
+			(Self::PlayerSendActionBar, "messages") => Some(18),
+			(Self::PlayerSendBreakAnimation, "locations") => Some(14),
+			(Self::PlayerSendDialogue, "messages") => Some(17),
+			(Self::PlayerSendMessage, "messages") => Some(18),
+			(Self::PlayerSetChatCompletions, "completions") => Some(18),
+			(Self::PlayerSetEnderChestContents, "items") => Some(27),
+			(Self::PlayerSetEntityGlowing, "name_or_uuid") => Some(14),
+			(Self::PlayerSetItems, "items") => Some(36),
+			(Self::PlayerSetPlayerListInfo, "text") => Some(16),
+			(Self::PlayerShowInventoryMenu, "items") => Some(27),
+			(Self::PlayerStopSound, "sounds") => Some(18),
+			(Self::PlayerTeleportSequence, "locations") => Some(14),
+			(Self::RepeatOnPath, "locations") => Some(21),
+			(Self::SelectAddEntityByName, "name_or_uuid") => Some(18),
+			(Self::SelectAddMobByName, "name_or_uuid") => Some(18),
+			(Self::SelectAddPlayerByName, "name_or_uuid") => Some(18),
+			(Self::SelectEntityByName, "name_or_uuid") => Some(18),
+			(Self::SelectMobByName, "name_or_uuid") => Some(18),
+			(Self::SelectPlayerByName, "name_or_uuid") => Some(18),
+			(Self::SetVariableAdd, "value") => Some(21),
+			(Self::SetVariableAddItemPotionEffects, "potions") => Some(18),
+			(Self::SetVariableAddVectors, "vectors") => Some(21),
+			(Self::SetVariableAppendComponent, "components") => Some(21),
+			(Self::SetVariableAppendValue, "values") => Some(21),
+			(Self::SetVariableAverage, "value") => Some(21),
+			(Self::SetVariableCenterLocation, "locations") => Some(21),
+			(Self::SetVariableComponentOfChildren, "components") => Some(21),
+			(Self::SetVariableCreateList, "values") => Some(21),
+			(Self::SetVariableCreateMapFromValues, "keys") => Some(15),
+			(Self::SetVariableCreateMapFromValues, "values") => Some(15),
+			(Self::SetVariableCreateTranslatableComponent, "args") => Some(21),
+			(Self::SetVariableDivide, "value") => Some(21),
+			(Self::SetVariableMax, "value") => Some(21),
+			(Self::SetVariableMin, "value") => Some(21),
+			(Self::SetVariableMultiply, "value") => Some(21),
+			(Self::SetVariablePurge, "names") => Some(16),
+			(Self::SetVariableRandom, "values") => Some(21),
+			(Self::SetVariableRemoveItemPotionEffects, "effects") => Some(18),
+			(Self::SetVariableRemoveMapEntry, "values") => Some(18),
+			(Self::SetVariableRemoveText, "remove") => Some(18),
+			(Self::SetVariableSetBookPages, "text") => Some(21),
+			(Self::SetVariableSetBundleItems, "items") => Some(35),
+			(Self::SetVariableSetComponentChildren, "children") => Some(21),
+			(Self::SetVariableSetItemDestroyableBlocks, "destroyable") => Some(18),
+			(Self::SetVariableSetItemLore, "lore") => Some(21),
+			(Self::SetVariableSetItemPlaceableBlocks, "placeable") => Some(18),
+			(Self::SetVariableSubtract, "value") => Some(21),
+			(Self::SetVariableSubtractVectors, "vectors") => Some(21),
+			(Self::SetVariableText, "text") => Some(21),
+			_ => None,
+		}
+	}
 }
+
+pub const ACTION_COUNTS: &[(ActionIdWants11Bits, usize)] = &[
+	(ActionIdWants11Bits::CallFunction, 4),
+	(ActionIdWants11Bits::ControlCallException, 1),
+	(ActionIdWants11Bits::ControlDummy, 1),
+	(ActionIdWants11Bits::ControlEndThread, 1),
+	(ActionIdWants11Bits::ControlReturnFunction, 1),
+	(ActionIdWants11Bits::ControlSkipIteration, 1),
+	(ActionIdWants11Bits::ControlStopRepeat, 3),
+	(ActionIdWants11Bits::ControlWait, 1),
+	(ActionIdWants11Bits::ControllerAsyncRun, 1),
+	(ActionIdWants11Bits::ControllerException, 1),
+	(ActionIdWants11Bits::ControllerMeasureTime, 1),
+	(ActionIdWants11Bits::Else, 2),
+	(ActionIdWants11Bits::EntityAttachLead, 1),
+	(ActionIdWants11Bits::EntityCelarPotionEffects, 1),
+	(ActionIdWants11Bits::EntityClearMerchantRecipes, 1),
+	(ActionIdWants11Bits::EntityDamage, 1),
+	(ActionIdWants11Bits::EntityDisguiseAsBlock, 1),
+	(ActionIdWants11Bits::EntityDisguiseAsEntity, 1),
+	(ActionIdWants11Bits::EntityDisguiseAsItem, 1),
+	(ActionIdWants11Bits::EntityDisguiseAsPlayer, 1),
+	(ActionIdWants11Bits::EntityDummy, 1),
+	(ActionIdWants11Bits::EntityEatGrass, 1),
+	(ActionIdWants11Bits::EntityEatTarget, 1),
+	(ActionIdWants11Bits::EntityExplode, 1),
+	(ActionIdWants11Bits::EntityFaceLocation, 1),
+	(ActionIdWants11Bits::EntityGetCustomTag, 1),
+	(ActionIdWants11Bits::EntityGivePotionEffects, 1),
+	(ActionIdWants11Bits::EntityHeal, 1),
+	(ActionIdWants11Bits::EntityIgniteCreeper, 1),
+	(ActionIdWants11Bits::EntityJump, 1),
+	(ActionIdWants11Bits::EntityLaunchForward, 1),
+	(ActionIdWants11Bits::EntityLaunchProjectile, 1),
+	(ActionIdWants11Bits::EntityLaunchToLocation, 1),
+	(ActionIdWants11Bits::EntityLaunchUp, 1),
+	(ActionIdWants11Bits::EntityLeaveVehicle, 1),
+	(ActionIdWants11Bits::EntityModifyPiglinBarterMaterials, 1),
+	(ActionIdWants11Bits::EntityModifyPiglinInterestedMaterials, 1),
+	(ActionIdWants11Bits::EntityMoveToLocation, 1),
+	(ActionIdWants11Bits::EntityMoveToLocationStop, 1),
+	(ActionIdWants11Bits::EntityPlayDamageAnimation, 1),
+	(ActionIdWants11Bits::EntityPlayHurtAnimation, 1),
+	(ActionIdWants11Bits::EntityRamTarget, 1),
+	(ActionIdWants11Bits::EntityRemove, 1),
+	(ActionIdWants11Bits::EntityRemoveCustomTag, 1),
+	(ActionIdWants11Bits::EntityRemoveDisguise, 1),
+	(ActionIdWants11Bits::EntityRemoveMerchantRecipe, 1),
+	(ActionIdWants11Bits::EntityRemovePotionEffect, 1),
+	(ActionIdWants11Bits::EntityResetDisplayBrightness, 1),
+	(ActionIdWants11Bits::EntityResetDisplayGlowColor, 1),
+	(ActionIdWants11Bits::EntityResetTextDisplayBackground, 1),
+	(ActionIdWants11Bits::EntityRideEntity, 1),
+	(ActionIdWants11Bits::EntitySetAbsorptionHealth, 1),
+	(ActionIdWants11Bits::EntitySetAi, 1),
+	(ActionIdWants11Bits::EntitySetAllayDancing, 1),
+	(ActionIdWants11Bits::EntitySetAngry, 1),
+	(ActionIdWants11Bits::EntitySetAnimalAge, 1),
+	(ActionIdWants11Bits::EntitySetArmorItems, 1),
+	(ActionIdWants11Bits::EntitySetArmorStandParts, 1),
+	(ActionIdWants11Bits::EntitySetArmorStandPose, 1),
+	(ActionIdWants11Bits::EntitySetArrowHitSound, 1),
+	(ActionIdWants11Bits::EntitySetArrowPierce, 1),
+	(ActionIdWants11Bits::EntitySetAttribute, 1),
+	(ActionIdWants11Bits::EntitySetAware, 1),
+	(ActionIdWants11Bits::EntitySetAxolotlType, 1),
+	(ActionIdWants11Bits::EntitySetBaby, 1),
+	(ActionIdWants11Bits::EntitySetBaseArrowDamage, 1),
+	(ActionIdWants11Bits::EntitySetBeeHasStinger, 1),
+	(ActionIdWants11Bits::EntitySetBeeNectar, 1),
+	(ActionIdWants11Bits::EntitySetBlockDisplayBlock, 1),
+	(ActionIdWants11Bits::EntitySetCamelDashing, 1),
+	(ActionIdWants11Bits::EntitySetCarryingChest, 1),
+	(ActionIdWants11Bits::EntitySetCatLyingDown, 1),
+	(ActionIdWants11Bits::EntitySetCatType, 1),
+	(ActionIdWants11Bits::EntitySetCelebrating, 1),
+	(ActionIdWants11Bits::EntitySetCollidable, 1),
+	(ActionIdWants11Bits::EntitySetCreeperCharge, 1),
+	(ActionIdWants11Bits::EntitySetCreeperFuse, 1),
+	(ActionIdWants11Bits::EntitySetCurrentHealth, 1),
+	(ActionIdWants11Bits::EntitySetCustomName, 1),
+	(ActionIdWants11Bits::EntitySetCustomNameVisibility, 2),
+	(ActionIdWants11Bits::EntitySetCustomTag, 1),
+	(ActionIdWants11Bits::EntitySetDeathDrops, 1),
+	(ActionIdWants11Bits::EntitySetDeathTime, 1),
+	(ActionIdWants11Bits::EntitySetDefaultVisible, 1),
+	(ActionIdWants11Bits::EntitySetDespawning, 1),
+	(ActionIdWants11Bits::EntitySetDisplayBillboard, 1),
+	(ActionIdWants11Bits::EntitySetDisplayBrightness, 1),
+	(ActionIdWants11Bits::EntitySetDisplayCullingSuze, 1),
+	(ActionIdWants11Bits::EntitySetDisplayGlowColor, 1),
+	(ActionIdWants11Bits::EntitySetDisplayInterpolation, 1),
+	(ActionIdWants11Bits::EntitySetDisplayRotationFromAxisAngle, 1),
+	(ActionIdWants11Bits::EntitySetDisplayRotationFromEulerAngles, 1),
+	(ActionIdWants11Bits::EntitySetDisplayScale, 1),
+	(ActionIdWants11Bits::EntitySetDisplayShadow, 1),
+	(ActionIdWants11Bits::EntitySetDisplayTeleportDuration, 1),
+	(ActionIdWants11Bits::EntitySetDisplayTransformationMatrix, 1),
+	(ActionIdWants11Bits::EntitySetDisplayTranslation, 1),
+	(ActionIdWants11Bits::EntitySetDisplayViewRange, 1),
+	(ActionIdWants11Bits::EntitySetDragonPhase, 1),
+	(ActionIdWants11Bits::EntitySetDyeColor, 1),
+	(ActionIdWants11Bits::EntitySetEndCrystalBeam, 1),
+	(ActionIdWants11Bits::EntitySetEndermanBlock, 1),
+	(ActionIdWants11Bits::EntitySetEquipmentItem, 1),
+	(ActionIdWants11Bits::EntitySetExplosivePower, 1),
+	(ActionIdWants11Bits::EntitySetFallDistance, 1),
+	(ActionIdWants11Bits::EntitySetFallingBlockType, 1),
+	(ActionIdWants11Bits::EntitySetFireTicks, 1),
+	(ActionIdWants11Bits::EntitySetFishingWait, 1),
+	(ActionIdWants11Bits::EntitySetFoxLeaping, 1),
+	(ActionIdWants11Bits::EntitySetFoxType, 1),
+	(ActionIdWants11Bits::EntitySetFreezeTicks, 1),
+	(ActionIdWants11Bits::EntitySetFriction, 1),
+	(ActionIdWants11Bits::EntitySetFrogType, 1),
+	(ActionIdWants11Bits::EntitySetFuseTicks, 1),
+	(ActionIdWants11Bits::EntitySetGliding, 1),
+	(ActionIdWants11Bits::EntitySetGlowSquidDark, 1),
+	(ActionIdWants11Bits::EntitySetGlowing, 1),
+	(ActionIdWants11Bits::EntitySetGoatScreaming, 1),
+	(ActionIdWants11Bits::EntitySetGravity, 1),
+	(ActionIdWants11Bits::EntitySetHorseJump, 1),
+	(ActionIdWants11Bits::EntitySetHorsePattern, 1),
+	(ActionIdWants11Bits::EntitySetImmuneToZombification, 1),
+	(ActionIdWants11Bits::EntitySetInteractionResponsive, 1),
+	(ActionIdWants11Bits::EntitySetInteractionSize, 1),
+	(ActionIdWants11Bits::EntitySetInvisible, 1),
+	(ActionIdWants11Bits::EntitySetInvulnerabilityTicks, 1),
+	(ActionIdWants11Bits::EntitySetInvulnerable, 1),
+	(ActionIdWants11Bits::EntitySetItem, 1),
+	(ActionIdWants11Bits::EntitySetItemDisplayItem, 1),
+	(ActionIdWants11Bits::EntitySetItemDisplayModelType, 1),
+	(ActionIdWants11Bits::EntitySetItemInFrame, 1),
+	(ActionIdWants11Bits::EntitySetLlamaType, 1),
+	(ActionIdWants11Bits::EntitySetLocation, 2),
+	(ActionIdWants11Bits::EntitySetMarker, 1),
+	(ActionIdWants11Bits::EntitySetMaxHealth, 1),
+	(ActionIdWants11Bits::EntitySetMerchantRecipe, 1),
+	(ActionIdWants11Bits::EntitySetMinecartBlock, 1),
+	(ActionIdWants11Bits::EntitySetMobAggressive, 1),
+	(ActionIdWants11Bits::EntitySetMushroomCowType, 1),
+	(ActionIdWants11Bits::EntitySetNoPhysics, 1),
+	(ActionIdWants11Bits::EntitySetPandaGene, 1),
+	(ActionIdWants11Bits::EntitySetPandaOnBack, 1),
+	(ActionIdWants11Bits::EntitySetPandaRolling, 1),
+	(ActionIdWants11Bits::EntitySetPandaSadTicks, 1),
+	(ActionIdWants11Bits::EntitySetParrotType, 1),
+	(ActionIdWants11Bits::EntitySetPersistence, 1),
+	(ActionIdWants11Bits::EntitySetPickup, 1),
+	(ActionIdWants11Bits::EntitySetPickupDelay, 1),
+	(ActionIdWants11Bits::EntitySetPiglinAbleToHunt, 1),
+	(ActionIdWants11Bits::EntitySetPiglinChargingCrossbow, 1),
+	(ActionIdWants11Bits::EntitySetPiglinDancing, 1),
+	(ActionIdWants11Bits::EntitySetPose, 1),
+	(ActionIdWants11Bits::EntitySetPotionCloudRadius, 1),
+	(ActionIdWants11Bits::EntitySetPrimedTntBlock, 1),
+	(ActionIdWants11Bits::EntitySetProjectileDisplayItem, 1),
+	(ActionIdWants11Bits::EntitySetProjectilePower, 1),
+	(ActionIdWants11Bits::EntitySetProjectileShooter, 1),
+	(ActionIdWants11Bits::EntitySetRabbitType, 1),
+	(ActionIdWants11Bits::EntitySetRearing, 1),
+	(ActionIdWants11Bits::EntitySetRiptiding, 1),
+	(ActionIdWants11Bits::EntitySetRotation, 1),
+	(ActionIdWants11Bits::EntitySetRotationByVector, 1),
+	(ActionIdWants11Bits::EntitySetSheepSheared, 1),
+	(ActionIdWants11Bits::EntitySetShulkerBulletTarget, 1),
+	(ActionIdWants11Bits::EntitySetShulkerPeek, 1),
+	(ActionIdWants11Bits::EntitySetSilenced, 1),
+	(ActionIdWants11Bits::EntitySetSitting, 1),
+	(ActionIdWants11Bits::EntitySetSize, 1),
+	(ActionIdWants11Bits::EntitySetSnifferState, 1),
+	(ActionIdWants11Bits::EntitySetSnowmanPumpkin, 1),
+	(ActionIdWants11Bits::EntitySetTame, 1),
+	(ActionIdWants11Bits::EntitySetTarget, 1),
+	(ActionIdWants11Bits::EntitySetTextDisplayAlignment, 1),
+	(ActionIdWants11Bits::EntitySetTextDisplayBackground, 1),
+	(ActionIdWants11Bits::EntitySetTextDisplayLineWidth, 1),
+	(ActionIdWants11Bits::EntitySetTextDisplayOpacity, 1),
+	(ActionIdWants11Bits::EntitySetTextDisplaySeeThrough, 1),
+	(ActionIdWants11Bits::EntitySetTextDisplayText, 1),
+	(ActionIdWants11Bits::EntitySetTextDisplayTextShadow, 1),
+	(ActionIdWants11Bits::EntitySetTropicalFishPattern, 1),
+	(ActionIdWants11Bits::EntitySetVexCharging, 1),
+	(ActionIdWants11Bits::EntitySetVexLimitedLifetimeTicks, 1),
+	(ActionIdWants11Bits::EntitySetVillagerBiome, 1),
+	(ActionIdWants11Bits::EntitySetVillagerExperience, 1),
+	(ActionIdWants11Bits::EntitySetVillagerProfession, 1),
+	(ActionIdWants11Bits::EntitySetVisualFire, 1),
+	(ActionIdWants11Bits::EntitySetWardenAngerLevel, 1),
+	(ActionIdWants11Bits::EntitySetWardenDigging, 1),
+	(ActionIdWants11Bits::EntitySetWearingSaddle, 1),
+	(ActionIdWants11Bits::EntitySetWitherInvulnerabilityTicks, 1),
+	(ActionIdWants11Bits::EntitySetWolfType, 1),
+	(ActionIdWants11Bits::EntitySetZombieArmsRaised, 1),
+	(ActionIdWants11Bits::EntityShear, 1),
+	(ActionIdWants11Bits::EntityShearSheep, 1),
+	(ActionIdWants11Bits::EntitySleep, 1),
+	(ActionIdWants11Bits::EntitySwingHand, 1),
+	(ActionIdWants11Bits::EntityTeleport, 1),
+	(ActionIdWants11Bits::EntityUseItem, 1),
+	(ActionIdWants11Bits::GameBlockGrowth, 1),
+	(ActionIdWants11Bits::GameBloomSkulkCatalyst, 1),
+	(ActionIdWants11Bits::GameBoneMealBlock, 1),
+	(ActionIdWants11Bits::GameBreakBlock, 1),
+	(ActionIdWants11Bits::GameCancelEvent, 1),
+	(ActionIdWants11Bits::GameClearContainer, 1),
+	(ActionIdWants11Bits::GameClearContainerItems, 1),
+	(ActionIdWants11Bits::GameClearExplodedBlocks, 1),
+	(ActionIdWants11Bits::GameClearRegion, 1),
+	(ActionIdWants11Bits::GameClearScoreboardScores, 1),
+	(ActionIdWants11Bits::GameCloneRegion, 1),
+	(ActionIdWants11Bits::GameCreateExplosion, 1),
+	(ActionIdWants11Bits::GameCreateScoreboard, 1),
+	(ActionIdWants11Bits::GameDummy, 1),
+	(ActionIdWants11Bits::GameFillContainer, 1),
+	(ActionIdWants11Bits::GameGenerateTree, 1),
+	(ActionIdWants11Bits::GameHideEventMessage, 1),
+	(ActionIdWants11Bits::GameLaunchFirework, 1),
+	(ActionIdWants11Bits::GameLaunchProjectile, 1),
+	(ActionIdWants11Bits::GameRandomTickBlock, 1),
+	(ActionIdWants11Bits::GameRemoveContainerItems, 1),
+	(ActionIdWants11Bits::GameRemoveScoreboard, 1),
+	(ActionIdWants11Bits::GameRemoveScoreboardScoreByName, 1),
+	(ActionIdWants11Bits::GameRemoveScoreboardScoreByScore, 1),
+	(ActionIdWants11Bits::GameReplaceBlocksInRegion, 1),
+	(ActionIdWants11Bits::GameReplaceContainerItems, 1),
+	(ActionIdWants11Bits::GameSendWebRequest, 1),
+	(ActionIdWants11Bits::GameSetAge, 1),
+	(ActionIdWants11Bits::GameSetBlock, 1),
+	(ActionIdWants11Bits::GameSetBlockAnaloguePower, 1),
+	(ActionIdWants11Bits::GameSetBlockCustomTag, 1),
+	(ActionIdWants11Bits::GameSetBlockData, 1),
+	(ActionIdWants11Bits::GameSetBlockDropsEnabled, 1),
+	(ActionIdWants11Bits::GameSetBlockPowered, 1),
+	(ActionIdWants11Bits::GameSetBlockSingleData, 1),
+	(ActionIdWants11Bits::GameSetBrushableBlockItem, 1),
+	(ActionIdWants11Bits::GameSetCampfireItem, 1),
+	(ActionIdWants11Bits::GameSetContainer, 1),
+	(ActionIdWants11Bits::GameSetContainerLock, 1),
+	(ActionIdWants11Bits::GameSetContainerName, 1),
+	(ActionIdWants11Bits::GameSetDecoratePotSherd, 1),
+	(ActionIdWants11Bits::GameSetEventDamage, 1),
+	(ActionIdWants11Bits::GameSetEventExhaustion, 1),
+	(ActionIdWants11Bits::GameSetEventExperience, 1),
+	(ActionIdWants11Bits::GameSetEventHeal, 1),
+	(ActionIdWants11Bits::GameSetEventItem, 1),
+	(ActionIdWants11Bits::GameSetEventItems, 1),
+	(ActionIdWants11Bits::GameSetEventKnockbackVector, 1),
+	(ActionIdWants11Bits::GameSetEventMoveAllowed, 1),
+	(ActionIdWants11Bits::GameSetEventProjectile, 1),
+	(ActionIdWants11Bits::GameSetEventSound, 1),
+	(ActionIdWants11Bits::GameSetEventSourceSlot, 1),
+	(ActionIdWants11Bits::GameSetEventTargetSlot, 1),
+	(ActionIdWants11Bits::GameSetEventUeryInfo, 1),
+	(ActionIdWants11Bits::GameSetEventVelocity, 1),
+	(ActionIdWants11Bits::GameSetFurnaceCookTime, 1),
+	(ActionIdWants11Bits::GameSetItemInContainerSlot, 1),
+	(ActionIdWants11Bits::GameSetLecternBook, 1),
+	(ActionIdWants11Bits::GameSetPlayerHead, 1),
+	(ActionIdWants11Bits::GameSetRegion, 1),
+	(ActionIdWants11Bits::GameSetScoreboardLine, 1),
+	(ActionIdWants11Bits::GameSetScoreboardLineDisplay, 1),
+	(ActionIdWants11Bits::GameSetScoreboardLineFormat, 1),
+	(ActionIdWants11Bits::GameSetScoreboardNumberFormat, 1),
+	(ActionIdWants11Bits::GameSetScoreboardScore, 1),
+	(ActionIdWants11Bits::GameSetScoreboardTitle, 1),
+	(ActionIdWants11Bits::GameSetSculkShriekerCanSummon, 1),
+	(ActionIdWants11Bits::GameSetSculkShriekerShrieking, 1),
+	(ActionIdWants11Bits::GameSetSculkShriekerWarningLevel, 1),
+	(ActionIdWants11Bits::GameSetSignText, 1),
+	(ActionIdWants11Bits::GameSetSignTextColor, 1),
+	(ActionIdWants11Bits::GameSetSignWaxed, 1),
+	(ActionIdWants11Bits::GameSetSpawnerEntity, 1),
+	(ActionIdWants11Bits::GameSetWorldDifficulty, 1),
+	(ActionIdWants11Bits::GameSetWorldGamerule, 1),
+	(ActionIdWants11Bits::GameSetWorldSimulationDistance, 1),
+	(ActionIdWants11Bits::GameSetWorldTime, 1),
+	(ActionIdWants11Bits::GameSetWorldWeather, 1),
+	(ActionIdWants11Bits::GameSpawnArmorStand, 1),
+	(ActionIdWants11Bits::GameSpawnBlockDisplay, 1),
+	(ActionIdWants11Bits::GameSpawnEffectCloud, 1),
+	(ActionIdWants11Bits::GameSpawnEndCrystal, 1),
+	(ActionIdWants11Bits::GameSpawnEvokerFangs, 1),
+	(ActionIdWants11Bits::GameSpawnExperienceOrb, 1),
+	(ActionIdWants11Bits::GameSpawnEyeOfEnder, 1),
+	(ActionIdWants11Bits::GameSpawnFallingBlock, 1),
+	(ActionIdWants11Bits::GameSpawnInteractionEntity, 1),
+	(ActionIdWants11Bits::GameSpawnItem, 2),
+	(ActionIdWants11Bits::GameSpawnItemDisplay, 1),
+	(ActionIdWants11Bits::GameSpawnLightningBolt, 1),
+	(ActionIdWants11Bits::GameSpawnMob, 1),
+	(ActionIdWants11Bits::GameSpawnPrimedTnt, 1),
+	(ActionIdWants11Bits::GameSpawnShulkerBullet, 1),
+	(ActionIdWants11Bits::GameSpawnTextDisplay, 1),
+	(ActionIdWants11Bits::GameSpawnVehicle, 1),
+	(ActionIdWants11Bits::GameUncancelEvent, 1),
+	(ActionIdWants11Bits::GameUpdateBlock, 1),
+	(ActionIdWants11Bits::IfEntityCollidesAtLocation, 1),
+	(ActionIdWants11Bits::IfEntityCollidesUsingHitbox, 1),
+	(ActionIdWants11Bits::IfEntityCollidesWithEntity, 1),
+	(ActionIdWants11Bits::IfEntityDummy, 1),
+	(ActionIdWants11Bits::IfEntityExists, 1),
+	(ActionIdWants11Bits::IfEntityHasCustomTag, 1),
+	(ActionIdWants11Bits::IfEntityHasPotionEffect, 1),
+	(ActionIdWants11Bits::IfEntityInArea, 1),
+	(ActionIdWants11Bits::IfEntityIsDisguised, 1),
+	(ActionIdWants11Bits::IfEntityIsGrounded, 1),
+	(ActionIdWants11Bits::IfEntityIsItem, 1),
+	(ActionIdWants11Bits::IfEntityIsMob, 1),
+	(ActionIdWants11Bits::IfEntityIsNearLocation, 1),
+	(ActionIdWants11Bits::IfEntityIsProjectile, 1),
+	(ActionIdWants11Bits::IfEntityIsRidingEntity, 1),
+	(ActionIdWants11Bits::IfEntityIsStandingOnBlock, 1),
+	(ActionIdWants11Bits::IfEntityIsType, 1),
+	(ActionIdWants11Bits::IfEntityIsUndead, 1),
+	(ActionIdWants11Bits::IfEntityIsVehicle, 1),
+	(ActionIdWants11Bits::IfEntityNameEquals, 1),
+	(ActionIdWants11Bits::IfEntitySpawnReasonEquals, 1),
+	(ActionIdWants11Bits::IfGameBlockEquals, 1),
+	(ActionIdWants11Bits::IfGameBlockPowered, 1),
+	(ActionIdWants11Bits::IfGameChunkIsLoaded, 1),
+	(ActionIdWants11Bits::IfGameContainerHas, 1),
+	(ActionIdWants11Bits::IfGameContainerHasRoomForItem, 1),
+	(ActionIdWants11Bits::IfGameDamageCauseEquals, 1),
+	(ActionIdWants11Bits::IfGameDummy, 1),
+	(ActionIdWants11Bits::IfGameEventAttackIsCritical, 1),
+	(ActionIdWants11Bits::IfGameEventBlockEquals, 1),
+	(ActionIdWants11Bits::IfGameEventIsCanceled, 1),
+	(ActionIdWants11Bits::IfGameEventItemEquals, 1),
+	(ActionIdWants11Bits::IfGameHasPlayer, 1),
+	(ActionIdWants11Bits::IfGameHealCauseEquals, 1),
+	(ActionIdWants11Bits::IfGameIgniteCauseEquals, 1),
+	(ActionIdWants11Bits::IfGameInstrumentEquals, 1),
+	(ActionIdWants11Bits::IfGameLocationInBlock, 1),
+	(ActionIdWants11Bits::IfGameSignContains, 1),
+	(ActionIdWants11Bits::IfPlayerChatColorsEnabled, 1),
+	(ActionIdWants11Bits::IfPlayerChatMessageEquals, 1),
+	(ActionIdWants11Bits::IfPlayerCollidesAtLocation, 1),
+	(ActionIdWants11Bits::IfPlayerCollidesUsingHitbox, 1),
+	(ActionIdWants11Bits::IfPlayerCollidesWithEntity, 1),
+	(ActionIdWants11Bits::IfPlayerCursorItemEquals, 1),
+	(ActionIdWants11Bits::IfPlayerDummy, 1),
+	(ActionIdWants11Bits::IfPlayerGamemodeEquals, 1),
+	(ActionIdWants11Bits::IfPlayerHasItem, 1),
+	(ActionIdWants11Bits::IfPlayerHasItemAtLeast, 1),
+	(ActionIdWants11Bits::IfPlayerHasItemInSlot, 1),
+	(ActionIdWants11Bits::IfPlayerHasPotionEffect, 1),
+	(ActionIdWants11Bits::IfPlayerHasPrivilege, 1),
+	(ActionIdWants11Bits::IfPlayerHasRoomForItem, 1),
+	(ActionIdWants11Bits::IfPlayerHotbarSlotEquals, 1),
+	(ActionIdWants11Bits::IfPlayerInArea, 1),
+	(ActionIdWants11Bits::IfPlayerInventoryMenuSlotEquals, 1),
+	(ActionIdWants11Bits::IfPlayerInventoryTypeOpen, 1),
+	(ActionIdWants11Bits::IfPlayerIsAllowServerListing, 1),
+	(ActionIdWants11Bits::IfPlayerIsBlocking, 1),
+	(ActionIdWants11Bits::IfPlayerIsDisguised, 1),
+	(ActionIdWants11Bits::IfPlayerIsFlying, 1),
+	(ActionIdWants11Bits::IfPlayerIsGliding, 1),
+	(ActionIdWants11Bits::IfPlayerIsHolding, 1),
+	(ActionIdWants11Bits::IfPlayerIsLookingAtBlock, 1),
+	(ActionIdWants11Bits::IfPlayerIsNear, 1),
+	(ActionIdWants11Bits::IfPlayerIsOnGround, 1),
+	(ActionIdWants11Bits::IfPlayerIsOnlineMode, 1),
+	(ActionIdWants11Bits::IfPlayerIsRidingEntity, 1),
+	(ActionIdWants11Bits::IfPlayerIsSelfDisguised, 1),
+	(ActionIdWants11Bits::IfPlayerIsSleeping, 1),
+	(ActionIdWants11Bits::IfPlayerIsSneaking, 1),
+	(ActionIdWants11Bits::IfPlayerIsSprinting, 1),
+	(ActionIdWants11Bits::IfPlayerIsStandingOnBlock, 1),
+	(ActionIdWants11Bits::IfPlayerIsSwimming, 1),
+	(ActionIdWants11Bits::IfPlayerIsUsingItem, 1),
+	(ActionIdWants11Bits::IfPlayerIsWearingItem, 1),
+	(ActionIdWants11Bits::IfPlayerItemIsNotOnCooldown, 1),
+	(ActionIdWants11Bits::IfPlayerNameEquals, 1),
+	(ActionIdWants11Bits::IfPlayerTextFilteringEnabled, 1),
+	(ActionIdWants11Bits::IfVariableBlockIsSolid, 1),
+	(ActionIdWants11Bits::IfVariableDummy, 1),
+	(ActionIdWants11Bits::IfVariableEquals, 3),
+	(ActionIdWants11Bits::IfVariableExists, 1),
+	(ActionIdWants11Bits::IfVariableGreater, 3),
+	(ActionIdWants11Bits::IfVariableGreaterOrEquals, 1),
+	(ActionIdWants11Bits::IfVariableInRange, 1),
+	(ActionIdWants11Bits::IfVariableIsType, 2),
+	(ActionIdWants11Bits::IfVariableItemEquals, 1),
+	(ActionIdWants11Bits::IfVariableItemHasEnchantment, 1),
+	(ActionIdWants11Bits::IfVariableItemHasTag, 1),
+	(ActionIdWants11Bits::IfVariableItemIsBlock, 1),
+	(ActionIdWants11Bits::IfVariableLess, 2),
+	(ActionIdWants11Bits::IfVariableLessOrEquals, 2),
+	(ActionIdWants11Bits::IfVariableListContainsValue, 1),
+	(ActionIdWants11Bits::IfVariableListIsEmpty, 1),
+	(ActionIdWants11Bits::IfVariableListValueEquals, 1),
+	(ActionIdWants11Bits::IfVariableLocationInRange, 1),
+	(ActionIdWants11Bits::IfVariableLocationIsNear, 1),
+	(ActionIdWants11Bits::IfVariableMapHasKey, 1),
+	(ActionIdWants11Bits::IfVariableMapValueEquals, 1),
+	(ActionIdWants11Bits::IfVariableNotEquals, 1),
+	(ActionIdWants11Bits::IfVariableRangeIntersectsRange, 1),
+	(ActionIdWants11Bits::IfVariableTextContains, 1),
+	(ActionIdWants11Bits::IfVariableTextEndsWith, 1),
+	(ActionIdWants11Bits::IfVariableTextMatches, 1),
+	(ActionIdWants11Bits::IfVariableTextStartsWith, 1),
+	(ActionIdWants11Bits::PlayerAddInventoryMenuRow, 1),
+	(ActionIdWants11Bits::PlayerAllowPlacingBreakingBlocks, 1),
+	(ActionIdWants11Bits::PlayerBoostElytra, 1),
+	(ActionIdWants11Bits::PlayerClearChat, 1),
+	(ActionIdWants11Bits::PlayerClearDebugMarkers, 1),
+	(ActionIdWants11Bits::PlayerClearEnderChestContents, 1),
+	(ActionIdWants11Bits::PlayerClearInventory, 1),
+	(ActionIdWants11Bits::PlayerClearItems, 1),
+	(ActionIdWants11Bits::PlayerClearPotionEffects, 1),
+	(ActionIdWants11Bits::PlayerCloseInventory, 1),
+	(ActionIdWants11Bits::PlayerDamage, 1),
+	(ActionIdWants11Bits::PlayerDisguiseAsBlock, 1),
+	(ActionIdWants11Bits::PlayerDisguiseAsEntity, 1),
+	(ActionIdWants11Bits::PlayerDisguiseAsItem, 1),
+	(ActionIdWants11Bits::PlayerDisplayBellRing, 1),
+	(ActionIdWants11Bits::PlayerDisplayBlock, 1),
+	(ActionIdWants11Bits::PlayerDisplayEndGatewayBeam, 1),
+	(ActionIdWants11Bits::PlayerDisplayHologram, 1),
+	(ActionIdWants11Bits::PlayerDisplayLightning, 1),
+	(ActionIdWants11Bits::PlayerDisplayParticle, 1),
+	(ActionIdWants11Bits::PlayerDisplayParticleCircle, 1),
+	(ActionIdWants11Bits::PlayerDisplayParticleCube, 1),
+	(ActionIdWants11Bits::PlayerDisplayParticleLine, 1),
+	(ActionIdWants11Bits::PlayerDisplayParticleRay, 1),
+	(ActionIdWants11Bits::PlayerDisplayParticleSphere, 1),
+	(ActionIdWants11Bits::PlayerDisplayParticleSpiral, 1),
+	(ActionIdWants11Bits::PlayerDisplayPickUpAnimation, 1),
+	(ActionIdWants11Bits::PlayerDisplaySignText, 1),
+	(ActionIdWants11Bits::PlayerDisplayVibration, 1),
+	(ActionIdWants11Bits::PlayerDummy, 1),
+	(ActionIdWants11Bits::PlayerExpandInventoryMenu, 1),
+	(ActionIdWants11Bits::PlayerFaceLocation, 1),
+	(ActionIdWants11Bits::PlayerForceFlightMode, 1),
+	(ActionIdWants11Bits::PlayerGiveExperience, 1),
+	(ActionIdWants11Bits::PlayerGiveItems, 1),
+	(ActionIdWants11Bits::PlayerGivePotionEffect, 1),
+	(ActionIdWants11Bits::PlayerGiveRandomItem, 1),
+	(ActionIdWants11Bits::PlayerHeal, 1),
+	(ActionIdWants11Bits::PlayerHideEntity, 1),
+	(ActionIdWants11Bits::PlayerHideScoreboard, 1),
+	(ActionIdWants11Bits::PlayerKick, 1),
+	(ActionIdWants11Bits::PlayerLaunchForward, 1),
+	(ActionIdWants11Bits::PlayerLaunchProjectile, 1),
+	(ActionIdWants11Bits::PlayerLaunchToLocation, 1),
+	(ActionIdWants11Bits::PlayerLaunchUp, 1),
+	(ActionIdWants11Bits::PlayerLeaveVehicle, 1),
+	(ActionIdWants11Bits::PlayerLoadInventory, 1),
+	(ActionIdWants11Bits::PlayerOpenBook, 1),
+	(ActionIdWants11Bits::PlayerOpenContainerInventory, 1),
+	(ActionIdWants11Bits::PlayerPlayAnimationAction, 1),
+	(ActionIdWants11Bits::PlayerPlayHurtAnimation, 1),
+	(ActionIdWants11Bits::PlayerPlaySound, 1),
+	(ActionIdWants11Bits::PlayerPlaySoundFromEntity, 1),
+	(ActionIdWants11Bits::PlayerPlaySoundSequence, 1),
+	(ActionIdWants11Bits::PlayerRandomizedTeleport, 1),
+	(ActionIdWants11Bits::PlayerRedirectWorld, 1),
+	(ActionIdWants11Bits::PlayerRemoveBossBar, 1),
+	(ActionIdWants11Bits::PlayerRemoveDisguise, 1),
+	(ActionIdWants11Bits::PlayerRemoveDisplayBlocks, 1),
+	(ActionIdWants11Bits::PlayerRemoveInventoryMenuRow, 1),
+	(ActionIdWants11Bits::PlayerRemoveItems, 1),
+	(ActionIdWants11Bits::PlayerRemovePose, 1),
+	(ActionIdWants11Bits::PlayerRemovePotionEffect, 1),
+	(ActionIdWants11Bits::PlayerRemoveSelfDisguise, 1),
+	(ActionIdWants11Bits::PlayerRemoveSkin, 1),
+	(ActionIdWants11Bits::PlayerRemoveWorldBorder, 1),
+	(ActionIdWants11Bits::PlayerReplaceItems, 1),
+	(ActionIdWants11Bits::PlayerResetWeather, 1),
+	(ActionIdWants11Bits::PlayerRideEntity, 1),
+	(ActionIdWants11Bits::PlayerSaveInventory, 1),
+	(ActionIdWants11Bits::PlayerSelfDisguiseAsBlock, 1),
+	(ActionIdWants11Bits::PlayerSelfDisguiseAsEntity, 1),
+	(ActionIdWants11Bits::PlayerSelfDisguiseAsItem, 1),
+	(ActionIdWants11Bits::PlayerSendActionBar, 1),
+	(ActionIdWants11Bits::PlayerSendAdvancement, 1),
+	(ActionIdWants11Bits::PlayerSendBreakAnimation, 1),
+	(ActionIdWants11Bits::PlayerSendDialogue, 1),
+	(ActionIdWants11Bits::PlayerSendHover, 1),
+	(ActionIdWants11Bits::PlayerSendMessage, 1),
+	(ActionIdWants11Bits::PlayerSendMinimessage, 1),
+	(ActionIdWants11Bits::PlayerSendTitle, 1),
+	(ActionIdWants11Bits::PlayerSetAbsorptionHealth, 1),
+	(ActionIdWants11Bits::PlayerSetAirTicks, 1),
+	(ActionIdWants11Bits::PlayerSetAllowFlying, 1),
+	(ActionIdWants11Bits::PlayerSetArmor, 1),
+	(ActionIdWants11Bits::PlayerSetArrowsInBody, 1),
+	(ActionIdWants11Bits::PlayerSetAttackSpeed, 1),
+	(ActionIdWants11Bits::PlayerSetAttribute, 1),
+	(ActionIdWants11Bits::PlayerSetBeeStingersInBody, 1),
+	(ActionIdWants11Bits::PlayerSetBlockOpenedState, 1),
+	(ActionIdWants11Bits::PlayerSetBossBar, 1),
+	(ActionIdWants11Bits::PlayerSetChatCompletions, 1),
+	(ActionIdWants11Bits::PlayerSetCollidable, 1),
+	(ActionIdWants11Bits::PlayerSetCompassTarget, 1),
+	(ActionIdWants11Bits::PlayerSetCursorItem, 1),
+	(ActionIdWants11Bits::PlayerSetDeathDrops, 1),
+	(ActionIdWants11Bits::PlayerSetDefaultVisible, 1),
+	(ActionIdWants11Bits::PlayerSetEnderChestContents, 1),
+	(ActionIdWants11Bits::PlayerSetEntityGlowing, 1),
+	(ActionIdWants11Bits::PlayerSetEquipment, 1),
+	(ActionIdWants11Bits::PlayerSetExhaustion, 1),
+	(ActionIdWants11Bits::PlayerSetExperience, 1),
+	(ActionIdWants11Bits::PlayerSetFallDistance, 1),
+	(ActionIdWants11Bits::PlayerSetFireTicks, 1),
+	(ActionIdWants11Bits::PlayerSetFlying, 1),
+	(ActionIdWants11Bits::PlayerSetFogDistance, 1),
+	(ActionIdWants11Bits::PlayerSetFood, 1),
+	(ActionIdWants11Bits::PlayerSetFreezeTicks, 1),
+	(ActionIdWants11Bits::PlayerSetGamemode, 1),
+	(ActionIdWants11Bits::PlayerSetGliding, 1),
+	(ActionIdWants11Bits::PlayerSetHealth, 1),
+	(ActionIdWants11Bits::PlayerSetHotbarSlot, 1),
+	(ActionIdWants11Bits::PlayerSetInstantRespawn, 1),
+	(ActionIdWants11Bits::PlayerSetInventoryKept, 1),
+	(ActionIdWants11Bits::PlayerSetInventoryMenuItem, 1),
+	(ActionIdWants11Bits::PlayerSetInventoryMenuName, 1),
+	(ActionIdWants11Bits::PlayerSetInvulnerabilityTicks, 1),
+	(ActionIdWants11Bits::PlayerSetItemCooldown, 1),
+	(ActionIdWants11Bits::PlayerSetItems, 1),
+	(ActionIdWants11Bits::PlayerSetMaxHealth, 1),
+	(ActionIdWants11Bits::PlayerSetMovementSpeed, 1),
+	(ActionIdWants11Bits::PlayerSetNametagVisible, 1),
+	(ActionIdWants11Bits::PlayerSetPlayerListInfo, 1),
+	(ActionIdWants11Bits::PlayerSetPose, 1),
+	(ActionIdWants11Bits::PlayerSetPvp, 1),
+	(ActionIdWants11Bits::PlayerSetRainLevel, 1),
+	(ActionIdWants11Bits::PlayerSetRotation, 1),
+	(ActionIdWants11Bits::PlayerSetRotationByVector, 1),
+	(ActionIdWants11Bits::PlayerSetSaturation, 1),
+	(ActionIdWants11Bits::PlayerSetSimulationDistance, 1),
+	(ActionIdWants11Bits::PlayerSetSkin, 1),
+	(ActionIdWants11Bits::PlayerSetSlotItem, 1),
+	(ActionIdWants11Bits::PlayerSetSpawnPoint, 1),
+	(ActionIdWants11Bits::PlayerSetThunderLevel, 1),
+	(ActionIdWants11Bits::PlayerSetTickRate, 1),
+	(ActionIdWants11Bits::PlayerSetTime, 1),
+	(ActionIdWants11Bits::PlayerSetVelocity, 1),
+	(ActionIdWants11Bits::PlayerSetVisualFire, 1),
+	(ActionIdWants11Bits::PlayerSetWeather, 1),
+	(ActionIdWants11Bits::PlayerSetWorldBorder, 1),
+	(ActionIdWants11Bits::PlayerShiftWorldBorder, 1),
+	(ActionIdWants11Bits::PlayerShowDebugMarker, 1),
+	(ActionIdWants11Bits::PlayerShowDemoScreen, 1),
+	(ActionIdWants11Bits::PlayerShowInventoryMenu, 1),
+	(ActionIdWants11Bits::PlayerShowScoreboard, 1),
+	(ActionIdWants11Bits::PlayerShowWinScreen, 1),
+	(ActionIdWants11Bits::PlayerSpectateTarget, 1),
+	(ActionIdWants11Bits::PlayerStopSound, 1),
+	(ActionIdWants11Bits::PlayerStopSoundsBySource, 1),
+	(ActionIdWants11Bits::PlayerSwingHand, 1),
+	(ActionIdWants11Bits::PlayerTeleport, 1),
+	(ActionIdWants11Bits::PlayerTeleportSequence, 1),
+	(ActionIdWants11Bits::RepeatAdjacently, 1),
+	(ActionIdWants11Bits::RepeatDummy, 1),
+	(ActionIdWants11Bits::RepeatForEachInList, 4),
+	(ActionIdWants11Bits::RepeatForEachMapEntry, 1),
+	(ActionIdWants11Bits::RepeatForever, 2),
+	(ActionIdWants11Bits::RepeatMultiTimes, 1),
+	(ActionIdWants11Bits::RepeatOnCircle, 1),
+	(ActionIdWants11Bits::RepeatOnGrid, 1),
+	(ActionIdWants11Bits::RepeatOnPath, 1),
+	(ActionIdWants11Bits::RepeatOnRange, 1),
+	(ActionIdWants11Bits::RepeatOnSphere, 2),
+	(ActionIdWants11Bits::RepeatWhile, 2),
+	(ActionIdWants11Bits::SelectAddAllEntities, 1),
+	(ActionIdWants11Bits::SelectAddAllMobs, 1),
+	(ActionIdWants11Bits::SelectAddAllPlayers, 1),
+	(ActionIdWants11Bits::SelectAddEntityByConditional, 1),
+	(ActionIdWants11Bits::SelectAddEntityByName, 1),
+	(ActionIdWants11Bits::SelectAddEventTarget, 1),
+	(ActionIdWants11Bits::SelectAddLastEntity, 1),
+	(ActionIdWants11Bits::SelectAddLastMob, 1),
+	(ActionIdWants11Bits::SelectAddMobByName, 1),
+	(ActionIdWants11Bits::SelectAddPlayerByConditional, 1),
+	(ActionIdWants11Bits::SelectAddPlayerByName, 1),
+	(ActionIdWants11Bits::SelectAddRandomEntity, 1),
+	(ActionIdWants11Bits::SelectAddRandomMob, 1),
+	(ActionIdWants11Bits::SelectAddRandomPlayer, 1),
+	(ActionIdWants11Bits::SelectAllEntities, 1),
+	(ActionIdWants11Bits::SelectAllMobs, 1),
+	(ActionIdWants11Bits::SelectAllPlayers, 1),
+	(ActionIdWants11Bits::SelectDummy, 1),
+	(ActionIdWants11Bits::SelectEntityByConditional, 1),
+	(ActionIdWants11Bits::SelectEntityByName, 1),
+	(ActionIdWants11Bits::SelectEventTarget, 1),
+	(ActionIdWants11Bits::SelectFilterByConditional, 1),
+	(ActionIdWants11Bits::SelectFilterByDistance, 1),
+	(ActionIdWants11Bits::SelectFilterByRaycast, 1),
+	(ActionIdWants11Bits::SelectFilterRandomly, 1),
+	(ActionIdWants11Bits::SelectInvert, 1),
+	(ActionIdWants11Bits::SelectLastEntity, 1),
+	(ActionIdWants11Bits::SelectLastMob, 1),
+	(ActionIdWants11Bits::SelectMobByName, 1),
+	(ActionIdWants11Bits::SelectPlayerByConditional, 1),
+	(ActionIdWants11Bits::SelectPlayerByName, 1),
+	(ActionIdWants11Bits::SelectRandomEntity, 1),
+	(ActionIdWants11Bits::SelectRandomMob, 1),
+	(ActionIdWants11Bits::SelectRandomPlayer, 1),
+	(ActionIdWants11Bits::SelectReset, 1),
+	(ActionIdWants11Bits::SetVariableAbsolute, 1),
+	(ActionIdWants11Bits::SetVariableAdd, 1),
+	(ActionIdWants11Bits::SetVariableAddItemEnchantment, 1),
+	(ActionIdWants11Bits::SetVariableAddItemPotionEffects, 1),
+	(ActionIdWants11Bits::SetVariableAddVectors, 1),
+	(ActionIdWants11Bits::SetVariableAlignLocation, 1),
+	(ActionIdWants11Bits::SetVariableAlignToAxisVector, 1),
+	(ActionIdWants11Bits::SetVariableAppendComponent, 1),
+	(ActionIdWants11Bits::SetVariableAppendList, 1),
+	(ActionIdWants11Bits::SetVariableAppendMap, 1),
+	(ActionIdWants11Bits::SetVariableAppendValue, 5),
+	(ActionIdWants11Bits::SetVariableAtan2, 1),
+	(ActionIdWants11Bits::SetVariableAverage, 1),
+	(ActionIdWants11Bits::SetVariableBitwiseOperation, 1),
+	(ActionIdWants11Bits::SetVariableCenterLocation, 1),
+	(ActionIdWants11Bits::SetVariableChangeComponentParsing, 1),
+	(ActionIdWants11Bits::SetVariableCharToNumber, 1),
+	(ActionIdWants11Bits::SetVariableClamp, 1),
+	(ActionIdWants11Bits::SetVariableClearColorCodes, 1),
+	(ActionIdWants11Bits::SetVariableClearMap, 1),
+	(ActionIdWants11Bits::SetVariableCompactComponent, 1),
+	(ActionIdWants11Bits::SetVariableComponentOfChildren, 1),
+	(ActionIdWants11Bits::SetVariableConvertNumberToText, 1),
+	(ActionIdWants11Bits::SetVariableConvertTextToNumber, 1),
+	(ActionIdWants11Bits::SetVariableCosine, 1),
+	(ActionIdWants11Bits::SetVariableCotangent, 1),
+	(ActionIdWants11Bits::SetVariableCreateKeybindComponent, 1),
+	(ActionIdWants11Bits::SetVariableCreateList, 5),
+	(ActionIdWants11Bits::SetVariableCreateMap, 1),
+	(ActionIdWants11Bits::SetVariableCreateMapFromValues, 1),
+	(ActionIdWants11Bits::SetVariableCreateTranslatableComponent, 1),
+	(ActionIdWants11Bits::SetVariableDecrement, 3),
+	(ActionIdWants11Bits::SetVariableDivide, 2),
+	(ActionIdWants11Bits::SetVariableDivideVector, 1),
+	(ActionIdWants11Bits::SetVariableDummy, 1),
+	(ActionIdWants11Bits::SetVariableFaceLocation, 1),
+	(ActionIdWants11Bits::SetVariableFlattenList, 1),
+	(ActionIdWants11Bits::SetVariableFormatTimestamp, 1),
+	(ActionIdWants11Bits::SetVariableGaussianDistribution, 1),
+	(ActionIdWants11Bits::SetVariableGetAllBlockData, 1),
+	(ActionIdWants11Bits::SetVariableGetAllCoordinates, 1),
+	(ActionIdWants11Bits::SetVariableGetAngleBetweenVectors, 1),
+	(ActionIdWants11Bits::SetVariableGetBlockCustomTag, 1),
+	(ActionIdWants11Bits::SetVariableGetBlockData, 1),
+	(ActionIdWants11Bits::SetVariableGetBlockGrowth, 1),
+	(ActionIdWants11Bits::SetVariableGetBlockMaterial, 1),
+	(ActionIdWants11Bits::SetVariableGetBlockMaterialProperty, 1),
+	(ActionIdWants11Bits::SetVariableGetBlockPower, 1),
+	(ActionIdWants11Bits::SetVariableGetBlockSound, 1),
+	(ActionIdWants11Bits::SetVariableGetBookText, 1),
+	(ActionIdWants11Bits::SetVariableGetBrushableBlockItem, 1),
+	(ActionIdWants11Bits::SetVariableGetBundleItems, 1),
+	(ActionIdWants11Bits::SetVariableGetCharAt, 1),
+	(ActionIdWants11Bits::SetVariableGetColorChannels, 1),
+	(ActionIdWants11Bits::SetVariableGetCompassLodestone, 1),
+	(ActionIdWants11Bits::SetVariableGetComponentChildren, 1),
+	(ActionIdWants11Bits::SetVariableGetComponentDecorations, 1),
+	(ActionIdWants11Bits::SetVariableGetComponentHexColor, 1),
+	(ActionIdWants11Bits::SetVariableGetComponentParsing, 1),
+	(ActionIdWants11Bits::SetVariableGetContainerContents, 1),
+	(ActionIdWants11Bits::SetVariableGetContainerLock, 1),
+	(ActionIdWants11Bits::SetVariableGetContainerName, 1),
+	(ActionIdWants11Bits::SetVariableGetCoordinate, 1),
+	(ActionIdWants11Bits::SetVariableGetDecoratePotSherd, 1),
+	(ActionIdWants11Bits::SetVariableGetIndexOfSubtext, 1),
+	(ActionIdWants11Bits::SetVariableGetItemAmount, 1),
+	(ActionIdWants11Bits::SetVariableGetItemAttribute, 1),
+	(ActionIdWants11Bits::SetVariableGetItemColor, 1),
+	(ActionIdWants11Bits::SetVariableGetItemCustomModelData, 1),
+	(ActionIdWants11Bits::SetVariableGetItemCustomTag, 1),
+	(ActionIdWants11Bits::SetVariableGetItemCustomTags, 1),
+	(ActionIdWants11Bits::SetVariableGetItemDestroyableBlocks, 1),
+	(ActionIdWants11Bits::SetVariableGetItemDurability, 1),
+	(ActionIdWants11Bits::SetVariableGetItemEnchantments, 1),
+	(ActionIdWants11Bits::SetVariableGetItemLore, 1),
+	(ActionIdWants11Bits::SetVariableGetItemLoreLine, 1),
+	(ActionIdWants11Bits::SetVariableGetItemMaxStackSize, 1),
+	(ActionIdWants11Bits::SetVariableGetItemName, 1),
+	(ActionIdWants11Bits::SetVariableGetItemNbtTags, 1),
+	(ActionIdWants11Bits::SetVariableGetItemPlaceableBlocks, 1),
+	(ActionIdWants11Bits::SetVariableGetItemPotionEffects, 1),
+	(ActionIdWants11Bits::SetVariableGetItemRarity, 1),
+	(ActionIdWants11Bits::SetVariableGetItemType, 1),
+	(ActionIdWants11Bits::SetVariableGetLecternBook, 1),
+	(ActionIdWants11Bits::SetVariableGetLecternPage, 1),
+	(ActionIdWants11Bits::SetVariableGetLightLevel, 1),
+	(ActionIdWants11Bits::SetVariableGetListIndexOfValue, 1),
+	(ActionIdWants11Bits::SetVariableGetListLength, 4),
+	(ActionIdWants11Bits::SetVariableGetListRandomValue, 1),
+	(ActionIdWants11Bits::SetVariableGetListValue, 4),
+	(ActionIdWants11Bits::SetVariableGetListVariables, 1),
+	(ActionIdWants11Bits::SetVariableGetLocationDirection, 1),
+	(ActionIdWants11Bits::SetVariableGetMapKeyByIndex, 1),
+	(ActionIdWants11Bits::SetVariableGetMapKeys, 1),
+	(ActionIdWants11Bits::SetVariableGetMapKeysByValue, 1),
+	(ActionIdWants11Bits::SetVariableGetMapSize, 1),
+	(ActionIdWants11Bits::SetVariableGetMapValue, 1),
+	(ActionIdWants11Bits::SetVariableGetMapValueByIndex, 1),
+	(ActionIdWants11Bits::SetVariableGetMapValues, 1),
+	(ActionIdWants11Bits::SetVariableGetMidpointBetweenVectors, 1),
+	(ActionIdWants11Bits::SetVariableGetParticleAmount, 1),
+	(ActionIdWants11Bits::SetVariableGetParticleColor, 1),
+	(ActionIdWants11Bits::SetVariableGetParticleMaterial, 1),
+	(ActionIdWants11Bits::SetVariableGetParticleOffset, 1),
+	(ActionIdWants11Bits::SetVariableGetParticleSize, 1),
+	(ActionIdWants11Bits::SetVariableGetParticleSpread, 1),
+	(ActionIdWants11Bits::SetVariableGetParticleType, 1),
+	(ActionIdWants11Bits::SetVariableGetPlayerHead, 1),
+	(ActionIdWants11Bits::SetVariableGetPlayerHeadOwner, 1),
+	(ActionIdWants11Bits::SetVariableGetPlayerHeadValue, 1),
+	(ActionIdWants11Bits::SetVariableGetPotionEffectAmplifier, 1),
+	(ActionIdWants11Bits::SetVariableGetPotionEffectDuration, 1),
+	(ActionIdWants11Bits::SetVariableGetPotionEffectType, 1),
+	(ActionIdWants11Bits::SetVariableGetSculkShriekerWarningLevel, 1),
+	(ActionIdWants11Bits::SetVariableGetSignText, 1),
+	(ActionIdWants11Bits::SetVariableGetSoundPitch, 1),
+	(ActionIdWants11Bits::SetVariableGetSoundSource, 1),
+	(ActionIdWants11Bits::SetVariableGetSoundType, 1),
+	(ActionIdWants11Bits::SetVariableGetSoundVariation, 1),
+	(ActionIdWants11Bits::SetVariableGetSoundVariations, 1),
+	(ActionIdWants11Bits::SetVariableGetSoundVolumeAction, 1),
+	(ActionIdWants11Bits::SetVariableGetTemplateCode, 1),
+	(ActionIdWants11Bits::SetVariableGetTextWidth, 1),
+	(ActionIdWants11Bits::SetVariableGetVectorAllComponents, 1),
+	(ActionIdWants11Bits::SetVariableGetVectorBetweenLocations, 2),
+	(ActionIdWants11Bits::SetVariableGetVectorComponent, 1),
+	(ActionIdWants11Bits::SetVariableGetVectorFromBlockFace, 1),
+	(ActionIdWants11Bits::SetVariableGetVectorLength, 1),
+	(ActionIdWants11Bits::SetVariableHash, 1),
+	(ActionIdWants11Bits::SetVariableIncrement, 3),
+	(ActionIdWants11Bits::SetVariableInsertListValue, 1),
+	(ActionIdWants11Bits::SetVariableJoinText, 1),
+	(ActionIdWants11Bits::SetVariableLerpNumber, 1),
+	(ActionIdWants11Bits::SetVariableLocationRelative, 1),
+	(ActionIdWants11Bits::SetVariableLocationsDistance, 1),
+	(ActionIdWants11Bits::SetVariableLog, 3),
+	(ActionIdWants11Bits::SetVariableMapRange, 1),
+	(ActionIdWants11Bits::SetVariableMax, 1),
+	(ActionIdWants11Bits::SetVariableMin, 2),
+	(ActionIdWants11Bits::SetVariableMultiply, 2),
+	(ActionIdWants11Bits::SetVariableMultiplyVector, 2),
+	(ActionIdWants11Bits::SetVariableParseJson, 1),
+	(ActionIdWants11Bits::SetVariableParseToComponent, 1),
+	(ActionIdWants11Bits::SetVariablePerlinNoise3d, 1),
+	(ActionIdWants11Bits::SetVariablePow, 2),
+	(ActionIdWants11Bits::SetVariablePurge, 1),
+	(ActionIdWants11Bits::SetVariableRandom, 1),
+	(ActionIdWants11Bits::SetVariableRandomLocation, 1),
+	(ActionIdWants11Bits::SetVariableRandomNumber, 1),
+	(ActionIdWants11Bits::SetVariableRandomizeListOrder, 1),
+	(ActionIdWants11Bits::SetVariableRayTraceResult, 1),
+	(ActionIdWants11Bits::SetVariableReflectVectorProduct, 1),
+	(ActionIdWants11Bits::SetVariableRegexReplaceText, 1),
+	(ActionIdWants11Bits::SetVariableRemainder, 1),
+	(ActionIdWants11Bits::SetVariableRemoveCompassLodestone, 1),
+	(ActionIdWants11Bits::SetVariableRemoveEnchantment, 1),
+	(ActionIdWants11Bits::SetVariableRemoveItemAttribute, 1),
+	(ActionIdWants11Bits::SetVariableRemoveItemCustomModelData, 1),
+	(ActionIdWants11Bits::SetVariableRemoveItemCustomTag, 1),
+	(ActionIdWants11Bits::SetVariableRemoveItemLoreLine, 1),
+	(ActionIdWants11Bits::SetVariableRemoveItemPotionEffects, 1),
+	(ActionIdWants11Bits::SetVariableRemoveListDuplicates, 2),
+	(ActionIdWants11Bits::SetVariableRemoveListValue, 1),
+	(ActionIdWants11Bits::SetVariableRemoveListValueAtIndex, 1),
+	(ActionIdWants11Bits::SetVariableRemoveMapEntry, 1),
+	(ActionIdWants11Bits::SetVariableRemoveText, 1),
+	(ActionIdWants11Bits::SetVariableRepeatText, 1),
+	(ActionIdWants11Bits::SetVariableReplaceText, 1),
+	(ActionIdWants11Bits::SetVariableReverseList, 1),
+	(ActionIdWants11Bits::SetVariableRoot, 1),
+	(ActionIdWants11Bits::SetVariableRotateVectorAroundAxis, 1),
+	(ActionIdWants11Bits::SetVariableRotateVectorAroundVector, 1),
+	(ActionIdWants11Bits::SetVariableRound, 4),
+	(ActionIdWants11Bits::SetVariableSetAllCoordinates, 1),
+	(ActionIdWants11Bits::SetVariableSetArmorTrim, 1),
+	(ActionIdWants11Bits::SetVariableSetBookPage, 1),
+	(ActionIdWants11Bits::SetVariableSetBookPages, 1),
+	(ActionIdWants11Bits::SetVariableSetBundleItems, 1),
+	(ActionIdWants11Bits::SetVariableSetCompassLodestone, 1),
+	(ActionIdWants11Bits::SetVariableSetComponentChildren, 1),
+	(ActionIdWants11Bits::SetVariableSetComponentClick, 1),
+	(ActionIdWants11Bits::SetVariableSetComponentDecorations, 1),
+	(ActionIdWants11Bits::SetVariableSetComponentEntityHover, 1),
+	(ActionIdWants11Bits::SetVariableSetComponentFont, 1),
+	(ActionIdWants11Bits::SetVariableSetComponentHexColor, 1),
+	(ActionIdWants11Bits::SetVariableSetComponentHover, 1),
+	(ActionIdWants11Bits::SetVariableSetComponentInsertion, 1),
+	(ActionIdWants11Bits::SetVariableSetComponentItemHover, 1),
+	(ActionIdWants11Bits::SetVariableSetCoordinate, 1),
+	(ActionIdWants11Bits::SetVariableSetItemAmount, 1),
+	(ActionIdWants11Bits::SetVariableSetItemAttribute, 1),
+	(ActionIdWants11Bits::SetVariableSetItemColor, 1),
+	(ActionIdWants11Bits::SetVariableSetItemComponent, 1),
+	(ActionIdWants11Bits::SetVariableSetItemCustomModelData, 1),
+	(ActionIdWants11Bits::SetVariableSetItemCustomTag, 3),
+	(ActionIdWants11Bits::SetVariableSetItemDestroyableBlocks, 1),
+	(ActionIdWants11Bits::SetVariableSetItemDurability, 1),
+	(ActionIdWants11Bits::SetVariableSetItemEnchantments, 1),
+	(ActionIdWants11Bits::SetVariableSetItemLore, 1),
+	(ActionIdWants11Bits::SetVariableSetItemLoreLine, 1),
+	(ActionIdWants11Bits::SetVariableSetItemMaxStackSize, 1),
+	(ActionIdWants11Bits::SetVariableSetItemName, 1),
+	(ActionIdWants11Bits::SetVariableSetItemPlaceableBlocks, 1),
+	(ActionIdWants11Bits::SetVariableSetItemType, 1),
+	(ActionIdWants11Bits::SetVariableSetItemUnbreakable, 1),
+	(ActionIdWants11Bits::SetVariableSetItemVisibilityFlags, 1),
+	(ActionIdWants11Bits::SetVariableSetListValue, 1),
+	(ActionIdWants11Bits::SetVariableSetLocationDirection, 1),
+	(ActionIdWants11Bits::SetVariableSetMapValue, 1),
+	(ActionIdWants11Bits::SetVariableSetParticleAmount, 1),
+	(ActionIdWants11Bits::SetVariableSetParticleColor, 1),
+	(ActionIdWants11Bits::SetVariableSetParticleMaterial, 1),
+	(ActionIdWants11Bits::SetVariableSetParticleOffset, 1),
+	(ActionIdWants11Bits::SetVariableSetParticleSize, 1),
+	(ActionIdWants11Bits::SetVariableSetParticleSpread, 1),
+	(ActionIdWants11Bits::SetVariableSetParticleType, 1),
+	(ActionIdWants11Bits::SetVariableSetPotionEffectAmplifier, 1),
+	(ActionIdWants11Bits::SetVariableSetPotionEffectDuration, 1),
+	(ActionIdWants11Bits::SetVariableSetPotionEffectType, 1),
+	(ActionIdWants11Bits::SetVariableSetSoundPitch, 1),
+	(ActionIdWants11Bits::SetVariableSetSoundSource, 1),
+	(ActionIdWants11Bits::SetVariableSetSoundType, 1),
+	(ActionIdWants11Bits::SetVariableSetSoundVariation, 1),
+	(ActionIdWants11Bits::SetVariableSetSoundVolumeAction, 1),
+	(ActionIdWants11Bits::SetVariableSetTemplateCode, 1),
+	(ActionIdWants11Bits::SetVariableSetTextureToMap, 1),
+	(ActionIdWants11Bits::SetVariableSetVectorComponent, 1),
+	(ActionIdWants11Bits::SetVariableSetVectorLength, 1),
+	(ActionIdWants11Bits::SetVariableShiftAllCoordinates, 1),
+	(ActionIdWants11Bits::SetVariableShiftCoordinate, 1),
+	(ActionIdWants11Bits::SetVariableShiftLocationInDirection, 1),
+	(ActionIdWants11Bits::SetVariableShiftLocationOnVector, 1),
+	(ActionIdWants11Bits::SetVariableShiftLocationTowardsLocation, 1),
+	(ActionIdWants11Bits::SetVariableSimplexNoise3d, 1),
+	(ActionIdWants11Bits::SetVariableSine, 1),
+	(ActionIdWants11Bits::SetVariableSortAnyList, 2),
+	(ActionIdWants11Bits::SetVariableSortAnyMap, 1),
+	(ActionIdWants11Bits::SetVariableSplitText, 1),
+	(ActionIdWants11Bits::SetVariableStripText, 1),
+	(ActionIdWants11Bits::SetVariableSubtract, 5),
+	(ActionIdWants11Bits::SetVariableSubtractVectors, 1),
+	(ActionIdWants11Bits::SetVariableTangent, 1),
+	(ActionIdWants11Bits::SetVariableText, 1),
+	(ActionIdWants11Bits::SetVariableTextCase, 1),
+	(ActionIdWants11Bits::SetVariableTextLength, 1),
+	(ActionIdWants11Bits::SetVariableToChar, 1),
+	(ActionIdWants11Bits::SetVariableToHsb, 1),
+	(ActionIdWants11Bits::SetVariableToHsl, 1),
+	(ActionIdWants11Bits::SetVariableToJson, 1),
+	(ActionIdWants11Bits::SetVariableToRgb, 1),
+	(ActionIdWants11Bits::SetVariableTrimList, 1),
+	(ActionIdWants11Bits::SetVariableTrimText, 1),
+	(ActionIdWants11Bits::SetVariableValue, 6),
+	(ActionIdWants11Bits::SetVariableVector, 1),
+	(ActionIdWants11Bits::SetVariableVectorCrossProduct, 1),
+	(ActionIdWants11Bits::SetVariableVectorDotProduct, 1),
+	(ActionIdWants11Bits::SetVariableVectorToDirectionName, 1),
+	(ActionIdWants11Bits::SetVariableVoronoiNoise3d, 1),
+	(ActionIdWants11Bits::SetVariableWarp, 1),
+	(ActionIdWants11Bits::StartProcess, 1),
+];
+
+pub const VALUE_TAG_COUNTS: &[(&str, usize)] = &[
+	("array", 20),
+	("enum", 13),
+	("game_value", 1),
+	("item", 3),
+	("number", 47),
+	("text", 7),
+	("variable", 102),
+];
+
+pub const ARG_NAME_COUNTS: &[(&str, usize)] = &[
+	("able", 1),
+	("age", 1),
+	("aggressive", 1),
+	("ai", 1),
+	("algorithm", 1),
+	("align_mode", 1),
+	("allow", 1),
+	("allow_flying", 1),
+	("allowed", 1),
+	("alpha", 1),
+	("amount", 9),
+	("amplifier", 1),
+	("amplitude", 2),
+	("anger", 1),
+	("angle", 3),
+	("angle_unit", 3),
+	("angle_units", 3),
+	("angry", 1),
+	("animation", 1),
+	("apply_motion", 2),
+	("args", 1),
+	("armor", 1),
+	("arms", 1),
+	("arms_raised", 1),
+	("attribute", 3),
+	("attribute_type", 2),
+	("aware", 1),
+	("axis", 1),
+	("axis_vector", 2),
+	("axolotl_type", 1),
+	("baby", 1),
+	("base", 6),
+	("base_plate", 2),
+	("beam", 1),
+	("billboard_type", 1),
+	("biome", 1),
+	("block", 16),
+	("block_data", 1),
+	("block_face", 2),
+	("block_light_level", 1),
+	("block_offset", 1),
+	("blocks", 6),
+	("bloom_location", 1),
+	("blue", 2),
+	("body_color", 1),
+	("body_part", 1),
+	("bold", 1),
+	("book", 4),
+	("boots", 4),
+	("border_handling", 1),
+	("bounce", 1),
+	("break_blocks", 1),
+	("brightness", 1),
+	("bundle", 2),
+	("can_mob_pickup", 3),
+	("can_player_pickup", 2),
+	("can_summon", 1),
+	("cannon_eq", 1),
+	("carrying", 1),
+	("case_type", 1),
+	("cat_type", 1),
+	("cause", 2),
+	("celebrating", 1),
+	("center", 7),
+	("change_rotation", 1),
+	("char", 1),
+	("charge", 1),
+	("charged", 1),
+	("charging", 2),
+	("chat_messages", 1),
+	("check", 1),
+	("check_mode", 9),
+	("check_side", 2),
+	("check_type", 5),
+	("checked_slots", 1),
+	("chestplate", 4),
+	("children", 1),
+	("circle_points", 1),
+	("clear_mode", 1),
+	("click_action", 1),
+	("code", 1),
+	("collected_name_or_uuid", 1),
+	("collector_name_or_uuid", 1),
+	("collidable", 2),
+	("color", 7),
+	("color_channels", 1),
+	("color_hexadecimal", 2),
+	("color_type", 2),
+	("comments", 1),
+	("compare", 16),
+	("compare_mode", 3),
+	("compare_type", 2),
+	("comparison_mode", 11),
+	("completions", 1),
+	("component", 16),
+	("components", 2),
+	("consider_blocks", 1),
+	("container_key", 1),
+	("content_body", 1),
+	("content_type", 1),
+	("cooking_time", 1),
+	("cooldown", 1),
+	("coordinate", 1),
+	("coordinates_mode", 1),
+	("copy_entity", 1),
+	("count", 4),
+	("cow_type", 1),
+	("custom_name", 18),
+	("damage", 4),
+	("damage_type", 1),
+	("dance", 1),
+	("dancing_time", 1),
+	("dark_ticks", 1),
+	("dashing", 1),
+	("data", 1),
+	("death_drops", 1),
+	("death_time", 1),
+	("default", 1),
+	("default_value", 10),
+	("default_visible", 2),
+	("delay", 4),
+	("delimiter", 1),
+	("demand", 1),
+	("description", 3),
+	("despawning", 1),
+	("destination", 1),
+	("destination_time", 1),
+	("destroyable", 1),
+	("deviant", 1),
+	("difficulty", 1),
+	("digging", 1),
+	("direction", 2),
+	("dismount", 2),
+	("displacement", 1),
+	("display", 2),
+	("display_model_type", 1),
+	("display_name", 2),
+	("displayed_block", 1),
+	("displayed_item", 2),
+	("displayed_text", 2),
+	("distance", 10),
+	("distribution", 1),
+	("dividend", 1),
+	("divider", 3),
+	("division_mode", 2),
+	("divisor", 1),
+	("dot_matches_all", 1),
+	("drop_exp", 1),
+	("drops", 1),
+	("durability", 1),
+	("durability_type", 2),
+	("duration", 6),
+	("effect_type", 1),
+	("effects", 3),
+	("enable", 2),
+	("enable_distance", 1),
+	("enable_see_through", 1),
+	("enable_text_shadow", 1),
+	("enchant", 1),
+	("enchantment", 2),
+	("enchantments", 1),
+	("end", 5),
+	("end_location", 2),
+	("end_of_lifespan", 1),
+	("entities", 1),
+	("entity", 1),
+	("entity_ids", 2),
+	("entity_type", 3),
+	("entity_types", 1),
+	("exact", 1),
+	("exception_type", 1),
+	("exhaustion", 2),
+	("experience", 4),
+	("experience_amount", 1),
+	("experience_reward", 1),
+	("fade_in", 1),
+	("fade_out", 1),
+	("fall_distance", 1),
+	("fetch_mode", 1),
+	("find_mode", 1),
+	("fire", 1),
+	("firework", 2),
+	("first", 2),
+	("first_corner", 1),
+	("flight_mode", 1),
+	("fluid_collision_mode", 2),
+	("fluid_mode", 1),
+	("food", 1),
+	("format", 4),
+	("format_content", 3),
+	("fox_type", 1),
+	("frame", 1),
+	("frequency", 3),
+	("friction", 1),
+	("frog_variant", 1),
+	("from", 1),
+	("from_start", 1),
+	("from_stop", 1),
+	("function_name", 4),
+	("fuse_duration", 1),
+	("fuse_ticks", 2),
+	("gamemode", 2),
+	("gamerule", 1),
+	("gene", 1),
+	("gene_type", 1),
+	("glow", 1),
+	("glowing", 2),
+	("gravity", 2),
+	("green", 2),
+	("growth_stage", 1),
+	("growth_type", 1),
+	("growth_unit", 1),
+	("hand", 1),
+	("hand_slot", 1),
+	("hand_type", 2),
+	("head", 1),
+	("heal", 4),
+	("heal_cause", 1),
+	("heal_to_max", 1),
+	("health", 6),
+	("height", 3),
+	("helmet", 4),
+	("hex_color", 1),
+	("hide", 2),
+	("hide_armor_trim", 1),
+	("hide_attributes", 1),
+	("hide_destroys", 1),
+	("hide_dye", 1),
+	("hide_enchantments", 1),
+	("hide_place_on", 1),
+	("hide_potion_effects", 1),
+	("hide_unbreakable", 1),
+	("hide_unspecified", 1),
+	("horizontal", 1),
+	("horse_color", 1),
+	("horse_style", 1),
+	("hover", 3),
+	("hue", 2),
+	("icon", 4),
+	("id", 15),
+	("ignore_air", 1),
+	("ignore_case", 7),
+	("ignore_discounts", 1),
+	("ignore_empty_slots", 1),
+	("ignore_passable_blocks", 2),
+	("ignore_y_axis", 5),
+	("inaccuracy", 3),
+	("include_self", 1),
+	("increment", 9),
+	("index", 6),
+	("index_variable", 4),
+	("information", 1),
+	("ingredient_one", 1),
+	("ingredient_two", 1),
+	("input", 6),
+	("insertion", 1),
+	("instant", 1),
+	("instant_respawn", 1),
+	("instrument", 1),
+	("integer", 2),
+	("interpolation_delay", 1),
+	("interpolation_duration", 1),
+	("intersect_type", 2),
+	("interval", 1),
+	("inventory_type", 2),
+	("invisible", 2),
+	("invulnerable", 1),
+	("is_flying", 2),
+	("is_gliding", 2),
+	("is_hidden", 3),
+	("is_immune", 1),
+	("is_opened", 1),
+	("italic", 1),
+	("item", 71),
+	("items", 29),
+	("json", 1),
+	("keep_rotation", 3),
+	("keep_velocity", 2),
+	("kept", 1),
+	("key", 7),
+	("key_variable", 1),
+	("keys", 2),
+	("knockback", 1),
+	("launch_axis", 2),
+	("leaping", 1),
+	("left_hand", 1),
+	("leggings", 4),
+	("length", 2),
+	("length_type", 1),
+	("level", 2),
+	("lifespan", 1),
+	("lifetime", 1),
+	("lightness", 1),
+	("limit", 1),
+	("line", 7),
+	("line_1", 1),
+	("line_2", 1),
+	("line_3", 1),
+	("line_4", 1),
+	("line_width", 1),
+	("lines", 1),
+	("list", 30),
+	("list_1", 1),
+	("list_2", 1),
+	("literal", 1),
+	("loc_frequency", 2),
+	("local_variables_mode", 1),
+	("locale", 1),
+	("location", 121),
+	("location_1", 4),
+	("location_2", 4),
+	("location_from", 1),
+	("location_to", 1),
+	("locations", 12),
+	("lock", 1),
+	("locked", 1),
+	("lore", 1),
+	("lying_down", 1),
+	("main_hand", 1),
+	("map", 16),
+	("marker", 2),
+	("match", 2),
+	("material", 2),
+	("materials", 2),
+	("max", 7),
+	("max1", 1),
+	("max2", 1),
+	("max_distance", 2),
+	("max_health", 1),
+	("max_uses", 1),
+	("mean", 1),
+	("merging", 5),
+	("merging_mode", 2),
+	("message", 2),
+	("messages", 3),
+	("min", 7),
+	("min1", 1),
+	("min2", 1),
+	("minimessage", 1),
+	("mob", 1),
+	("mode", 12),
+	("model", 1),
+	("modification_mode", 2),
+	("movement", 1),
+	("movement_type", 1),
+	("multiline", 1),
+	("multiplier", 2),
+	("name", 13),
+	("name_or_uuid", 26),
+	("names", 1),
+	("names_or_uuids", 3),
+	("namespace", 2),
+	("natural_equipment", 1),
+	("nectar", 1),
+	("new_block", 1),
+	("no_physics", 1),
+	("normalize", 1),
+	("normalized", 2),
+	("number", 29),
+	("obfuscated", 1),
+	("octaves", 2),
+	("off_hand", 1),
+	("offset", 1),
+	("old_block", 1),
+	("old_size", 1),
+	("on_back", 1),
+	("only_solid", 2),
+	("opacity", 1),
+	("operand1", 1),
+	("operand2", 1),
+	("operation", 2),
+	("operator", 1),
+	("origin", 2),
+	("other_map", 1),
+	("overwrite", 3),
+	("page", 3),
+	("parrot_type", 1),
+	("parsing", 2),
+	("particle", 22),
+	("particle_mode", 3),
+	("paste_pos", 1),
+	("pattern", 4),
+	("pattern_color", 1),
+	("perpendicular", 1),
+	("perpendicular_to_plane", 1),
+	("persistence", 1),
+	("phase", 1),
+	("pierce", 1),
+	("pitch", 7),
+	("placeable", 1),
+	("points", 5),
+	("pos_1", 5),
+	("pos_2", 5),
+	("pose", 2),
+	("position", 3),
+	("postfix", 1),
+	("potion", 6),
+	("potion_effects", 1),
+	("potions", 6),
+	("power", 12),
+	("power_level", 1),
+	("power_mode", 1),
+	("powered", 1),
+	("precision", 4),
+	("prefix", 1),
+	("pretty_print", 1),
+	("price_multiplifier", 1),
+	("privilege", 1),
+	("process_name", 1),
+	("profession", 1),
+	("progress", 1),
+	("projectile", 4),
+	("property", 1),
+	("pumpkin", 1),
+	("pvp", 1),
+	("rabbit_type", 1),
+	("radius", 9),
+	("radix", 2),
+	("rain_level", 1),
+	("range", 2),
+	("range_mode", 3),
+	("ray", 1),
+	("ray_collision_mode", 1),
+	("ray_size", 2),
+	("rearing", 1),
+	("reason", 1),
+	("receive_type", 2),
+	("recipe_index", 1),
+	("red", 2),
+	("regex", 2),
+	("regular_expressions", 1),
+	("remainder_mode", 1),
+	("remove", 1),
+	("remove_mode", 1),
+	("removed_value", 2),
+	("repeat", 1),
+	("replace", 3),
+	("replacement", 2),
+	("request_type", 1),
+	("responsive", 2),
+	("result", 1),
+	("return_type", 1),
+	("return_value", 2),
+	("right_hand", 1),
+	("riptiding", 1),
+	("roll", 1),
+	("rolling", 2),
+	("root", 1),
+	("rotate_location", 2),
+	("rotating_vector", 1),
+	("rotation", 3),
+	("rotation_mode", 1),
+	("rotations", 1),
+	("round_type", 4),
+	("row_major_matrix", 1),
+	("sad_ticks", 1),
+	("saturation", 3),
+	("scale_vector", 1),
+	("scope", 2),
+	("score", 3),
+	("screams", 1),
+	("search_mode", 2),
+	("second_corner", 1),
+	("seed", 3),
+	("selection_size", 2),
+	("selection_type", 2),
+	("separator", 1),
+	("server_type", 2),
+	("setting_mode", 2),
+	("shadow_opacity_percentage", 1),
+	("shadow_radius", 1),
+	("shape", 1),
+	("sheared", 1),
+	("shift", 1),
+	("should_expire", 1),
+	("show_arms", 1),
+	("show_bottom", 1),
+	("show_icon", 3),
+	("shrieking", 1),
+	("shrinking_speed", 1),
+	("side", 4),
+	("sign_line", 1),
+	("sign_text_color", 1),
+	("silenced", 1),
+	("silent", 1),
+	("sitting", 1),
+	("size", 8),
+	("sky_effect", 1),
+	("sky_light_level", 1),
+	("sleep", 1),
+	("slot", 10),
+	("slots", 2),
+	("small", 1),
+	("sort_mode", 2),
+	("sort_order", 1),
+	("sort_type", 1),
+	("sound", 15),
+	("sounds", 3),
+	("source", 5),
+	("source_slot", 1),
+	("spacing", 1),
+	("spawn_location", 3),
+	("spawn_point", 1),
+	("special_price", 1),
+	("speed", 5),
+	("stage", 1),
+	("start", 8),
+	("start_angle", 3),
+	("start_index", 1),
+	("start_location", 2),
+	("state", 1),
+	("stay", 1),
+	("step", 1),
+	("stinger", 1),
+	("stop", 1),
+	("strikethrough", 1),
+	("strip_type", 1),
+	("style", 1),
+	("subtext", 1),
+	("subtitle", 1),
+	("tag", 2),
+	("tag_name", 8),
+	("tag_value", 6),
+	("target", 4),
+	("target_mode", 1),
+	("target_pos", 1),
+	("template", 2),
+	("text", 27),
+	("text_alignment", 1),
+	("text_opacity", 1),
+	("texts", 1),
+	("thunder_level", 1),
+	("tick", 1),
+	("tick_rate", 1),
+	("ticking_locked", 2),
+	("ticks", 8),
+	("time", 6),
+	("time_unit", 1),
+	("times", 1),
+	("title", 3),
+	("tnt_power", 1),
+	("to", 1),
+	("to_start", 1),
+	("to_stop", 1),
+	("tool", 1),
+	("tracked", 1),
+	("trail", 3),
+	("translation_vector", 1),
+	("tree_type", 1),
+	("truncated", 1),
+	("type", 11),
+	("unbreakable", 1),
+	("underlined", 1),
+	("unit_of_measurement", 2),
+	("unix_lines", 1),
+	("update_blocks", 1),
+	("url", 2),
+	("uses", 1),
+	("uuid", 1),
+	("value", 59),
+	("value_type", 2),
+	("value_variable", 5),
+	("values", 18),
+	("variable", 308),
+	("variable_for_hit_block_face", 1),
+	("variable_for_hit_block_location", 1),
+	("variable_for_hit_entity_uuid", 1),
+	("variable_for_hit_location", 1),
+	("variable_type", 2),
+	("variant", 4),
+	("variation", 1),
+	("vector", 15),
+	("vector_1", 5),
+	("vector_2", 5),
+	("vector_component", 2),
+	("vectors", 2),
+	("vehicle", 1),
+	("velocity", 4),
+	("vertical", 1),
+	("view_range", 1),
+	("villager_experience", 1),
+	("visibility", 1),
+	("visible", 1),
+	("visible_to_self", 3),
+	("visual_fire", 2),
+	("volume", 1),
+	("wait_type", 1),
+	("warning", 1),
+	("warning_level", 1),
+	("waxed", 1),
+	("wearing", 1),
+	("weather_duration", 1),
+	("weather_type", 2),
+	("width", 3),
+	("wolf_type", 1),
+	("world_id", 1),
+	("x", 6),
+	("x_rotation", 1),
+	("y", 6),
+	("y_rotation", 1),
+	("yaw", 8),
+	("z", 5),
+	("z_rotation", 1),
+	("zone_id", 1),
+];
+
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+#[repr(u16)]
+pub enum SoundIdWants10Bits {
+	#[serde(rename = "ambient.basalt_deltas.additions")]
+	AmbientBasaltDeltasAdditions,
+	#[serde(rename = "ambient.basalt_deltas.loop")]
+	AmbientBasaltDeltasLoop,
+	#[serde(rename = "ambient.basalt_deltas.mood")]
+	AmbientBasaltDeltasMood,
+	#[serde(rename = "ambient.cave")]
+	AmbientCave,
+	#[serde(rename = "ambient.crimson_forest.additions")]
+	AmbientCrimsonForestAdditions,
+	#[serde(rename = "ambient.crimson_forest.loop")]
+	AmbientCrimsonForestLoop,
+	#[serde(rename = "ambient.crimson_forest.mood")]
+	AmbientCrimsonForestMood,
+	#[serde(rename = "ambient.nether_wastes.additions")]
+	AmbientNetherWastesAdditions,
+	#[serde(rename = "ambient.nether_wastes.loop")]
+	AmbientNetherWastesLoop,
+	#[serde(rename = "ambient.nether_wastes.mood")]
+	AmbientNetherWastesMood,
+	#[serde(rename = "ambient.soul_sand_valley.additions")]
+	AmbientSoulSandValleyAdditions,
+	#[serde(rename = "ambient.soul_sand_valley.loop")]
+	AmbientSoulSandValleyLoop,
+	#[serde(rename = "ambient.soul_sand_valley.mood")]
+	AmbientSoulSandValleyMood,
+	#[serde(rename = "ambient.underwater.enter")]
+	AmbientUnderwaterEnter,
+	#[serde(rename = "ambient.underwater.exit")]
+	AmbientUnderwaterExit,
+	#[serde(rename = "ambient.underwater.loop")]
+	AmbientUnderwaterLoop,
+	#[serde(rename = "ambient.warped_forest.additions")]
+	AmbientWarpedForestAdditions,
+	#[serde(rename = "ambient.warped_forest.loop")]
+	AmbientWarpedForestLoop,
+	#[serde(rename = "ambient.warped_forest.mood")]
+	AmbientWarpedForestMood,
+	#[serde(rename = "block.amethyst_block.break")]
+	BlockAmethystBlockBreak,
+	#[serde(rename = "block.amethyst_block.chime")]
+	BlockAmethystBlockChime,
+	#[serde(rename = "block.amethyst_block.hit")]
+	BlockAmethystBlockHit,
+	#[serde(rename = "block.amethyst_block.place")]
+	BlockAmethystBlockPlace,
+	#[serde(rename = "block.amethyst_block.step")]
+	BlockAmethystBlockStep,
+	#[serde(rename = "block.anvil.break")]
+	BlockAnvilBreak,
+	#[serde(rename = "block.anvil.destroy")]
+	BlockAnvilDestroy,
+	#[serde(rename = "block.anvil.fall")]
+	BlockAnvilFall,
+	#[serde(rename = "block.anvil.hit")]
+	BlockAnvilHit,
+	#[serde(rename = "block.anvil.land")]
+	BlockAnvilLand,
+	#[serde(rename = "block.anvil.place")]
+	BlockAnvilPlace,
+	#[serde(rename = "block.anvil.step")]
+	BlockAnvilStep,
+	#[serde(rename = "block.anvil.use")]
+	BlockAnvilUse,
+	#[serde(rename = "block.barrel.close")]
+	BlockBarrelClose,
+	#[serde(rename = "block.barrel.open")]
+	BlockBarrelOpen,
+	#[serde(rename = "block.beacon.activate")]
+	BlockBeaconActivate,
+	#[serde(rename = "block.beacon.ambient")]
+	BlockBeaconAmbient,
+	#[serde(rename = "block.beacon.deactivate")]
+	BlockBeaconDeactivate,
+	#[serde(rename = "block.beacon.power_select")]
+	BlockBeaconPowerSelect,
+	#[serde(rename = "block.bell.resonate")]
+	BlockBellResonate,
+	#[serde(rename = "block.bell.use")]
+	BlockBellUse,
+	#[serde(rename = "block.brewing_stand.brew")]
+	BlockBrewingStandBrew,
+	#[serde(rename = "block.bubble_column.bubble_pop")]
+	BlockBubbleColumnBubblePop,
+	#[serde(rename = "block.bubble_column.upwards_ambient")]
+	BlockBubbleColumnUpwardsAmbient,
+	#[serde(rename = "block.bubble_column.whirlpool_ambient")]
+	BlockBubbleColumnWhirlpoolAmbient,
+	#[serde(rename = "block.campfire.crackle")]
+	BlockCampfireCrackle,
+	#[serde(rename = "block.chest.close")]
+	BlockChestClose,
+	#[serde(rename = "block.chest.locked")]
+	BlockChestLocked,
+	#[serde(rename = "block.chest.open")]
+	BlockChestOpen,
+	#[serde(rename = "block.chorus_flower.death")]
+	BlockChorusFlowerDeath,
+	#[serde(rename = "block.chorus_flower.grow")]
+	BlockChorusFlowerGrow,
+	#[serde(rename = "block.comparator.click")]
+	BlockComparatorClick,
+	#[serde(rename = "block.composter.empty")]
+	BlockComposterEmpty,
+	#[serde(rename = "block.composter.fill")]
+	BlockComposterFill,
+	#[serde(rename = "block.composter.fill_success")]
+	BlockComposterFillSuccess,
+	#[serde(rename = "block.composter.ready")]
+	BlockComposterReady,
+	#[serde(rename = "block.conduit.activate")]
+	BlockConduitActivate,
+	#[serde(rename = "block.conduit.ambient")]
+	BlockConduitAmbient,
+	#[serde(rename = "block.conduit.attack.target")]
+	BlockConduitAttackTarget,
+	#[serde(rename = "block.conduit.deactivate")]
+	BlockConduitDeactivate,
+	#[serde(rename = "block.dispenser.dispense")]
+	BlockDispenserDispense,
+	#[serde(rename = "block.dispenser.fail")]
+	BlockDispenserFail,
+	#[serde(rename = "block.dispenser.launch")]
+	BlockDispenserLaunch,
+	#[serde(rename = "block.enchantment_table.use")]
+	BlockEnchantmentTableUse,
+	#[serde(rename = "block.end_gateway.spawn")]
+	BlockEndGatewaySpawn,
+	#[serde(rename = "block.end_portal.spawn")]
+	BlockEndPortalSpawn,
+	#[serde(rename = "block.end_portal_frame.fill")]
+	BlockEndPortalFrameFill,
+	#[serde(rename = "block.ender_chest.close")]
+	BlockEnderChestClose,
+	#[serde(rename = "block.ender_chest.open")]
+	BlockEnderChestOpen,
+	#[serde(rename = "block.fence_gate.close")]
+	BlockFenceGateClose,
+	#[serde(rename = "block.fence_gate.open")]
+	BlockFenceGateOpen,
+	#[serde(rename = "block.fire.ambient")]
+	BlockFireAmbient,
+	#[serde(rename = "block.fire.extinguish")]
+	BlockFireExtinguish,
+	#[serde(rename = "block.furnace.fire_crackle")]
+	BlockFurnaceFireCrackle,
+	#[serde(rename = "block.glass.break")]
+	BlockGlassBreak,
+	#[serde(rename = "block.glass.place")]
+	BlockGlassPlace,
+	#[serde(rename = "block.grass.break")]
+	BlockGrassBreak,
+	#[serde(rename = "block.grass.place")]
+	BlockGrassPlace,
+	#[serde(rename = "block.grass.step")]
+	BlockGrassStep,
+	#[serde(rename = "block.gravel.break")]
+	BlockGravelBreak,
+	#[serde(rename = "block.gravel.step")]
+	BlockGravelStep,
+	#[serde(rename = "block.grindstone.use")]
+	BlockGrindstoneUse,
+	#[serde(rename = "block.honey_block.slide")]
+	BlockHoneyBlockSlide,
+	#[serde(rename = "block.iron_door.close")]
+	BlockIronDoorClose,
+	#[serde(rename = "block.iron_door.open")]
+	BlockIronDoorOpen,
+	#[serde(rename = "block.iron_trapdoor.close")]
+	BlockIronTrapdoorClose,
+	#[serde(rename = "block.iron_trapdoor.open")]
+	BlockIronTrapdoorOpen,
+	#[serde(rename = "block.lava.ambient")]
+	BlockLavaAmbient,
+	#[serde(rename = "block.lava.extinguish")]
+	BlockLavaExtinguish,
+	#[serde(rename = "block.lava.pop")]
+	BlockLavaPop,
+	#[serde(rename = "block.lever.click")]
+	BlockLeverClick,
+	#[serde(rename = "block.note_block.banjo")]
+	BlockNoteBlockBanjo,
+	#[serde(rename = "block.note_block.basedrum")]
+	BlockNoteBlockBasedrum,
+	#[serde(rename = "block.note_block.bass")]
+	BlockNoteBlockBass,
+	#[serde(rename = "block.note_block.bell")]
+	BlockNoteBlockBell,
+	#[serde(rename = "block.note_block.bit")]
+	BlockNoteBlockBit,
+	#[serde(rename = "block.note_block.chime")]
+	BlockNoteBlockChime,
+	#[serde(rename = "block.note_block.cow_bell")]
+	BlockNoteBlockCowBell,
+	#[serde(rename = "block.note_block.didgeridoo")]
+	BlockNoteBlockDidgeridoo,
+	#[serde(rename = "block.note_block.flute")]
+	BlockNoteBlockFlute,
+	#[serde(rename = "block.note_block.guitar")]
+	BlockNoteBlockGuitar,
+	#[serde(rename = "block.note_block.harp")]
+	BlockNoteBlockHarp,
+	#[serde(rename = "block.note_block.hat")]
+	BlockNoteBlockHat,
+	#[serde(rename = "block.note_block.iron_xylophone")]
+	BlockNoteBlockIronXylophone,
+	#[serde(rename = "block.note_block.pling")]
+	BlockNoteBlockPling,
+	#[serde(rename = "block.note_block.snare")]
+	BlockNoteBlockSnare,
+	#[serde(rename = "block.note_block.xylophone")]
+	BlockNoteBlockXylophone,
+	#[serde(rename = "block.piston.contract")]
+	BlockPistonContract,
+	#[serde(rename = "block.piston.extend")]
+	BlockPistonExtend,
+	#[serde(rename = "block.portal.ambient")]
+	BlockPortalAmbient,
+	#[serde(rename = "block.portal.travel")]
+	BlockPortalTravel,
+	#[serde(rename = "block.portal.trigger")]
+	BlockPortalTrigger,
+	#[serde(rename = "block.pressure_plate.click_off")]
+	BlockPressurePlateClickOff,
+	#[serde(rename = "block.pressure_plate.click_on")]
+	BlockPressurePlateClickOn,
+	#[serde(rename = "block.respawn_anchor.charge")]
+	BlockRespawnAnchorCharge,
+	#[serde(rename = "block.respawn_anchor.deplete")]
+	BlockRespawnAnchorDeplete,
+	#[serde(rename = "block.respawn_anchor.set_spawn")]
+	BlockRespawnAnchorSetSpawn,
+	#[serde(rename = "block.sand.break")]
+	BlockSandBreak,
+	#[serde(rename = "block.sand.step")]
+	BlockSandStep,
+	#[serde(rename = "block.sculk_sensor.clicking")]
+	BlockSculkSensorClicking,
+	#[serde(rename = "block.shulker_box.close")]
+	BlockShulkerBoxClose,
+	#[serde(rename = "block.shulker_box.open")]
+	BlockShulkerBoxOpen,
+	#[serde(rename = "block.smithing_table.use")]
+	BlockSmithingTableUse,
+	#[serde(rename = "block.snow.break")]
+	BlockSnowBreak,
+	#[serde(rename = "block.snow.step")]
+	BlockSnowStep,
+	#[serde(rename = "block.stone.break")]
+	BlockStoneBreak,
+	#[serde(rename = "block.stone.hit")]
+	BlockStoneHit,
+	#[serde(rename = "block.stone.place")]
+	BlockStonePlace,
+	#[serde(rename = "block.stone.step")]
+	BlockStoneStep,
+	#[serde(rename = "block.stone_button.click_off")]
+	BlockStoneButtonClickOff,
+	#[serde(rename = "block.stone_button.click_on")]
+	BlockStoneButtonClickOn,
+	#[serde(rename = "block.sweet_berry_bush.pick_berries")]
+	BlockSweetBerryBushPickBerries,
+	#[serde(rename = "block.water.ambient")]
+	BlockWaterAmbient,
+	#[serde(rename = "block.wood.break")]
+	BlockWoodBreak,
+	#[serde(rename = "block.wood.hit")]
+	BlockWoodHit,
+	#[serde(rename = "block.wood.place")]
+	BlockWoodPlace,
+	#[serde(rename = "block.wood.step")]
+	BlockWoodStep,
+	#[serde(rename = "block.wooden_button.click_off")]
+	BlockWoodenButtonClickOff,
+	#[serde(rename = "block.wooden_button.click_on")]
+	BlockWoodenButtonClickOn,
+	#[serde(rename = "block.wooden_door.close")]
+	BlockWoodenDoorClose,
+	#[serde(rename = "block.wooden_door.open")]
+	BlockWoodenDoorOpen,
+	#[serde(rename = "block.wooden_trapdoor.close")]
+	BlockWoodenTrapdoorClose,
+	#[serde(rename = "block.wooden_trapdoor.open")]
+	BlockWoodenTrapdoorOpen,
+	#[serde(rename = "block.wool.break")]
+	BlockWoolBreak,
+	#[serde(rename = "block.wool.place")]
+	BlockWoolPlace,
+	#[serde(rename = "block.wool.step")]
+	BlockWoolStep,
+	#[serde(rename = "entity.allay.ambient_with_item")]
+	EntityAllayAmbientWithItem,
+	#[serde(rename = "entity.allay.ambient_without_item")]
+	EntityAllayAmbientWithoutItem,
+	#[serde(rename = "entity.allay.death")]
+	EntityAllayDeath,
+	#[serde(rename = "entity.allay.hurt")]
+	EntityAllayHurt,
+	#[serde(rename = "entity.armor_stand.break")]
+	EntityArmorStandBreak,
+	#[serde(rename = "entity.armor_stand.fall")]
+	EntityArmorStandFall,
+	#[serde(rename = "entity.armor_stand.hit")]
+	EntityArmorStandHit,
+	#[serde(rename = "entity.armor_stand.place")]
+	EntityArmorStandPlace,
+	#[serde(rename = "entity.arrow.hit")]
+	EntityArrowHit,
+	#[serde(rename = "entity.arrow.hit_player")]
+	EntityArrowHitPlayer,
+	#[serde(rename = "entity.arrow.shoot")]
+	EntityArrowShoot,
+	#[serde(rename = "entity.axolotl.attack")]
+	EntityAxolotlAttack,
+	#[serde(rename = "entity.axolotl.death")]
+	EntityAxolotlDeath,
+	#[serde(rename = "entity.axolotl.hurt")]
+	EntityAxolotlHurt,
+	#[serde(rename = "entity.bat.ambient")]
+	EntityBatAmbient,
+	#[serde(rename = "entity.bat.death")]
+	EntityBatDeath,
+	#[serde(rename = "entity.bat.hurt")]
+	EntityBatHurt,
+	#[serde(rename = "entity.bat.takeoff")]
+	EntityBatTakeoff,
+	#[serde(rename = "entity.bee.death")]
+	EntityBeeDeath,
+	#[serde(rename = "entity.bee.hurt")]
+	EntityBeeHurt,
+	#[serde(rename = "entity.bee.loop")]
+	EntityBeeLoop,
+	#[serde(rename = "entity.bee.sting")]
+	EntityBeeSting,
+	#[serde(rename = "entity.blaze.ambient")]
+	EntityBlazeAmbient,
+	#[serde(rename = "entity.blaze.burn")]
+	EntityBlazeBurn,
+	#[serde(rename = "entity.blaze.death")]
+	EntityBlazeDeath,
+	#[serde(rename = "entity.blaze.hurt")]
+	EntityBlazeHurt,
+	#[serde(rename = "entity.blaze.shoot")]
+	EntityBlazeShoot,
+	#[serde(rename = "entity.boat.paddle_water")]
+	EntityBoatPaddleWater,
+	#[serde(rename = "entity.cat.ambient")]
+	EntityCatAmbient,
+	#[serde(rename = "entity.cat.death")]
+	EntityCatDeath,
+	#[serde(rename = "entity.cat.hiss")]
+	EntityCatHiss,
+	#[serde(rename = "entity.cat.hurt")]
+	EntityCatHurt,
+	#[serde(rename = "entity.cat.purr")]
+	EntityCatPurr,
+	#[serde(rename = "entity.cat.purreow")]
+	EntityCatPurreow,
+	#[serde(rename = "entity.chicken.ambient")]
+	EntityChickenAmbient,
+	#[serde(rename = "entity.chicken.death")]
+	EntityChickenDeath,
+	#[serde(rename = "entity.chicken.egg")]
+	EntityChickenEgg,
+	#[serde(rename = "entity.chicken.hurt")]
+	EntityChickenHurt,
+	#[serde(rename = "entity.chicken.step")]
+	EntityChickenStep,
+	#[serde(rename = "entity.cow.ambient")]
+	EntityCowAmbient,
+	#[serde(rename = "entity.cow.death")]
+	EntityCowDeath,
+	#[serde(rename = "entity.cow.hurt")]
+	EntityCowHurt,
+	#[serde(rename = "entity.cow.milk")]
+	EntityCowMilk,
+	#[serde(rename = "entity.cow.step")]
+	EntityCowStep,
+	#[serde(rename = "entity.creeper.death")]
+	EntityCreeperDeath,
+	#[serde(rename = "entity.creeper.hurt")]
+	EntityCreeperHurt,
+	#[serde(rename = "entity.creeper.primed")]
+	EntityCreeperPrimed,
+	#[serde(rename = "entity.dolphin.ambient")]
+	EntityDolphinAmbient,
+	#[serde(rename = "entity.dolphin.death")]
+	EntityDolphinDeath,
+	#[serde(rename = "entity.dolphin.hurt")]
+	EntityDolphinHurt,
+	#[serde(rename = "entity.donkey.ambient")]
+	EntityDonkeyAmbient,
+	#[serde(rename = "entity.donkey.death")]
+	EntityDonkeyDeath,
+	#[serde(rename = "entity.donkey.hurt")]
+	EntityDonkeyHurt,
+	#[serde(rename = "entity.dragon_fireball.explode")]
+	EntityDragonFireballExplode,
+	#[serde(rename = "entity.drowned.ambient")]
+	EntityDrownedAmbient,
+	#[serde(rename = "entity.drowned.death")]
+	EntityDrownedDeath,
+	#[serde(rename = "entity.drowned.hurt")]
+	EntityDrownedHurt,
+	#[serde(rename = "entity.egg.throw")]
+	EntityEggThrow,
+	#[serde(rename = "entity.elder_guardian.curse")]
+	EntityElderGuardianCurse,
+	#[serde(rename = "entity.ender_dragon.ambient")]
+	EntityEnderDragonAmbient,
+	#[serde(rename = "entity.ender_dragon.death")]
+	EntityEnderDragonDeath,
+	#[serde(rename = "entity.ender_dragon.flap")]
+	EntityEnderDragonFlap,
+	#[serde(rename = "entity.ender_dragon.growl")]
+	EntityEnderDragonGrowl,
+	#[serde(rename = "entity.ender_dragon.hurt")]
+	EntityEnderDragonHurt,
+	#[serde(rename = "entity.ender_eye.death")]
+	EntityEnderEyeDeath,
+	#[serde(rename = "entity.ender_eye.launch")]
+	EntityEnderEyeLaunch,
+	#[serde(rename = "entity.ender_pearl.throw")]
+	EntityEnderPearlThrow,
+	#[serde(rename = "entity.enderman.ambient")]
+	EntityEndermanAmbient,
+	#[serde(rename = "entity.enderman.death")]
+	EntityEndermanDeath,
+	#[serde(rename = "entity.enderman.hurt")]
+	EntityEndermanHurt,
+	#[serde(rename = "entity.enderman.scream")]
+	EntityEndermanScream,
+	#[serde(rename = "entity.enderman.stare")]
+	EntityEndermanStare,
+	#[serde(rename = "entity.enderman.teleport")]
+	EntityEndermanTeleport,
+	#[serde(rename = "entity.evoker.ambient")]
+	EntityEvokerAmbient,
+	#[serde(rename = "entity.evoker.cast_spell")]
+	EntityEvokerCastSpell,
+	#[serde(rename = "entity.evoker.death")]
+	EntityEvokerDeath,
+	#[serde(rename = "entity.evoker.hurt")]
+	EntityEvokerHurt,
+	#[serde(rename = "entity.evoker.prepare_summon")]
+	EntityEvokerPrepareSummon,
+	#[serde(rename = "entity.experience_bottle.throw")]
+	EntityExperienceBottleThrow,
+	#[serde(rename = "entity.experience_orb.pickup")]
+	EntityExperienceOrbPickup,
+	#[serde(rename = "entity.firework_rocket.blast")]
+	EntityFireworkRocketBlast,
+	#[serde(rename = "entity.firework_rocket.large_blast")]
+	EntityFireworkRocketLargeBlast,
+	#[serde(rename = "entity.firework_rocket.launch")]
+	EntityFireworkRocketLaunch,
+	#[serde(rename = "entity.firework_rocket.twinkle")]
+	EntityFireworkRocketTwinkle,
+	#[serde(rename = "entity.fishing_bobber.splash")]
+	EntityFishingBobberSplash,
+	#[serde(rename = "entity.fishing_bobber.throw")]
+	EntityFishingBobberThrow,
+	#[serde(rename = "entity.fox.ambient")]
+	EntityFoxAmbient,
+	#[serde(rename = "entity.fox.death")]
+	EntityFoxDeath,
+	#[serde(rename = "entity.fox.hurt")]
+	EntityFoxHurt,
+	#[serde(rename = "entity.fox.screech")]
+	EntityFoxScreech,
+	#[serde(rename = "entity.generic.big_fall")]
+	EntityGenericBigFall,
+	#[serde(rename = "entity.generic.burn")]
+	EntityGenericBurn,
+	#[serde(rename = "entity.generic.death")]
+	EntityGenericDeath,
+	#[serde(rename = "entity.generic.drink")]
+	EntityGenericDrink,
+	#[serde(rename = "entity.generic.eat")]
+	EntityGenericEat,
+	#[serde(rename = "entity.generic.explode")]
+	EntityGenericExplode,
+	#[serde(rename = "entity.generic.extinguish_fire")]
+	EntityGenericExtinguishFire,
+	#[serde(rename = "entity.generic.hurt")]
+	EntityGenericHurt,
+	#[serde(rename = "entity.generic.small_fall")]
+	EntityGenericSmallFall,
+	#[serde(rename = "entity.generic.splash")]
+	EntityGenericSplash,
+	#[serde(rename = "entity.generic.swim")]
+	EntityGenericSwim,
+	#[serde(rename = "entity.ghast.ambient")]
+	EntityGhastAmbient,
+	#[serde(rename = "entity.ghast.death")]
+	EntityGhastDeath,
+	#[serde(rename = "entity.ghast.hurt")]
+	EntityGhastHurt,
+	#[serde(rename = "entity.ghast.scream")]
+	EntityGhastScream,
+	#[serde(rename = "entity.ghast.shoot")]
+	EntityGhastShoot,
+	#[serde(rename = "entity.ghast.warn")]
+	EntityGhastWarn,
+	#[serde(rename = "entity.goat.ambient")]
+	EntityGoatAmbient,
+	#[serde(rename = "entity.goat.death")]
+	EntityGoatDeath,
+	#[serde(rename = "entity.goat.hurt")]
+	EntityGoatHurt,
+	#[serde(rename = "entity.goat.ram_impact")]
+	EntityGoatRamImpact,
+	#[serde(rename = "entity.guardian.ambient")]
+	EntityGuardianAmbient,
+	#[serde(rename = "entity.guardian.attack")]
+	EntityGuardianAttack,
+	#[serde(rename = "entity.guardian.death")]
+	EntityGuardianDeath,
+	#[serde(rename = "entity.guardian.hurt")]
+	EntityGuardianHurt,
+	#[serde(rename = "entity.hoglin.ambient")]
+	EntityHoglinAmbient,
+	#[serde(rename = "entity.hoglin.angry")]
+	EntityHoglinAngry,
+	#[serde(rename = "entity.hoglin.death")]
+	EntityHoglinDeath,
+	#[serde(rename = "entity.hoglin.hurt")]
+	EntityHoglinHurt,
+	#[serde(rename = "entity.horse.ambient")]
+	EntityHorseAmbient,
+	#[serde(rename = "entity.horse.angry")]
+	EntityHorseAngry,
+	#[serde(rename = "entity.horse.death")]
+	EntityHorseDeath,
+	#[serde(rename = "entity.horse.gallop")]
+	EntityHorseGallop,
+	#[serde(rename = "entity.horse.hurt")]
+	EntityHorseHurt,
+	#[serde(rename = "entity.horse.jump")]
+	EntityHorseJump,
+	#[serde(rename = "entity.horse.saddle")]
+	EntityHorseSaddle,
+	#[serde(rename = "entity.husk.ambient")]
+	EntityHuskAmbient,
+	#[serde(rename = "entity.husk.death")]
+	EntityHuskDeath,
+	#[serde(rename = "entity.husk.hurt")]
+	EntityHuskHurt,
+	#[serde(rename = "entity.illusioner.cast_spell")]
+	EntityIllusionerCastSpell,
+	#[serde(rename = "entity.iron_golem.attack")]
+	EntityIronGolemAttack,
+	#[serde(rename = "entity.iron_golem.damage")]
+	EntityIronGolemDamage,
+	#[serde(rename = "entity.iron_golem.death")]
+	EntityIronGolemDeath,
+	#[serde(rename = "entity.iron_golem.hurt")]
+	EntityIronGolemHurt,
+	#[serde(rename = "entity.iron_golem.repair")]
+	EntityIronGolemRepair,
+	#[serde(rename = "entity.item.break")]
+	EntityItemBreak,
+	#[serde(rename = "entity.item.pickup")]
+	EntityItemPickup,
+	#[serde(rename = "entity.item_frame.add_item")]
+	EntityItemFrameAddItem,
+	#[serde(rename = "entity.item_frame.break")]
+	EntityItemFrameBreak,
+	#[serde(rename = "entity.item_frame.remove_item")]
+	EntityItemFrameRemoveItem,
+	#[serde(rename = "entity.item_frame.rotate_item")]
+	EntityItemFrameRotateItem,
+	#[serde(rename = "entity.lightning_bolt.impact")]
+	EntityLightningBoltImpact,
+	#[serde(rename = "entity.lightning_bolt.thunder")]
+	EntityLightningBoltThunder,
+	#[serde(rename = "entity.llama.ambient")]
+	EntityLlamaAmbient,
+	#[serde(rename = "entity.llama.death")]
+	EntityLlamaDeath,
+	#[serde(rename = "entity.llama.hurt")]
+	EntityLlamaHurt,
+	#[serde(rename = "entity.llama.spit")]
+	EntityLlamaSpit,
+	#[serde(rename = "entity.magma_cube.death")]
+	EntityMagmaCubeDeath,
+	#[serde(rename = "entity.magma_cube.hurt")]
+	EntityMagmaCubeHurt,
+	#[serde(rename = "entity.magma_cube.jump")]
+	EntityMagmaCubeJump,
+	#[serde(rename = "entity.minecart.riding")]
+	EntityMinecartRiding,
+	#[serde(rename = "entity.mooshroom.convert")]
+	EntityMooshroomConvert,
+	#[serde(rename = "entity.parrot.ambient")]
+	EntityParrotAmbient,
+	#[serde(rename = "entity.parrot.death")]
+	EntityParrotDeath,
+	#[serde(rename = "entity.parrot.fly")]
+	EntityParrotFly,
+	#[serde(rename = "entity.parrot.hurt")]
+	EntityParrotHurt,
+	#[serde(rename = "entity.phantom.ambient")]
+	EntityPhantomAmbient,
+	#[serde(rename = "entity.phantom.bite")]
+	EntityPhantomBite,
+	#[serde(rename = "entity.phantom.death")]
+	EntityPhantomDeath,
+	#[serde(rename = "entity.phantom.flap")]
+	EntityPhantomFlap,
+	#[serde(rename = "entity.phantom.hurt")]
+	EntityPhantomHurt,
+	#[serde(rename = "entity.phantom.swoop")]
+	EntityPhantomSwoop,
+	#[serde(rename = "entity.pig.ambient")]
+	EntityPigAmbient,
+	#[serde(rename = "entity.pig.death")]
+	EntityPigDeath,
+	#[serde(rename = "entity.pig.hurt")]
+	EntityPigHurt,
+	#[serde(rename = "entity.pig.saddle")]
+	EntityPigSaddle,
+	#[serde(rename = "entity.piglin.admiring_item")]
+	EntityPiglinAdmiringItem,
+	#[serde(rename = "entity.piglin.ambient")]
+	EntityPiglinAmbient,
+	#[serde(rename = "entity.piglin.angry")]
+	EntityPiglinAngry,
+	#[serde(rename = "entity.piglin.celebrate")]
+	EntityPiglinCelebrate,
+	#[serde(rename = "entity.piglin.death")]
+	EntityPiglinDeath,
+	#[serde(rename = "entity.piglin.hurt")]
+	EntityPiglinHurt,
+	#[serde(rename = "entity.pillager.ambient")]
+	EntityPillagerAmbient,
+	#[serde(rename = "entity.pillager.celebrate")]
+	EntityPillagerCelebrate,
+	#[serde(rename = "entity.pillager.death")]
+	EntityPillagerDeath,
+	#[serde(rename = "entity.pillager.hurt")]
+	EntityPillagerHurt,
+	#[serde(rename = "entity.player.attack.crit")]
+	EntityPlayerAttackCrit,
+	#[serde(rename = "entity.player.attack.knockback")]
+	EntityPlayerAttackKnockback,
+	#[serde(rename = "entity.player.attack.nodamage")]
+	EntityPlayerAttackNodamage,
+	#[serde(rename = "entity.player.attack.strong")]
+	EntityPlayerAttackStrong,
+	#[serde(rename = "entity.player.attack.sweep")]
+	EntityPlayerAttackSweep,
+	#[serde(rename = "entity.player.attack.weak")]
+	EntityPlayerAttackWeak,
+	#[serde(rename = "entity.player.breath")]
+	EntityPlayerBreath,
+	#[serde(rename = "entity.player.burp")]
+	EntityPlayerBurp,
+	#[serde(rename = "entity.player.death")]
+	EntityPlayerDeath,
+	#[serde(rename = "entity.player.hurt")]
+	EntityPlayerHurt,
+	#[serde(rename = "entity.player.hurt_drown")]
+	EntityPlayerHurtDrown,
+	#[serde(rename = "entity.player.hurt_freeze")]
+	EntityPlayerHurtFreeze,
+	#[serde(rename = "entity.player.hurt_on_fire")]
+	EntityPlayerHurtOnFire,
+	#[serde(rename = "entity.player.hurt_sweet_berry_bush")]
+	EntityPlayerHurtSweetBerryBush,
+	#[serde(rename = "entity.player.levelup")]
+	EntityPlayerLevelup,
+	#[serde(rename = "entity.player.small_fall")]
+	EntityPlayerSmallFall,
+	#[serde(rename = "entity.player.splash")]
+	EntityPlayerSplash,
+	#[serde(rename = "entity.player.swim")]
+	EntityPlayerSwim,
+	#[serde(rename = "entity.player.teleport")]
+	EntityPlayerTeleport,
+	#[serde(rename = "entity.polar_bear.ambient")]
+	EntityPolarBearAmbient,
+	#[serde(rename = "entity.polar_bear.death")]
+	EntityPolarBearDeath,
+	#[serde(rename = "entity.polar_bear.hurt")]
+	EntityPolarBearHurt,
+	#[serde(rename = "entity.polar_bear.warning")]
+	EntityPolarBearWarning,
+	#[serde(rename = "entity.puffer_fish.blow_out")]
+	EntityPufferFishBlowOut,
+	#[serde(rename = "entity.puffer_fish.blow_up")]
+	EntityPufferFishBlowUp,
+	#[serde(rename = "entity.puffer_fish.sting")]
+	EntityPufferFishSting,
+	#[serde(rename = "entity.rabbit.ambient")]
+	EntityRabbitAmbient,
+	#[serde(rename = "entity.rabbit.attack")]
+	EntityRabbitAttack,
+	#[serde(rename = "entity.rabbit.death")]
+	EntityRabbitDeath,
+	#[serde(rename = "entity.rabbit.hurt")]
+	EntityRabbitHurt,
+	#[serde(rename = "entity.ravager.ambient")]
+	EntityRavagerAmbient,
+	#[serde(rename = "entity.ravager.attack")]
+	EntityRavagerAttack,
+	#[serde(rename = "entity.ravager.death")]
+	EntityRavagerDeath,
+	#[serde(rename = "entity.ravager.hurt")]
+	EntityRavagerHurt,
+	#[serde(rename = "entity.ravager.roar")]
+	EntityRavagerRoar,
+	#[serde(rename = "entity.sheep.ambient")]
+	EntitySheepAmbient,
+	#[serde(rename = "entity.sheep.death")]
+	EntitySheepDeath,
+	#[serde(rename = "entity.sheep.hurt")]
+	EntitySheepHurt,
+	#[serde(rename = "entity.sheep.shear")]
+	EntitySheepShear,
+	#[serde(rename = "entity.shulker.ambient")]
+	EntityShulkerAmbient,
+	#[serde(rename = "entity.shulker.close")]
+	EntityShulkerClose,
+	#[serde(rename = "entity.shulker.death")]
+	EntityShulkerDeath,
+	#[serde(rename = "entity.shulker.hurt")]
+	EntityShulkerHurt,
+	#[serde(rename = "entity.shulker.open")]
+	EntityShulkerOpen,
+	#[serde(rename = "entity.shulker.shoot")]
+	EntityShulkerShoot,
+	#[serde(rename = "entity.shulker.teleport")]
+	EntityShulkerTeleport,
+	#[serde(rename = "entity.silverfish.ambient")]
+	EntitySilverfishAmbient,
+	#[serde(rename = "entity.silverfish.death")]
+	EntitySilverfishDeath,
+	#[serde(rename = "entity.silverfish.hurt")]
+	EntitySilverfishHurt,
+	#[serde(rename = "entity.skeleton.ambient")]
+	EntitySkeletonAmbient,
+	#[serde(rename = "entity.skeleton.death")]
+	EntitySkeletonDeath,
+	#[serde(rename = "entity.skeleton.hurt")]
+	EntitySkeletonHurt,
+	#[serde(rename = "entity.skeleton.shoot")]
+	EntitySkeletonShoot,
+	#[serde(rename = "entity.slime.attack")]
+	EntitySlimeAttack,
+	#[serde(rename = "entity.slime.death")]
+	EntitySlimeDeath,
+	#[serde(rename = "entity.slime.hurt")]
+	EntitySlimeHurt,
+	#[serde(rename = "entity.slime.jump")]
+	EntitySlimeJump,
+	#[serde(rename = "entity.snow_golem.ambient")]
+	EntitySnowGolemAmbient,
+	#[serde(rename = "entity.snow_golem.death")]
+	EntitySnowGolemDeath,
+	#[serde(rename = "entity.snow_golem.hurt")]
+	EntitySnowGolemHurt,
+	#[serde(rename = "entity.snow_golem.shoot")]
+	EntitySnowGolemShoot,
+	#[serde(rename = "entity.snowball.throw")]
+	EntitySnowballThrow,
+	#[serde(rename = "entity.spider.ambient")]
+	EntitySpiderAmbient,
+	#[serde(rename = "entity.spider.death")]
+	EntitySpiderDeath,
+	#[serde(rename = "entity.spider.hurt")]
+	EntitySpiderHurt,
+	#[serde(rename = "entity.splash_potion.break")]
+	EntitySplashPotionBreak,
+	#[serde(rename = "entity.splash_potion.throw")]
+	EntitySplashPotionThrow,
+	#[serde(rename = "entity.squid.ambient")]
+	EntitySquidAmbient,
+	#[serde(rename = "entity.squid.death")]
+	EntitySquidDeath,
+	#[serde(rename = "entity.squid.hurt")]
+	EntitySquidHurt,
+	#[serde(rename = "entity.strider.ambient")]
+	EntityStriderAmbient,
+	#[serde(rename = "entity.strider.death")]
+	EntityStriderDeath,
+	#[serde(rename = "entity.strider.hurt")]
+	EntityStriderHurt,
+	#[serde(rename = "entity.tnt.primed")]
+	EntityTntPrimed,
+	#[serde(rename = "entity.turtle.ambient_land")]
+	EntityTurtleAmbientLand,
+	#[serde(rename = "entity.turtle.death")]
+	EntityTurtleDeath,
+	#[serde(rename = "entity.turtle.hurt")]
+	EntityTurtleHurt,
+	#[serde(rename = "entity.vex.ambient")]
+	EntityVexAmbient,
+	#[serde(rename = "entity.vex.charge")]
+	EntityVexCharge,
+	#[serde(rename = "entity.vex.death")]
+	EntityVexDeath,
+	#[serde(rename = "entity.vex.hurt")]
+	EntityVexHurt,
+	#[serde(rename = "entity.villager.ambient")]
+	EntityVillagerAmbient,
+	#[serde(rename = "entity.villager.celebrate")]
+	EntityVillagerCelebrate,
+	#[serde(rename = "entity.villager.death")]
+	EntityVillagerDeath,
+	#[serde(rename = "entity.villager.hurt")]
+	EntityVillagerHurt,
+	#[serde(rename = "entity.villager.no")]
+	EntityVillagerNo,
+	#[serde(rename = "entity.villager.trade")]
+	EntityVillagerTrade,
+	#[serde(rename = "entity.villager.yes")]
+	EntityVillagerYes,
+	#[serde(rename = "entity.vindicator.ambient")]
+	EntityVindicatorAmbient,
+	#[serde(rename = "entity.vindicator.death")]
+	EntityVindicatorDeath,
+	#[serde(rename = "entity.vindicator.hurt")]
+	EntityVindicatorHurt,
+	#[serde(rename = "entity.wandering_trader.ambient")]
+	EntityWanderingTraderAmbient,
+	#[serde(rename = "entity.wandering_trader.death")]
+	EntityWanderingTraderDeath,
+	#[serde(rename = "entity.wandering_trader.hurt")]
+	EntityWanderingTraderHurt,
+	#[serde(rename = "entity.warden.ambient")]
+	EntityWardenAmbient,
+	#[serde(rename = "entity.warden.angry")]
+	EntityWardenAngry,
+	#[serde(rename = "entity.warden.attack_impact")]
+	EntityWardenAttackImpact,
+	#[serde(rename = "entity.warden.death")]
+	EntityWardenDeath,
+	#[serde(rename = "entity.warden.emerge")]
+	EntityWardenEmerge,
+	#[serde(rename = "entity.warden.heartbeat")]
+	EntityWardenHeartbeat,
+	#[serde(rename = "entity.warden.hurt")]
+	EntityWardenHurt,
+	#[serde(rename = "entity.warden.roar")]
+	EntityWardenRoar,
+	#[serde(rename = "entity.warden.sonic_boom")]
+	EntityWardenSonicBoom,
+	#[serde(rename = "entity.witch.ambient")]
+	EntityWitchAmbient,
+	#[serde(rename = "entity.witch.death")]
+	EntityWitchDeath,
+	#[serde(rename = "entity.witch.hurt")]
+	EntityWitchHurt,
+	#[serde(rename = "entity.witch.throw")]
+	EntityWitchThrow,
+	#[serde(rename = "entity.wither.ambient")]
+	EntityWitherAmbient,
+	#[serde(rename = "entity.wither.break_block")]
+	EntityWitherBreakBlock,
+	#[serde(rename = "entity.wither.death")]
+	EntityWitherDeath,
+	#[serde(rename = "entity.wither.hurt")]
+	EntityWitherHurt,
+	#[serde(rename = "entity.wither.shoot")]
+	EntityWitherShoot,
+	#[serde(rename = "entity.wither.spawn")]
+	EntityWitherSpawn,
+	#[serde(rename = "entity.wither_skeleton.ambient")]
+	EntityWitherSkeletonAmbient,
+	#[serde(rename = "entity.wither_skeleton.death")]
+	EntityWitherSkeletonDeath,
+	#[serde(rename = "entity.wither_skeleton.hurt")]
+	EntityWitherSkeletonHurt,
+	#[serde(rename = "entity.wolf.ambient")]
+	EntityWolfAmbient,
+	#[serde(rename = "entity.wolf.death")]
+	EntityWolfDeath,
+	#[serde(rename = "entity.wolf.growl")]
+	EntityWolfGrowl,
+	#[serde(rename = "entity.wolf.howl")]
+	EntityWolfHowl,
+	#[serde(rename = "entity.wolf.hurt")]
+	EntityWolfHurt,
+	#[serde(rename = "entity.wolf.pant")]
+	EntityWolfPant,
+	#[serde(rename = "entity.wolf.shake")]
+	EntityWolfShake,
+	#[serde(rename = "entity.wolf.whine")]
+	EntityWolfWhine,
+	#[serde(rename = "entity.zoglin.ambient")]
+	EntityZoglinAmbient,
+	#[serde(rename = "entity.zoglin.death")]
+	EntityZoglinDeath,
+	#[serde(rename = "entity.zoglin.hurt")]
+	EntityZoglinHurt,
+	#[serde(rename = "entity.zombie.ambient")]
+	EntityZombieAmbient,
+	#[serde(rename = "entity.zombie.attack_iron_door")]
+	EntityZombieAttackIronDoor,
+	#[serde(rename = "entity.zombie.attack_wooden_door")]
+	EntityZombieAttackWoodenDoor,
+	#[serde(rename = "entity.zombie.break_wooden_door")]
+	EntityZombieBreakWoodenDoor,
+	#[serde(rename = "entity.zombie.death")]
+	EntityZombieDeath,
+	#[serde(rename = "entity.zombie.hurt")]
+	EntityZombieHurt,
+	#[serde(rename = "entity.zombie.infect")]
+	EntityZombieInfect,
+	#[serde(rename = "entity.zombie_villager.ambient")]
+	EntityZombieVillagerAmbient,
+	#[serde(rename = "entity.zombie_villager.converted")]
+	EntityZombieVillagerConverted,
+	#[serde(rename = "entity.zombie_villager.cure")]
+	EntityZombieVillagerCure,
+	#[serde(rename = "entity.zombie_villager.death")]
+	EntityZombieVillagerDeath,
+	#[serde(rename = "entity.zombie_villager.hurt")]
+	EntityZombieVillagerHurt,
+	#[serde(rename = "entity.zombified_piglin.ambient")]
+	EntityZombifiedPiglinAmbient,
+	#[serde(rename = "entity.zombified_piglin.angry")]
+	EntityZombifiedPiglinAngry,
+	#[serde(rename = "entity.zombified_piglin.death")]
+	EntityZombifiedPiglinDeath,
+	#[serde(rename = "entity.zombified_piglin.hurt")]
+	EntityZombifiedPiglinHurt,
+	#[serde(rename = "event.raid.horn")]
+	EventRaidHorn,
+	#[serde(rename = "item.armor.equip_chain")]
+	ItemArmorEquipChain,
+	#[serde(rename = "item.armor.equip_diamond")]
+	ItemArmorEquipDiamond,
+	#[serde(rename = "item.armor.equip_elytra")]
+	ItemArmorEquipElytra,
+	#[serde(rename = "item.armor.equip_generic")]
+	ItemArmorEquipGeneric,
+	#[serde(rename = "item.armor.equip_gold")]
+	ItemArmorEquipGold,
+	#[serde(rename = "item.armor.equip_iron")]
+	ItemArmorEquipIron,
+	#[serde(rename = "item.armor.equip_leather")]
+	ItemArmorEquipLeather,
+	#[serde(rename = "item.armor.equip_netherite")]
+	ItemArmorEquipNetherite,
+	#[serde(rename = "item.armor.equip_turtle")]
+	ItemArmorEquipTurtle,
+	#[serde(rename = "item.axe.strip")]
+	ItemAxeStrip,
+	#[serde(rename = "item.bone_meal.use")]
+	ItemBoneMealUse,
+	#[serde(rename = "item.book.page_turn")]
+	ItemBookPageTurn,
+	#[serde(rename = "item.book.put")]
+	ItemBookPut,
+	#[serde(rename = "item.bottle.empty")]
+	ItemBottleEmpty,
+	#[serde(rename = "item.bottle.fill")]
+	ItemBottleFill,
+	#[serde(rename = "item.bucket.empty")]
+	ItemBucketEmpty,
+	#[serde(rename = "item.bucket.empty_lava")]
+	ItemBucketEmptyLava,
+	#[serde(rename = "item.bucket.fill")]
+	ItemBucketFill,
+	#[serde(rename = "item.bucket.fill_lava")]
+	ItemBucketFillLava,
+	#[serde(rename = "item.chorus_fruit.teleport")]
+	ItemChorusFruitTeleport,
+	#[serde(rename = "item.crossbow.hit")]
+	ItemCrossbowHit,
+	#[serde(rename = "item.crossbow.loading_end")]
+	ItemCrossbowLoadingEnd,
+	#[serde(rename = "item.crossbow.loading_middle")]
+	ItemCrossbowLoadingMiddle,
+	#[serde(rename = "item.crossbow.loading_start")]
+	ItemCrossbowLoadingStart,
+	#[serde(rename = "item.crossbow.shoot")]
+	ItemCrossbowShoot,
+	#[serde(rename = "item.elytra.flying")]
+	ItemElytraFlying,
+	#[serde(rename = "item.firecharge.use")]
+	ItemFirechargeUse,
+	#[serde(rename = "item.flintandsteel.use")]
+	ItemFlintandsteelUse,
+	#[serde(rename = "item.goat_horn.sound.0")]
+	ItemGoatHornSound0,
+	#[serde(rename = "item.hoe.till")]
+	ItemHoeTill,
+	#[serde(rename = "item.honey_bottle.drink")]
+	ItemHoneyBottleDrink,
+	#[serde(rename = "item.shield.block")]
+	ItemShieldBlock,
+	#[serde(rename = "item.shield.break")]
+	ItemShieldBreak,
+	#[serde(rename = "item.shovel.flatten")]
+	ItemShovelFlatten,
+	#[serde(rename = "item.spyglass.use")]
+	ItemSpyglassUse,
+	#[serde(rename = "item.totem.use")]
+	ItemTotemUse,
+	#[serde(rename = "item.trident.hit")]
+	ItemTridentHit,
+	#[serde(rename = "item.trident.return")]
+	ItemTridentReturn,
+	#[serde(rename = "item.trident.riptide_1")]
+	ItemTridentRiptide1,
+	#[serde(rename = "item.trident.thrown")]
+	ItemTridentThrown,
+	#[serde(rename = "item.trident.thunder")]
+	ItemTridentThunder,
+	#[serde(rename = "music.creative")]
+	MusicCreative,
+	#[serde(rename = "music.credits")]
+	MusicCredits,
+	#[serde(rename = "music.dragon")]
+	MusicDragon,
+	#[serde(rename = "music.end")]
+	MusicEnd,
+	#[serde(rename = "music.game")]
+	MusicGame,
+	#[serde(rename = "music.menu")]
+	MusicMenu,
+	#[serde(rename = "music.nether.basalt_deltas")]
+	MusicNetherBasaltDeltas,
+	#[serde(rename = "music.nether.crimson_forest")]
+	MusicNetherCrimsonForest,
+	#[serde(rename = "music.under_water")]
+	MusicUnderWater,
+	#[serde(rename = "music_disc.11")]
+	MusicDisc11,
+	#[serde(rename = "music_disc.13")]
+	MusicDisc13,
+	#[serde(rename = "music_disc.5")]
+	MusicDisc5,
+	#[serde(rename = "music_disc.blocks")]
+	MusicDiscBlocks,
+	#[serde(rename = "music_disc.cat")]
+	MusicDiscCat,
+	#[serde(rename = "music_disc.chirp")]
+	MusicDiscChirp,
+	#[serde(rename = "music_disc.far")]
+	MusicDiscFar,
+	#[serde(rename = "music_disc.mall")]
+	MusicDiscMall,
+	#[serde(rename = "music_disc.mellohi")]
+	MusicDiscMellohi,
+	#[serde(rename = "music_disc.otherside")]
+	MusicDiscOtherside,
+	#[serde(rename = "music_disc.pigstep")]
+	MusicDiscPigstep,
+	#[serde(rename = "music_disc.stal")]
+	MusicDiscStal,
+	#[serde(rename = "music_disc.strad")]
+	MusicDiscStrad,
+	#[serde(rename = "music_disc.wait")]
+	MusicDiscWait,
+	#[serde(rename = "music_disc.ward")]
+	MusicDiscWard,
+	#[serde(rename = "ui.button.click")]
+	UiButtonClick,
+	#[serde(rename = "ui.cartography_table.take_result")]
+	UiCartographyTableTakeResult,
+	#[serde(rename = "ui.loom.select_pattern")]
+	UiLoomSelectPattern,
+	#[serde(rename = "ui.loom.take_result")]
+	UiLoomTakeResult,
+	#[serde(rename = "ui.stonecutter.take_result")]
+	UiStonecutterTakeResult,
+	#[serde(rename = "ui.toast.challenge_complete")]
+	UiToastChallengeComplete,
+	#[serde(rename = "ui.toast.in")]
+	UiToastIn,
+	#[serde(rename = "ui.toast.out")]
+	UiToastOut,
+	#[serde(rename = "weather.rain")]
+	WeatherRain,
+	#[serde(rename = "weather.rain.above")]
+	WeatherRainAbove,
+}
+
+pub type SoundId = SoundIdWants10Bits;
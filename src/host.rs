@@ -0,0 +1,574 @@
+use std::collections::HashMap;
+
+use crate::{
+    assets::Action,
+    generated::{ActionObject, GameValueId},
+    interpreter::{Args, Val},
+    module::SelectionTypeWants5Bits,
+    types::type_name,
+};
+
+/// Storage, armor and off hand slots of a player.
+const INVENTORY_SLOTS: usize = 41;
+
+/// What the interpreter calls for actions of the `player`, `entity`, `world` and
+/// `select` families and for game values.
+pub trait Host {
+    /// Runs the action on the targets of `selection`. Returns values for the action's
+    /// assigned arguments, by argument name.
+    fn action(
+        &mut self,
+        action: &Action,
+        selection: Option<SelectionTypeWants5Bits>,
+        args: &Args,
+    ) -> Result<Vec<(String, Val)>, String>;
+
+    fn condition(
+        &mut self,
+        action: &Action,
+        selection: Option<SelectionTypeWants5Bits>,
+        args: &Args,
+    ) -> Result<bool, String>;
+
+    fn game_value(
+        &mut self,
+        id: GameValueId,
+        selection: SelectionTypeWants5Bits,
+    ) -> Result<Val, String>;
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Entity {
+    pub uuid: String,
+    pub name: String,
+    pub entity_type: String,
+    pub location: [f64; 5],
+    pub velocity: [f64; 3],
+    pub health: f64,
+    pub max_health: f64,
+    pub custom_name: Option<String>,
+    pub tags: Vec<(String, String)>,
+    /// Only players have one.
+    pub inventory: Option<Vec<Val>>,
+    /// Chat, action bar and title text sent to a player.
+    pub messages: Vec<String>,
+}
+
+impl Entity {
+    pub fn new(entity_type: &str, name: &str) -> Self {
+        Self {
+            uuid: String::new(),
+            name: name.to_string(),
+            entity_type: entity_type.to_string(),
+            location: [0.0; 5],
+            velocity: [0.0; 3],
+            health: 20.0,
+            max_health: 20.0,
+            custom_name: None,
+            tags: Vec::new(),
+            inventory: None,
+            messages: Vec::new(),
+        }
+    }
+
+    pub fn player(name: &str) -> Self {
+        Self {
+            inventory: Some(Vec::new()),
+            ..Self::new("player", name)
+        }
+    }
+
+    pub fn is_player(&self) -> bool {
+        self.inventory.is_some()
+    }
+
+    pub fn tag(&self, name: &str) -> Option<&str> {
+        self.tags
+            .iter()
+            .find(|(tag, _)| tag == name)
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+/// An in-memory stand-in for a server. It keeps entities, players and event state;
+/// actions it doesn't model, like particles or sounds, do nothing.
+#[derive(Debug, Clone, Default)]
+pub struct World {
+    pub entities: Vec<Entity>,
+    /// The player or entity the running event is about.
+    pub default: Option<String>,
+    /// Killer, damager, victim, shooter and projectile of the running event.
+    pub roles: HashMap<SelectionTypeWants5Bits, String>,
+    pub selected: Vec<String>,
+    pub last_entity: Option<String>,
+    pub cancelled: bool,
+    pub time: f64,
+    /// Answers for game values, checked before the entity ones. Event data goes here.
+    pub values: HashMap<GameValueId, Val>,
+    spawned: u64,
+}
+
+impl World {
+    /// Adds an entity, giving it a fresh uuid, and returns the uuid.
+    pub fn spawn(&mut self, mut entity: Entity) -> String {
+        self.spawned += 1;
+        entity.uuid = format!("00000000-0000-0000-0000-{:012x}", self.spawned);
+        self.last_entity = Some(entity.uuid.clone());
+        self.entities.push(entity);
+        self.entities.last().unwrap().uuid.clone()
+    }
+
+    pub fn entity(&self, uuid: &str) -> Option<&Entity> {
+        self.entities.iter().find(|entity| entity.uuid == uuid)
+    }
+
+    pub fn entity_mut(&mut self, uuid: &str) -> Option<&mut Entity> {
+        self.entities.iter_mut().find(|entity| entity.uuid == uuid)
+    }
+
+    /// Finds a player or entity by name or uuid.
+    pub fn find(&self, name_or_uuid: &str) -> Option<&Entity> {
+        self.entities
+            .iter()
+            .find(|entity| entity.name == name_or_uuid || entity.uuid == name_or_uuid)
+    }
+
+    /// Indices of the entities a selection stands for. Random selections take the first.
+    pub fn targets(&self, selection: Option<SelectionTypeWants5Bits>) -> Vec<usize> {
+        let by_uuid = |uuids: &mut dyn Iterator<Item = &String>| {
+            uuids
+                .filter_map(|uuid| self.entities.iter().position(|entity| entity.uuid == *uuid))
+                .collect::<Vec<_>>()
+        };
+        let all = |player: Option<bool>| {
+            (0..self.entities.len())
+                .filter(|&i| player.is_none_or(|player| self.entities[i].is_player() == player))
+                .collect::<Vec<_>>()
+        };
+
+        match selection.unwrap_or(SelectionTypeWants5Bits::Current) {
+            SelectionTypeWants5Bits::Current if !self.selected.is_empty() => {
+                by_uuid(&mut self.selected.iter())
+            }
+            SelectionTypeWants5Bits::Selected => by_uuid(&mut self.selected.iter()),
+            SelectionTypeWants5Bits::LastEntity | SelectionTypeWants5Bits::LastMob => {
+                by_uuid(&mut self.last_entity.iter())
+            }
+            SelectionTypeWants5Bits::RandomPlayer => all(Some(true)).into_iter().take(1).collect(),
+            SelectionTypeWants5Bits::RandomEntity => all(None).into_iter().take(1).collect(),
+            SelectionTypeWants5Bits::RandomMob => all(Some(false)).into_iter().take(1).collect(),
+            SelectionTypeWants5Bits::AllPlayers => all(Some(true)),
+            SelectionTypeWants5Bits::AllEntities => all(None),
+            SelectionTypeWants5Bits::AllMobs => all(Some(false)),
+            role @ (SelectionTypeWants5Bits::Killer
+            | SelectionTypeWants5Bits::Damager
+            | SelectionTypeWants5Bits::Victim
+            | SelectionTypeWants5Bits::Shooter
+            | SelectionTypeWants5Bits::KillerEntity
+            | SelectionTypeWants5Bits::DamagerEntity
+            | SelectionTypeWants5Bits::VictimEntity
+            | SelectionTypeWants5Bits::ShooterEntity
+            | SelectionTypeWants5Bits::Projectile) => {
+                by_uuid(&mut self.roles.get(&role).into_iter())
+            }
+            _ => by_uuid(&mut self.default.iter()),
+        }
+    }
+
+    fn select(&mut self, action: &Action, args: &Args) -> Result<(), String> {
+        let uuids = |indices: Vec<usize>, world: &Self| -> Vec<String> {
+            indices
+                .into_iter()
+                .map(|i| world.entities[i].uuid.clone())
+                .collect()
+        };
+        let named = |world: &Self, player: bool| -> Vec<String> {
+            args.list("name_or_uuid")
+                .iter()
+                .filter_map(|name| world.find(&name.to_string()))
+                .filter(|entity| !player || entity.is_player())
+                .map(|entity| entity.uuid.clone())
+                .collect()
+        };
+
+        let (add, name) = match action.name.strip_prefix("add_") {
+            Some(name) => (true, name),
+            None => (false, action.name.as_str()),
+        };
+
+        let found = match name {
+            "all_players" => uuids(
+                self.targets(Some(SelectionTypeWants5Bits::AllPlayers)),
+                self,
+            ),
+            "all_entities" => uuids(
+                self.targets(Some(SelectionTypeWants5Bits::AllEntities)),
+                self,
+            ),
+            "all_mobs" => uuids(self.targets(Some(SelectionTypeWants5Bits::AllMobs)), self),
+            "random_player" => uuids(
+                self.targets(Some(SelectionTypeWants5Bits::RandomPlayer)),
+                self,
+            ),
+            "random_entity" => uuids(
+                self.targets(Some(SelectionTypeWants5Bits::RandomEntity)),
+                self,
+            ),
+            "random_mob" => uuids(self.targets(Some(SelectionTypeWants5Bits::RandomMob)), self),
+            "last_entity" | "last_mob" => self.last_entity.iter().cloned().collect(),
+            "player_by_name" => named(self, true),
+            "entity_by_name" | "mob_by_name" => named(self, false),
+            "event_target" => {
+                let role = match args.choice("selection_type", "Default").as_str() {
+                    "Damager" => SelectionTypeWants5Bits::Damager,
+                    "Killer" => SelectionTypeWants5Bits::Killer,
+                    "Projectile" => SelectionTypeWants5Bits::Projectile,
+                    "Shooter" => SelectionTypeWants5Bits::Shooter,
+                    "Victim" => SelectionTypeWants5Bits::Victim,
+                    _ => SelectionTypeWants5Bits::Default,
+                };
+                uuids(self.targets(Some(role)), self)
+            }
+            "filter_randomly" => {
+                let size = args.number_or("size", 1.0)?.max(0.0) as usize;
+                self.selected.truncate(size);
+                return Ok(());
+            }
+            "reset" => Vec::new(),
+            "dummy" => return Ok(()),
+            name => return Err(format!("select.{name} is not simulated")),
+        };
+
+        if !add {
+            self.selected.clear();
+        }
+
+        for uuid in found {
+            if !self.selected.contains(&uuid) {
+                self.selected.push(uuid);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn merged_text(args: &Args, name: &str) -> String {
+    let parts: Vec<String> = args.list(name).iter().map(Val::to_string).collect();
+
+    match args.choice("merging", "Concatenation").as_str() {
+        "Spaces" => parts.join(" "),
+        "SeparateLines" => parts.join("\n"),
+        _ => parts.concat(),
+    }
+}
+
+fn item_type(item: &Val) -> String {
+    match item {
+        Val::Item { item, .. } => item.clone(),
+        other => other.to_string(),
+    }
+}
+
+fn near(entity: &Entity, args: &Args) -> Result<bool, String> {
+    let [x, y, z, ..] = args.location("location")?;
+    let [ex, ey, ez, ..] = entity.location;
+    let dy = if args.flag("ignore_y_axis") {
+        0.0
+    } else {
+        ey - y
+    };
+    let distance = ((ex - x).powi(2) + dy.powi(2) + (ez - z).powi(2)).sqrt();
+    Ok(distance <= args.number("range")?)
+}
+
+impl Host for World {
+    fn action(
+        &mut self,
+        action: &Action,
+        selection: Option<SelectionTypeWants5Bits>,
+        args: &Args,
+    ) -> Result<Vec<(String, Val)>, String> {
+        match action.object {
+            ActionObject::Select => return self.select(action, args).map(|_| Vec::new()),
+            ActionObject::World => {
+                match action.name.as_str() {
+                    "cancel_event" => self.cancelled = true,
+                    "uncancel_event" => self.cancelled = false,
+                    "set_world_time" => self.time = args.number("time")?,
+                    "set_event_damage" => {
+                        let damage = Val::Number(args.number("damage")?);
+                        self.values.insert(GameValueId::EventDamage, damage);
+                    }
+                    "spawn_item" | "spawn_mob" => {
+                        let (item, entity_type) = match action.name.as_str() {
+                            "spawn_item" => (args.value("item")?, "item"),
+                            _ => (args.value("mob")?, ""),
+                        };
+                        let mut entity = Entity::new(entity_type, &item_type(item));
+
+                        if entity_type.is_empty() {
+                            entity.entity_type = item_type(item);
+                            entity.health = args.number_or("health", 20.0)?;
+                            entity.max_health = entity.health;
+                        }
+
+                        entity.location = args.location("location")?;
+                        entity.custom_name = args.get("custom_name").map(Val::to_string);
+                        self.spawn(entity);
+                    }
+                    _ => {}
+                }
+
+                return Ok(Vec::new());
+            }
+            _ => {}
+        }
+
+        let players = action.object == ActionObject::Player;
+        let mut assigned = Vec::new();
+        let mut removed = Vec::new();
+
+        for i in self.targets(selection) {
+            let entity = &mut self.entities[i];
+
+            if players && !entity.is_player() {
+                continue;
+            }
+
+            match action.name.as_str() {
+                "message" => entity.messages.push(merged_text(args, "messages")),
+                "send_action_bar" => entity.messages.push(merged_text(args, "messages")),
+                "send_title" => entity.messages.push(
+                    [args.text_or("title", ""), args.text_or("subtitle", "")]
+                        .join("\n")
+                        .trim_end()
+                        .to_string(),
+                ),
+                "give_items" => {
+                    let amount = args.number_or("amount", 1.0)?.max(1.0) as usize;
+                    let items = args.list("items");
+                    let inventory = entity.inventory.get_or_insert_default();
+                    // Like in game, whatever doesn't fit anymore is lost
+                    let room = INVENTORY_SLOTS.saturating_sub(inventory.len());
+                    let given = items.len().saturating_mul(amount).min(room);
+                    inventory.extend(items.iter().cycle().take(given).cloned());
+                }
+                "set_items" => entity.inventory = Some(args.list("items")),
+                "clear_inventory" => entity.inventory = Some(Vec::new()),
+                "remove_items" => {
+                    let removed: Vec<String> = args.list("items").iter().map(item_type).collect();
+
+                    if let Some(inventory) = &mut entity.inventory {
+                        inventory.retain(|item| !removed.contains(&item_type(item)));
+                    }
+                }
+                "damage" => entity.health = (entity.health - args.number("damage")?).max(0.0),
+                "heal" => {
+                    let heal = args.number_or("heal", entity.max_health)?;
+                    entity.health = (entity.health + heal).min(entity.max_health);
+                }
+                "set_health" | "set_current_health" => {
+                    entity.health = args.number("health")?.min(entity.max_health)
+                }
+                "set_max_health" => {
+                    entity.max_health = match players {
+                        true => args.number("health")?,
+                        false => args.number("max_health")?,
+                    };
+                    entity.health = entity.health.min(entity.max_health);
+
+                    if args.flag("heal") || args.flag("heal_to_max") {
+                        entity.health = entity.max_health;
+                    }
+                }
+                "teleport" => entity.location = args.location("location")?,
+                "set_velocity" | "set_location" => {
+                    let velocity = args.vector("velocity")?;
+
+                    for (axis, change) in velocity.into_iter().enumerate() {
+                        match args.flag("increment") {
+                            true => entity.velocity[axis] += change,
+                            false => entity.velocity[axis] = change,
+                        }
+                    }
+                }
+                "set_custom_name" => entity.custom_name = Some(args.text_or("custom_name", "")),
+                "set_custom_tag" => {
+                    let name = args.text("name")?;
+                    let value = args.text_or("value", "");
+                    entity.tags.retain(|(tag, _)| *tag != name);
+                    entity.tags.push((name, value));
+                }
+                "remove_custom_tag" => {
+                    let name = args.text("name")?;
+                    entity.tags.retain(|(tag, _)| *tag != name);
+                }
+                "get_custom_tag" => {
+                    let name = args.text("name")?;
+                    let value = match entity.tag(&name) {
+                        Some(value) => Some(Val::Text(value.to_string())),
+                        None => args.get("default").cloned(),
+                    };
+                    assigned.extend(value.map(|value| ("variable".to_string(), value)));
+                }
+                "remove" | "kick" => removed.push(entity.uuid.clone()),
+                _ => {}
+            }
+        }
+
+        self.entities
+            .retain(|entity| !removed.contains(&entity.uuid));
+        Ok(assigned)
+    }
+
+    fn condition(
+        &mut self,
+        action: &Action,
+        selection: Option<SelectionTypeWants5Bits>,
+        args: &Args,
+    ) -> Result<bool, String> {
+        if action.object == ActionObject::World {
+            return match action.name.as_str() {
+                "event_is_canceled" => Ok(self.cancelled),
+                name => Err(format!("world condition {name} is not simulated")),
+            };
+        }
+
+        let targets = self.targets(selection);
+        let mut all = !targets.is_empty();
+
+        for i in targets {
+            let entity = &self.entities[i];
+            let names = || {
+                args.list("names_or_uuids")
+                    .into_iter()
+                    .map(|name| name.to_string())
+            };
+
+            all &= match action.name.as_str() {
+                "exists" => true,
+                "name_equals" => names().any(|name| name == entity.name || name == entity.uuid),
+                "is_type" => args
+                    .list("entity_types")
+                    .iter()
+                    .any(|kind| item_type(kind) == entity.entity_type),
+                "is_near" | "is_near_location" => near(entity, args)?,
+                "has_custom_tag" => {
+                    let Some(value) = entity.tag(&args.text("tag")?) else {
+                        all = false;
+                        continue;
+                    };
+                    let wanted = args.text_or("tag_value", "");
+
+                    match args.choice("compare_type", "Equals").as_str() {
+                        "Contains" => value.contains(&wanted),
+                        "StartsWith" => value.starts_with(&wanted),
+                        "EndsWith" => value.ends_with(&wanted),
+                        _ => wanted.is_empty() || value == wanted,
+                    }
+                }
+                "has_item" => {
+                    let inventory: Vec<String> =
+                        entity.inventory.iter().flatten().map(item_type).collect();
+                    let mut items = args.list("items").into_iter().map(|item| item_type(&item));
+
+                    match args.choice("check_mode", "Any").as_str() {
+                        "All" => items.all(|item| inventory.contains(&item)),
+                        _ => items.any(|item| inventory.contains(&item)),
+                    }
+                }
+                name => {
+                    return Err(format!(
+                        "{} condition {name} is not simulated",
+                        type_name(action.object)
+                    ));
+                }
+            };
+        }
+
+        Ok(all)
+    }
+
+    fn game_value(
+        &mut self,
+        id: GameValueId,
+        selection: SelectionTypeWants5Bits,
+    ) -> Result<Val, String> {
+        if let Some(value) = self.values.get(&id) {
+            return Ok(value.clone());
+        }
+
+        let players = self
+            .targets(Some(SelectionTypeWants5Bits::AllPlayers))
+            .len();
+
+        match id {
+            GameValueId::PlayerCount => return Ok(Val::Number(players as f64)),
+            GameValueId::WorldTime => return Ok(Val::Number(self.time)),
+            _ => {}
+        }
+
+        let Some(&i) = self.targets(Some(selection)).first() else {
+            return Err(format!("game value {} has no target", type_name(id)));
+        };
+        let entity = &self.entities[i];
+        let [x, y, z, yaw, pitch] = entity.location;
+
+        Ok(match id {
+            GameValueId::Location => Val::Location {
+                x,
+                y,
+                z,
+                yaw,
+                pitch,
+            },
+            GameValueId::XCoordinate => Val::Number(x),
+            GameValueId::YCoordinate => Val::Number(y),
+            GameValueId::ZCoordinate => Val::Number(z),
+            GameValueId::Yaw => Val::Number(yaw),
+            GameValueId::Pitch => Val::Number(pitch),
+            GameValueId::Velocity => {
+                let [x, y, z] = entity.velocity;
+                Val::Vector { x, y, z }
+            }
+            GameValueId::CurrentHealth => Val::Number(entity.health),
+            GameValueId::MaxHealth => Val::Number(entity.max_health),
+            GameValueId::Name => Val::Text(entity.name.clone()),
+            GameValueId::DisplayName => {
+                Val::Text(entity.custom_name.clone().unwrap_or(entity.name.clone()))
+            }
+            GameValueId::Uuid => Val::Text(entity.uuid.clone()),
+            GameValueId::EntityType => Val::Text(entity.entity_type.clone()),
+            GameValueId::InventoryItems => Val::List(entity.inventory.clone().unwrap_or_default()),
+            id => return Err(format!("game value {} is not simulated", type_name(id))),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        assets::Catalog,
+        interpreter::{Interpreter, Locals},
+        lang,
+    };
+
+    #[test]
+    fn give_items_stops_at_a_full_inventory() {
+        let catalog = Catalog::load().unwrap();
+        let source =
+            "function Give {\n    player.give_items(items: [local.item], amount: 1e18)\n}\n";
+        let module = lang::compile_source(source, &catalog).unwrap();
+        let mut interpreter = Interpreter::new(&module, &catalog);
+        let player = interpreter.host.spawn(Entity::player("player"));
+        interpreter.host.default = Some(player);
+
+        let mut locals = Locals::from([("item".to_string(), Val::Text("stone".to_string()))]);
+        interpreter.run_named("Give", &mut locals).unwrap();
+
+        let inventory = interpreter.host.entities[0].inventory.as_ref().unwrap();
+        assert_eq!(inventory.len(), INVENTORY_SLOTS);
+    }
+}
@@ -0,0 +1,246 @@
+use std::{
+    borrow::Borrow,
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap},
+    hash::Hash,
+};
+
+use bitvec::prelude::*;
+
+/// Codes are kept at most this long, so a length fits in [`LENGTH_BITS`].
+pub const MAX_LENGTH: u8 = 24;
+/// Width of a code length in a stored table.
+pub const LENGTH_BITS: usize = 5;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Code {
+    pub bits: u32,
+    pub len: u8,
+}
+
+impl Code {
+    /// Writes the code most significant bit first, so no code reads as the start of another.
+    pub fn write(self, out: &mut BitVec<u8, Lsb0>) {
+        for bit in (0..self.len).rev() {
+            out.push(self.bits >> bit & 1 == 1);
+        }
+    }
+}
+
+/// A canonical Huffman code. Only the code lengths have to be stored to rebuild it.
+#[derive(Debug, Clone)]
+pub struct Huffman<S> {
+    /// Symbols with their code lengths in canonical order. `None` is the escape, written
+    /// before symbols the code wasn't built with.
+    pub lengths: Vec<(Option<S>, u8)>,
+    codes: HashMap<S, Code>,
+    escape: Option<Code>,
+}
+
+impl<S: Hash + Ord + Clone> Huffman<S> {
+    /// Builds the code from how often each symbol appears. An `escape` weight of 0 leaves
+    /// the escape out.
+    pub fn build(counts: impl IntoIterator<Item = (S, usize)>, escape: usize) -> Self {
+        let mut weights: Vec<(Option<S>, usize)> = counts
+            .into_iter()
+            .filter(|(_, count)| *count > 0)
+            .map(|(symbol, count)| (Some(symbol), count))
+            .collect();
+
+        if escape > 0 {
+            weights.push((None, escape));
+        }
+
+        // Ties are broken by position, so the same counts always give the same code
+        weights.sort();
+
+        loop {
+            let lengths = lengths(&weights);
+
+            if lengths.iter().all(|&len| len <= MAX_LENGTH as usize) {
+                let symbols = weights.into_iter().map(|(symbol, _)| symbol);
+                return Self::from_lengths(symbols.zip(lengths.into_iter().map(|len| len as u8)));
+            }
+
+            // Evening out the weights shortens the longest codes
+            for (_, weight) in &mut weights {
+                *weight = *weight / 2 + 1;
+            }
+        }
+    }
+
+    /// Assigns canonical codes: shorter ones first, symbols of the same length in order.
+    pub fn from_lengths(lengths: impl IntoIterator<Item = (Option<S>, u8)>) -> Self {
+        let mut lengths: Vec<_> = lengths.into_iter().collect();
+        lengths.sort_by(|(a, a_len), (b, b_len)| a_len.cmp(b_len).then(a.cmp(b)));
+
+        let mut codes = HashMap::new();
+        let mut escape = None;
+        let mut bits = 0;
+        let mut previous = 0;
+
+        for (symbol, len) in &lengths {
+            bits <<= len - previous;
+            let code = Code { bits, len: *len };
+
+            match symbol {
+                Some(symbol) => _ = codes.insert(symbol.clone(), code),
+                None => escape = Some(code),
+            }
+
+            bits += 1;
+            previous = *len;
+        }
+
+        Self {
+            lengths,
+            codes,
+            escape,
+        }
+    }
+
+    pub fn code<Q>(&self, symbol: &Q) -> Option<Code>
+    where
+        S: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.codes.get(symbol).copied()
+    }
+
+    pub fn escape(&self) -> Option<Code> {
+        self.escape
+    }
+
+    /// Symbols with their code lengths, without the escape.
+    pub fn symbols(&self) -> impl Iterator<Item = (&S, u8)> {
+        (self.lengths.iter()).filter_map(|(symbol, len)| Some((symbol.as_ref()?, *len)))
+    }
+}
+
+/// Depth of each leaf in the Huffman tree of the weights. A lone symbol needs no bits.
+fn lengths<S>(weights: &[(S, usize)]) -> Vec<usize> {
+    if weights.len() < 2 {
+        return vec![0; weights.len()];
+    }
+
+    // Leaves first, then the nodes merging them. The root is its own parent.
+    let mut parents = vec![0; weights.len()];
+    let mut heap: BinaryHeap<_> = (weights.iter().enumerate())
+        .map(|(node, (_, weight))| Reverse((*weight, node)))
+        .collect();
+
+    while let (Some(Reverse((a_weight, a))), Some(Reverse((b_weight, b)))) =
+        (heap.pop(), heap.pop())
+    {
+        let node = parents.len();
+        parents.push(node);
+        parents[a] = node;
+        parents[b] = node;
+        heap.push(Reverse((a_weight + b_weight, node)));
+    }
+
+    (0..weights.len())
+        .map(|mut node| {
+            let mut depth = 0;
+
+            while parents[node] != node {
+                node = parents[node];
+                depth += 1;
+            }
+
+            depth
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Reads symbols back by matching codes bit by bit.
+    fn decode(code: &Huffman<char>, bits: &BitSlice<u8, Lsb0>) -> Vec<Option<char>> {
+        let mut symbols = Vec::new();
+        let (mut value, mut len) = (0, 0);
+
+        for bit in bits {
+            value = value << 1 | *bit as u32;
+            len += 1;
+
+            let read = Code { bits: value, len };
+            let found =
+                (code.lengths.iter())
+                    .map(|(symbol, _)| symbol)
+                    .find(|symbol| match symbol {
+                        Some(symbol) => code.code(symbol) == Some(read),
+                        None => code.escape() == Some(read),
+                    });
+
+            if let Some(symbol) = found {
+                symbols.push(*symbol);
+                (value, len) = (0, 0);
+            }
+        }
+
+        symbols
+    }
+
+    #[test]
+    fn round_trip_through_lengths() {
+        let text = "abracadabra alakazam";
+        let mut counts = HashMap::new();
+
+        for c in text.chars() {
+            *counts.entry(c).or_insert(0) += 1;
+        }
+
+        let built = Huffman::build(counts, 0);
+        let code = Huffman::from_lengths(built.lengths.clone());
+        let mut bits = BitVec::new();
+
+        for c in text.chars() {
+            assert_eq!(code.code(&c), built.code(&c));
+            code.code(&c).unwrap().write(&mut bits);
+        }
+
+        let decoded: Vec<_> = decode(&code, &bits).into_iter().flatten().collect();
+        assert_eq!(decoded.into_iter().collect::<String>(), text);
+    }
+
+    #[test]
+    fn lengths_are_limited() {
+        // Fibonacci weights give the most lopsided tree
+        let (mut a, mut b) = (1, 1);
+        let counts = ('a'..='z').chain('A'..='Z').map(|c| {
+            (a, b) = (b, a + b);
+            (c, a)
+        });
+        let code = Huffman::build(counts, 0);
+
+        assert_eq!(code.symbols().count(), 52);
+        assert!(code.symbols().all(|(_, len)| len <= MAX_LENGTH));
+        assert!(code.symbols().any(|(_, len)| len == MAX_LENGTH));
+    }
+
+    #[test]
+    fn lone_symbol_needs_no_bits() {
+        let code = Huffman::build([('a', 5)], 0);
+
+        assert_eq!(code.code(&'a'), Some(Code { bits: 0, len: 0 }));
+        assert_eq!(code.escape(), None);
+    }
+
+    #[test]
+    fn escape_is_a_code_of_its_own() {
+        let code = Huffman::build([('a', 5), ('b', 3)], 1);
+        let escape = code.escape().unwrap();
+
+        assert_eq!(code.code(&'z'), None);
+        assert_eq!(code.symbols().count(), 2);
+        assert!(escape.len > 0);
+
+        let mut bits = BitVec::new();
+        escape.write(&mut bits);
+        code.code(&'a').unwrap().write(&mut bits);
+        assert_eq!(decode(&code, &bits), [None, Some('a')]);
+    }
+}
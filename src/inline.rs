@@ -0,0 +1,346 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    callgraph::{CallGraph, CallKind},
+    generated::ActionIdWants11Bits,
+    module::{
+        Line, LineTypeWants2Bits, Module, NamedValue, Op, TextParsingWants2Bits, Value, walk_ops,
+    },
+    optimize::Change,
+};
+
+/// Functions this small, counting container bodies, are inlined into their only caller.
+pub const INLINE_OPS: usize = 3;
+/// Repeated sequences need at least this many ops to be moved into a function.
+pub const OUTLINE_OPS: usize = 3;
+
+fn size(ops: &[Op]) -> usize {
+    let mut size = 0;
+    walk_ops(ops, &mut |_, _| size += 1);
+    size
+}
+
+/// Whether the ops leave the function they are in. Moved into another function they'd
+/// leave that one instead.
+fn exits(ops: &[Op]) -> bool {
+    let mut exits = false;
+
+    walk_ops(ops, &mut |_, op| {
+        exits |= matches!(
+            op.action,
+            ActionIdWants11Bits::ControlReturnFunction
+                | ActionIdWants11Bits::ControlStopRepeat
+                | ActionIdWants11Bits::ControlSkipIteration
+        );
+    });
+
+    exits
+}
+
+fn call(name: &str) -> Op {
+    Op {
+        action: ActionIdWants11Bits::CallFunction,
+        values: vec![NamedValue {
+            name: "function_name".to_string(),
+            value: Value::Text {
+                text: name.to_string(),
+                parsing: TextParsingWants2Bits::Plain,
+            },
+        }],
+        selection: None,
+        is_inverted: false,
+        conditional: None,
+        operations: Vec::new(),
+    }
+}
+
+/// The op list reached by following `path` through container bodies.
+fn ops_at<'o>(ops: &'o mut Vec<Op>, path: &[usize]) -> &'o mut Vec<Op> {
+    match path.split_first() {
+        Some((index, rest)) => ops_at(&mut ops[*index].operations, rest),
+        None => ops,
+    }
+}
+
+/// Replaces every top level `call_function` of `name` with `body`, returning the paths of
+/// the calls.
+fn splice(ops: &mut Vec<Op>, name: &str, body: &[Op], path: &mut Vec<usize>) -> Vec<Vec<usize>> {
+    let mut spliced = Vec::new();
+    let mut index = 0;
+
+    while index < ops.len() {
+        path.push(index);
+
+        if let Some((CallKind::Function, arg)) = CallKind::of(&ops[index])
+            && let Some(Value::Text { text, .. }) = ops[index].value(arg)
+            && text == name
+        {
+            spliced.push(path.clone());
+            ops.splice(index..=index, body.iter().cloned());
+            index += body.len();
+        } else {
+            spliced.extend(splice(&mut ops[index].operations, name, body, path));
+            index += 1;
+        }
+
+        path.pop();
+    }
+
+    spliced
+}
+
+/// Inlines tiny functions that are called from a single place and removes them. Modules
+/// without any event are left alone.
+pub fn inline(module: &mut Module) -> Vec<Change> {
+    let mut changes = Vec::new();
+    let mut removed = HashSet::new();
+
+    // Without events the module is a library, its functions may be called from elsewhere
+    if !(module.handlers.iter()).any(|line| line.line_type == LineTypeWants2Bits::Event) {
+        return changes;
+    }
+
+    loop {
+        let calls = CallGraph::build(module);
+
+        // A computed name could call any function
+        if (calls.calls.iter()).any(|call| call.kind == CallKind::Function && call.is_dynamic()) {
+            break;
+        }
+
+        let recursive: HashSet<usize> = calls.cycles().into_iter().flatten().collect();
+        let candidate = module
+            .handlers
+            .iter()
+            .enumerate()
+            .find_map(|(index, line)| {
+                let mut sites = (calls.calls.iter())
+                    .filter(|call| call.kind == CallKind::Function && call.callee == Some(index));
+                let site = sites.next()?;
+
+                (line.line_type == LineTypeWants2Bits::Function
+                    && sites.next().is_none()
+                    && site.caller != index
+                    && !recursive.contains(&index)
+                    && !removed.contains(&index)
+                    && size(&line.operations) <= INLINE_OPS
+                    && !exits(&line.operations))
+                .then_some((index, site.caller))
+            });
+
+        let Some((function, caller)) = candidate else {
+            break;
+        };
+
+        let name = module.handlers[function].name.clone().unwrap_or_default();
+        let body = std::mem::take(&mut module.handlers[function].operations);
+
+        for path in splice(
+            &mut module.handlers[caller].operations,
+            &name,
+            &body,
+            &mut Vec::new(),
+        ) {
+            changes.push(Change::new(
+                caller,
+                &path,
+                format!("inlined function {name}"),
+            ));
+        }
+
+        removed.insert(function);
+    }
+
+    let lines = std::mem::take(&mut module.handlers).into_iter().enumerate();
+    module.handlers = lines
+        .filter(|(index, _)| !removed.contains(index))
+        .map(|(_, line)| line)
+        .collect();
+
+    changes
+}
+
+/// Where a repeated sequence starts: the line, the path of the list it's in and its index there.
+type Site = (usize, Vec<usize>, usize);
+
+/// Moves op sequences that appear in several places into a new function and calls it from
+/// each of them. Existing functions whose whole body matches are called instead.
+pub fn outline(module: &mut Module) -> Vec<Change> {
+    let mut changes = Vec::new();
+    let mut created = 0;
+
+    while let Some((body, sites)) = best_sequence(module) {
+        let existing = module.handlers.iter().position(|line| {
+            line.line_type == LineTypeWants2Bits::Function && line.operations == body
+        });
+        let name = match existing {
+            Some(line) => module.handlers[line].name.clone().unwrap_or_default(),
+            None => {
+                let taken: HashSet<u8> = module.handlers.iter().map(|line| line.position).collect();
+                let Some(position) = (0..=u8::MAX).find(|position| !taken.contains(position))
+                else {
+                    break;
+                };
+                let name = loop {
+                    created += 1;
+                    let name = format!("Outlined{created}");
+
+                    if !(module.handlers.iter()).any(|line| line.name.as_ref() == Some(&name)) {
+                        break name;
+                    }
+                };
+
+                module.handlers.push(Line {
+                    line_type: LineTypeWants2Bits::Function,
+                    name: Some(name.clone()),
+                    event: None,
+                    position,
+                    operations: body.clone(),
+                    values: Vec::new(),
+                });
+                name
+            }
+        };
+
+        // Later sites first, so earlier indices stay valid
+        for (line, parent, start) in sites.into_iter().rev() {
+            if Some(line) == existing && parent.is_empty() {
+                continue;
+            }
+
+            let ops = ops_at(&mut module.handlers[line].operations, &parent);
+            ops.splice(start..start + body.len(), [call(&name)]);
+
+            let mut path = parent;
+            path.push(start);
+            changes.push(Change::new(
+                line,
+                &path,
+                format!("moved {} ops into function {name}", size(&body)),
+            ));
+        }
+    }
+
+    changes
+}
+
+/// Calls `f` with every op list, parents before the bodies inside them.
+fn lists<'m>(ops: &'m [Op], path: &mut Vec<usize>, f: &mut impl FnMut(&[usize], &'m [Op])) {
+    f(path, ops);
+
+    for (index, op) in ops.iter().enumerate() {
+        path.push(index);
+        lists(&op.operations, path, f);
+        path.pop();
+    }
+}
+
+/// The repeated sequence that saves the most ops when moved into a function, with the
+/// sites it can be replaced at in order.
+fn best_sequence(module: &Module) -> Option<(Vec<Op>, Vec<Site>)> {
+    let mut groups: Vec<(Vec<Op>, Vec<Site>)> = Vec::new();
+    let mut keys: HashMap<String, usize> = HashMap::new();
+
+    for (line, handler) in module.handlers.iter().enumerate() {
+        lists(&handler.operations, &mut Vec::new(), &mut |parent, ops| {
+            for start in 0..ops.len() {
+                for end in start + 1..=ops.len() {
+                    let window = &ops[start..end];
+
+                    // An else has to stay right after its condition
+                    if window[0].action == ActionIdWants11Bits::Else
+                        || ops
+                            .get(end)
+                            .is_some_and(|next| next.action == ActionIdWants11Bits::Else)
+                        || size(window) < OUTLINE_OPS
+                        || exits(window)
+                    {
+                        continue;
+                    }
+
+                    let key = serde_json::to_string(window).unwrap();
+                    let group = *keys.entry(key).or_insert_with(|| {
+                        groups.push((window.to_vec(), Vec::new()));
+                        groups.len() - 1
+                    });
+                    groups[group].1.push((line, parent.to_vec(), start));
+                }
+            }
+        });
+    }
+
+    let mut best: Option<(usize, Vec<Op>, Vec<Site>)> = None;
+
+    for (body, sites) in groups {
+        let len = body.len();
+        let mut chosen: Vec<Site> = Vec::new();
+
+        // Sites can't overlap or sit inside one another
+        for site in sites {
+            let clashes = chosen.iter().any(|(line, parent, start)| {
+                *line == site.0
+                    && site.1.starts_with(parent)
+                    && match site.1.get(parent.len()) {
+                        Some(index) => (*start..start + len).contains(index),
+                        None => site.2 < start + len,
+                    }
+            });
+
+            if !clashes {
+                chosen.push(site);
+            }
+        }
+
+        let ops = size(&body);
+        let saved = (chosen.len() * (ops - 1)).saturating_sub(ops);
+
+        if chosen.len() > 1 && saved > best.as_ref().map_or(0, |(saved, ..)| *saved) {
+            best = Some((saved, body, chosen));
+        }
+    }
+
+    best.map(|(_, body, sites)| (body, sites))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assets::Catalog, lang};
+
+    const SOURCE: &str = "
+function F {
+    call G
+}
+function G {
+    local.x = 1
+}
+";
+
+    fn inlined(source: &str) -> Module {
+        let mut module = lang::compile_source(source, &Catalog::load().unwrap()).unwrap();
+        inline(&mut module);
+        module
+    }
+
+    #[test]
+    fn inlines_into_the_only_caller() {
+        let module = inlined(&format!("{SOURCE}event player_join {{\n    call F\n}}\n"));
+
+        assert_eq!(module.handlers.len(), 1);
+        assert_eq!(
+            module.handlers[0].operations[0].action,
+            ActionIdWants11Bits::SetVariableValue
+        );
+    }
+
+    #[test]
+    fn keeps_library_functions() {
+        let module = inlined(SOURCE);
+
+        assert_eq!(module.handlers.len(), 2);
+        assert_eq!(
+            module.handlers[0].operations[0].action,
+            ActionIdWants11Bits::CallFunction
+        );
+    }
+}
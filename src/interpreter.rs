@@ -0,0 +1,1369 @@
+use std::{
+    collections::HashMap,
+    fmt::{self, Display},
+};
+
+use heck::{ToShoutySnakeCase, ToUpperCamelCase};
+
+use crate::{
+    assets::{Action, Catalog},
+    debugger::{Debugger, Step},
+    diagnostic::Diagnostic,
+    generated::{ActionIdWants11Bits, ActionObject, ArgType},
+    host::{Host, World},
+    module::{
+        LineTypeWants2Bits, Module, Number, Op, Selection, SelectionTypeWants5Bits, Value,
+        VariableScopeWants2Bits,
+    },
+    pretty::{render_op, render_value},
+    types::type_name,
+};
+
+/// Lambda variable assignments for each iteration of a loop.
+type Items = Box<dyn Iterator<Item = Vec<(&'static str, Val)>>>;
+
+/// A runtime value.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Val {
+    Number(f64),
+    Text(String),
+    List(Vec<Val>),
+    /// Entries in insertion order.
+    Map(Vec<(Val, Val)>),
+    Location {
+        x: f64,
+        y: f64,
+        z: f64,
+        yaw: f64,
+        pitch: f64,
+    },
+    Vector {
+        x: f64,
+        y: f64,
+        z: f64,
+    },
+    Item {
+        item: String,
+        amount: f64,
+        tags: Vec<(String, String)>,
+    },
+    Enum(String),
+    /// Blocks, particles, potions and sounds are passed around untouched.
+    Other(Value),
+}
+
+impl Val {
+    pub fn location(x: f64, y: f64, z: f64) -> Self {
+        Self::Location {
+            x,
+            y,
+            z,
+            yaw: 0.0,
+            pitch: 0.0,
+        }
+    }
+
+    /// The `variable_type` name `if_variable_is_type` checks against.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::Number(_) => "Number",
+            Self::Text(_) | Self::Enum(_) => "Text",
+            Self::List(_) => "Array",
+            Self::Map(_) => "Map",
+            Self::Location { .. } => "Location",
+            Self::Vector { .. } => "Vector",
+            Self::Item { .. } => "Item",
+            Self::Other(Value::Particle { .. }) => "Particle",
+            Self::Other(Value::Potion { .. }) => "Potion",
+            Self::Other(Value::Sound { .. }) => "Sound",
+            Self::Other(_) => "Block",
+        }
+    }
+
+    pub fn as_number(&self) -> Option<f64> {
+        match self {
+            Self::Number(number) => Some(*number),
+            Self::Text(text) => text.trim().parse().ok(),
+            _ => None,
+        }
+    }
+
+    /// Single values stand for one-element lists.
+    pub fn into_list(self) -> Vec<Val> {
+        match self {
+            Self::List(values) => values,
+            value => vec![value],
+        }
+    }
+}
+
+impl Display for Val {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let join = |values: &mut dyn Iterator<Item = String>| values.collect::<Vec<_>>().join(", ");
+
+        match self {
+            Self::Number(number) => write!(f, "{number}"),
+            Self::Text(text) | Self::Enum(text) => f.write_str(text),
+            Self::List(values) => write!(f, "[{}]", join(&mut values.iter().map(Val::to_string))),
+            Self::Map(entries) => write!(
+                f,
+                "{{{}}}",
+                join(&mut entries.iter().map(|(key, value)| format!("{key}={value}")))
+            ),
+            Self::Location {
+                x,
+                y,
+                z,
+                yaw,
+                pitch,
+            } => write!(f, "[{x}, {y}, {z}, {yaw}, {pitch}]"),
+            Self::Vector { x, y, z } => write!(f, "<{x}, {y}, {z}>"),
+            Self::Item { amount, tags, .. } => write!(
+                f,
+                "item x{amount}{{{}}}",
+                join(&mut tags.iter().map(|(name, value)| format!("{name}={value}")))
+            ),
+            Self::Other(value) => f.write_str(&render_value(value)),
+        }
+    }
+}
+
+pub type Locals = HashMap<String, Val>;
+
+/// Where an interpreted op lives and what went wrong. Thrown exceptions carry the id and
+/// type given to `call_exception`; interpreter faults are `FATAL` and can't be caught.
+#[derive(Debug, Clone)]
+pub struct RuntimeError {
+    pub diagnostic: Diagnostic,
+    pub id: String,
+    pub exception_type: String,
+}
+
+impl RuntimeError {
+    pub fn display<'a>(&'a self, module: &'a Module) -> impl Display + 'a {
+        self.diagnostic.display(module)
+    }
+}
+
+/// A `player`, `entity`, `world` or `select` action as it reached the host.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Traced {
+    pub line: usize,
+    pub path: Vec<usize>,
+    pub action: ActionIdWants11Bits,
+    pub selection: Option<SelectionTypeWants5Bits>,
+    /// Resolved arguments, sorted by name.
+    pub args: Vec<(String, Val)>,
+}
+
+impl Traced {
+    pub fn display<'a>(&'a self, catalog: &'a Catalog) -> impl Display + 'a {
+        DisplayTraced {
+            traced: self,
+            catalog,
+        }
+    }
+}
+
+struct DisplayTraced<'a> {
+    traced: &'a Traced,
+    catalog: &'a Catalog,
+}
+
+impl Display for DisplayTraced<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let action = self.catalog.action(self.traced.action);
+        write!(f, "{}.{}", type_name(action.object), action.name)?;
+
+        if let Some(selection) = self.traced.selection {
+            write!(f, "[{}]", type_name(selection))?;
+        }
+
+        let args: Vec<_> = self
+            .traced
+            .args
+            .iter()
+            .map(|(name, value)| format!("{name}: {value}"))
+            .collect();
+        write!(f, "({})", args.join(", "))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Flow {
+    Next,
+    StopRepeat,
+    SkipIteration,
+    Return,
+    /// `break` ends the whole thread.
+    End,
+}
+
+/// Resolved op arguments, with variable arguments also kept as references to write to.
+#[derive(Debug, Default)]
+pub struct Args {
+    pub values: HashMap<String, Val>,
+    pub targets: HashMap<String, (VariableScopeWants2Bits, String)>,
+}
+
+impl Args {
+    pub fn get(&self, name: &str) -> Option<&Val> {
+        self.values.get(name)
+    }
+
+    pub fn sorted(&self) -> Vec<(String, Val)> {
+        let mut values: Vec<_> = (self.values.iter())
+            .map(|(name, value)| (name.clone(), value.clone()))
+            .collect();
+        values.sort_by(|a, b| a.0.cmp(&b.0));
+        values
+    }
+
+    pub fn value(&self, name: &str) -> Result<&Val, String> {
+        self.get(name)
+            .ok_or_else(|| format!("missing argument {name}"))
+    }
+
+    pub fn number(&self, name: &str) -> Result<f64, String> {
+        let value = self.value(name)?;
+        value
+            .as_number()
+            .ok_or_else(|| format!("{name} should be a number, got {value}"))
+    }
+
+    pub fn number_or(&self, name: &str, default: f64) -> Result<f64, String> {
+        match self.get(name) {
+            Some(_) => self.number(name),
+            None => Ok(default),
+        }
+    }
+
+    pub fn numbers(&self, name: &str) -> Result<Vec<f64>, String> {
+        self.list(name)
+            .into_iter()
+            .map(|value| {
+                value
+                    .as_number()
+                    .ok_or_else(|| format!("{name} should hold numbers, got {value}"))
+            })
+            .collect()
+    }
+
+    pub fn text(&self, name: &str) -> Result<String, String> {
+        self.value(name).map(Val::to_string)
+    }
+
+    pub fn text_or(&self, name: &str, default: &str) -> String {
+        self.get(name)
+            .map_or_else(|| default.to_string(), Val::to_string)
+    }
+
+    pub fn list(&self, name: &str) -> Vec<Val> {
+        self.get(name)
+            .cloned()
+            .map(Val::into_list)
+            .unwrap_or_default()
+    }
+
+    pub fn map(&self, name: &str) -> Result<Vec<(Val, Val)>, String> {
+        match self.get(name) {
+            Some(Val::Map(entries)) => Ok(entries.clone()),
+            None => Ok(Vec::new()),
+            Some(value) => Err(format!("{name} should be a map, got {value}")),
+        }
+    }
+
+    /// Enum values in UpperCamelCase, whatever case the module exported them in.
+    pub fn choice(&self, name: &str, default: &str) -> String {
+        match self.get(name) {
+            Some(Val::Enum(value)) => value.to_upper_camel_case(),
+            _ => default.to_string(),
+        }
+    }
+
+    pub fn flag(&self, name: &str) -> bool {
+        self.choice(name, "False") == "True"
+    }
+
+    pub fn vector(&self, name: &str) -> Result<[f64; 3], String> {
+        match self.value(name)? {
+            Val::Vector { x, y, z } => Ok([*x, *y, *z]),
+            value => Err(format!("{name} should be a vector, got {value}")),
+        }
+    }
+
+    pub fn location(&self, name: &str) -> Result<[f64; 5], String> {
+        match self.value(name)? {
+            Val::Location {
+                x,
+                y,
+                z,
+                yaw,
+                pitch,
+            } => Ok([*x, *y, *z, *yaw, *pitch]),
+            value => Err(format!("{name} should be a location, got {value}")),
+        }
+    }
+}
+
+/// Executes modules without a server. Actions of the `variable`, `code`, `repeat` and
+/// `controller` families run here; the rest and game values go to the host.
+pub struct Interpreter<'m, H = World> {
+    pub module: &'m Module,
+    pub catalog: &'m Catalog,
+    pub host: H,
+    pub globals: HashMap<String, Val>,
+    pub saved: HashMap<String, Val>,
+    /// Game time advanced by `wait`, in ticks.
+    pub ticks: u64,
+    /// Ops, loop iterations and conditions evaluated in one run before giving up on a
+    /// runaway loop.
+    pub step_limit: usize,
+    pub steps: usize,
+    pub seed: u64,
+    /// Every action handed to the host, in order.
+    pub trace: Vec<Traced>,
+    pub debugger: Option<Debugger<'m>>,
+    /// Functions and processes currently entered.
+    depth: usize,
+    functions: HashMap<&'m str, usize>,
+    processes: HashMap<&'m str, usize>,
+    line: usize,
+    path: Vec<usize>,
+}
+
+impl<'m> Interpreter<'m> {
+    /// An interpreter over an empty stub world.
+    pub fn new(module: &'m Module, catalog: &'m Catalog) -> Self {
+        Self::with_host(module, catalog, World::default())
+    }
+}
+
+impl<'m, H: Host> Interpreter<'m, H> {
+    pub fn with_host(module: &'m Module, catalog: &'m Catalog, host: H) -> Self {
+        let mut functions = HashMap::new();
+        let mut processes = HashMap::new();
+
+        for (index, line) in module.handlers.iter().enumerate() {
+            match (line.line_type, &line.name) {
+                (LineTypeWants2Bits::Function, Some(name)) => {
+                    functions.insert(name.as_str(), index);
+                }
+                (LineTypeWants2Bits::Process, Some(name)) => {
+                    processes.insert(name.as_str(), index);
+                }
+                _ => {}
+            }
+        }
+
+        Self {
+            module,
+            catalog,
+            host,
+            globals: HashMap::new(),
+            saved: HashMap::new(),
+            ticks: 0,
+            step_limit: 1_000_000,
+            steps: 0,
+            seed: 0x2545_f491_4f6c_dd1d,
+            trace: Vec::new(),
+            debugger: None,
+            depth: 0,
+            functions,
+            processes,
+            line: 0,
+            path: Vec::new(),
+        }
+    }
+
+    /// Runs one line of the module with the given local variables.
+    pub fn run(&mut self, line: usize, locals: &mut Locals) -> Result<(), RuntimeError> {
+        self.steps = 0;
+        let saved = (self.line, std::mem::take(&mut self.path));
+        self.line = line;
+        let module = self.module;
+        let result = self.exec_ops(&module.handlers[line].operations, locals);
+        (self.line, self.path) = saved;
+        result.map(|_| ())
+    }
+
+    pub fn call_function(&mut self, name: &str, locals: &mut Locals) -> Result<(), RuntimeError> {
+        match self.functions.get(name) {
+            Some(&line) => self.run(line, locals),
+            None => self.fail(format!("call to undefined function {name}")),
+        }
+    }
+
+    /// Runs the function called `name`, or else the process.
+    pub fn run_named(&mut self, name: &str, locals: &mut Locals) -> Result<(), RuntimeError> {
+        match self.functions.get(name).or(self.processes.get(name)) {
+            Some(&line) => self.run(line, locals),
+            None => self.fail(format!("no function or process called {name}")),
+        }
+    }
+
+    pub fn var<'a>(
+        &'a self,
+        scope: VariableScopeWants2Bits,
+        name: &str,
+        locals: &'a Locals,
+    ) -> Option<&'a Val> {
+        match scope {
+            VariableScopeWants2Bits::Local => locals.get(name),
+            VariableScopeWants2Bits::Global => self.globals.get(name),
+            VariableScopeWants2Bits::Save => self.saved.get(name),
+        }
+    }
+
+    pub fn set_var(
+        &mut self,
+        (scope, name): &(VariableScopeWants2Bits, String),
+        value: Option<Val>,
+        locals: &mut Locals,
+    ) {
+        let variables = match scope {
+            VariableScopeWants2Bits::Local => locals,
+            VariableScopeWants2Bits::Global => &mut self.globals,
+            VariableScopeWants2Bits::Save => &mut self.saved,
+        };
+
+        match value {
+            Some(value) => variables.insert(name.clone(), value),
+            None => variables.remove(name),
+        };
+    }
+
+    fn step(&mut self) -> Result<(), RuntimeError> {
+        self.steps += 1;
+
+        match self.steps > self.step_limit {
+            true => self.fail(format!("gave up after {} steps", self.step_limit)),
+            false => Ok(()),
+        }
+    }
+
+    pub fn error(&self, message: impl Into<String>) -> RuntimeError {
+        RuntimeError {
+            diagnostic: Diagnostic::error(self.line, &self.path, message),
+            id: "interpreter".to_string(),
+            exception_type: "Fatal".to_string(),
+        }
+    }
+
+    pub fn fail<T>(&self, message: impl Into<String>) -> Result<T, RuntimeError> {
+        Err(self.error(message))
+    }
+
+    /// A pseudo random number in `0..1`, reproducible through `seed`.
+    pub fn random(&mut self) -> f64 {
+        self.seed ^= self.seed << 13;
+        self.seed ^= self.seed >> 7;
+        self.seed ^= self.seed << 17;
+        (self.seed >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    pub fn exec_ops(&mut self, ops: &'m [Op], locals: &mut Locals) -> Result<Flow, RuntimeError> {
+        let mut condition = None;
+
+        for (index, op) in ops.iter().enumerate() {
+            self.path.push(index);
+            let flow = self.exec_op(op, &mut condition, locals);
+            self.path.pop();
+
+            match flow? {
+                Flow::Next => {}
+                flow => return Ok(flow),
+            }
+        }
+
+        Ok(Flow::Next)
+    }
+
+    /// `condition` is the result of the previous op if it was a condition, for `else`.
+    fn exec_op(
+        &mut self,
+        op: &'m Op,
+        condition: &mut Option<bool>,
+        locals: &mut Locals,
+    ) -> Result<Flow, RuntimeError> {
+        self.step()?;
+
+        let action = self.catalog.action(op.action);
+        let previous = condition.take();
+        let args = self.args(op, locals)?;
+
+        if let Some(debugger) = &mut self.debugger {
+            let step = Step {
+                line: self.line,
+                path: self.path.clone(),
+                depth: self.depth,
+                op: render_op(op, self.catalog),
+                args: args.sorted(),
+            };
+
+            if !debugger.before(self.module, step, locals, &self.globals, &self.saved) {
+                return self.fail("stopped in the debugger");
+            }
+        }
+
+        if op.action == ActionIdWants11Bits::Else {
+            return match previous {
+                Some(false) => self.exec_ops(&op.operations, locals),
+                _ => Ok(Flow::Next),
+            };
+        }
+
+        if action.boolean {
+            let selection = op.selection.map(|selection| selection.selection_type);
+            let result = self.condition(action, selection, &args, locals)? != op.is_inverted;
+            *condition = Some(result);
+
+            return match result {
+                true => self.exec_ops(&op.operations, locals),
+                false => Ok(Flow::Next),
+            };
+        }
+
+        match action.object {
+            ActionObject::Variable => {
+                self.variable_action(action, &args, locals)
+                    .map_err(|message| self.error(message))?;
+                Ok(Flow::Next)
+            }
+            ActionObject::Code => self.code_action(action, &args, locals),
+            ActionObject::Repeat => self.repeat_action(action, op, args, locals),
+            ActionObject::Controller => self.controller_action(action, op, &args, locals),
+            _ => {
+                let selection = op.selection.map(|selection| selection.selection_type);
+                self.trace.push(Traced {
+                    line: self.line,
+                    path: self.path.clone(),
+                    action: action.id,
+                    selection,
+                    args: args.sorted(),
+                });
+
+                let assigned = self
+                    .host
+                    .action(action, selection, &args)
+                    .map_err(|message| self.error(message))?;
+
+                for (name, value) in assigned {
+                    if let Some(target) = args.targets.get(&name) {
+                        self.set_var(target, Some(value), locals);
+                    }
+                }
+
+                Ok(Flow::Next)
+            }
+        }
+    }
+
+    pub fn condition(
+        &mut self,
+        action: &Action,
+        selection: Option<SelectionTypeWants5Bits>,
+        args: &Args,
+        locals: &Locals,
+    ) -> Result<bool, RuntimeError> {
+        self.step()?;
+
+        let result = match action.object {
+            ActionObject::Variable => self.variable_condition(action, args, locals),
+            _ => self.host.condition(action, selection, args),
+        };
+
+        result.map_err(|message| self.error(message))
+    }
+
+    /// Resolves every value of the op. Array arguments spread list values into one list.
+    pub fn args(&mut self, op: &Op, locals: &Locals) -> Result<Args, RuntimeError> {
+        let mut args = Args::default();
+
+        for value in &op.values {
+            let owner = self.catalog.arg_owner(op, &value.name);
+            let arg = owner.arg(&value.name);
+
+            if let Value::Variable { variable, scope } = &value.value
+                && (owner.assigns(&value.name)
+                    || arg.is_some_and(|arg| arg.arg_type == ArgType::Variable))
+            {
+                args.targets
+                    .insert(value.name.clone(), (*scope, variable.clone()));
+            }
+
+            let resolved = if arg.is_some_and(|arg| arg.array.is_some()) {
+                let elements = match &value.value {
+                    Value::Array { values } => values.iter().collect(),
+                    value => vec![value],
+                };
+                let mut list = Vec::new();
+
+                for element in elements {
+                    if let Some(element) = self.resolve(element, locals)? {
+                        list.extend(element.into_list());
+                    }
+                }
+
+                Some(Val::List(list))
+            } else {
+                self.resolve(&value.value, locals)?
+            };
+
+            if let Some(resolved) = resolved {
+                args.values.insert(value.name.clone(), resolved);
+            }
+        }
+
+        Ok(args)
+    }
+
+    pub fn resolve(&mut self, value: &Value, locals: &Locals) -> Result<Option<Val>, RuntimeError> {
+        Ok(Some(match value {
+            Value::Array { values } => {
+                let mut list = Vec::new();
+
+                for value in values {
+                    list.extend(self.resolve(value, locals)?);
+                }
+
+                Val::List(list)
+            }
+            Value::Enum { value } => Val::Enum(value.clone()),
+            Value::Item { item } => Val::Item {
+                item: item.clone(),
+                amount: 1.0,
+                tags: Vec::new(),
+            },
+            Value::Location {
+                x,
+                y,
+                z,
+                yaw,
+                pitch,
+            } => Val::Location {
+                x: *x,
+                y: *y,
+                z: *z,
+                yaw: *yaw,
+                pitch: *pitch,
+            },
+            Value::Number {
+                number: Number::Simple(number),
+            } => Val::Number(*number),
+            Value::Number {
+                number: Number::Calc(expression),
+            } => {
+                let text = self.expand(expression, locals)?;
+
+                match text.trim().parse() {
+                    Ok(number) => Val::Number(number),
+                    Err(_) => return self.fail(format!("{expression} gives {text}, not a number")),
+                }
+            }
+            Value::Text { text, .. } => Val::Text(self.expand(text, locals)?),
+            Value::Variable { variable, scope } => {
+                return Ok(self.var(*scope, variable, locals).cloned());
+            }
+            Value::Vector { x, y, z } => Val::Vector {
+                x: *x,
+                y: *y,
+                z: *z,
+            },
+            Value::GameValue {
+                game_value,
+                selection,
+            } => {
+                let selection = serde_json::from_str::<Selection>(selection)
+                    .map_or(SelectionTypeWants5Bits::Default, |selection| {
+                        selection.selection_type
+                    });
+
+                match self.host.game_value(*game_value, selection) {
+                    Ok(value) => value,
+                    Err(message) => return self.fail(message),
+                }
+            }
+            Value::Error { .. } => return Ok(None),
+            other => Val::Other(other.clone()),
+        }))
+    }
+
+    /// Substitutes `%var_local(..)`, `%var(..)`, `%var_save(..)`, `%math(..)` and `%random%`.
+    /// Unknown placeholders are left as they are.
+    pub fn expand(&mut self, text: &str, locals: &Locals) -> Result<String, RuntimeError> {
+        let mut out = String::new();
+        let mut rest = text;
+
+        while let Some(start) = rest.find('%') {
+            out.push_str(&rest[..start]);
+            rest = &rest[start + 1..];
+
+            let name_len = rest
+                .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+                .unwrap_or(rest.len());
+            let name = &rest[..name_len];
+
+            match rest[name_len..].chars().next() {
+                Some('(') => {
+                    let mut depth = 0;
+                    let Some(end) = rest[name_len..].char_indices().find_map(|(i, c)| {
+                        match c {
+                            '(' => depth += 1,
+                            ')' => depth -= 1,
+                            _ => {}
+                        }
+                        (depth == 0).then_some(name_len + i)
+                    }) else {
+                        out.push('%');
+                        continue;
+                    };
+
+                    let inner = self.expand(&rest[name_len + 1..end], locals)?;
+                    let scope = match name {
+                        "var_local" => Some(VariableScopeWants2Bits::Local),
+                        "var" => Some(VariableScopeWants2Bits::Global),
+                        "var_save" => Some(VariableScopeWants2Bits::Save),
+                        _ => None,
+                    };
+
+                    match (scope, name) {
+                        (Some(scope), _) => {
+                            let found = self.var(scope, &inner, locals);
+                            out.push_str(&found.map(Val::to_string).unwrap_or_default());
+                        }
+                        (None, "math") => match eval_math(&inner) {
+                            Ok(number) => out.push_str(&number.to_string()),
+                            Err(message) => return self.fail(format!("%math({inner}): {message}")),
+                        },
+                        (None, _) => {
+                            out.push('%');
+                            out.push_str(name);
+                            out.push('(');
+                            out.push_str(&inner);
+                            out.push(')');
+                        }
+                    }
+
+                    rest = &rest[end + 1..];
+                }
+                Some('%') if name == "random" => {
+                    out.push_str(&self.random().to_string());
+                    rest = &rest[name_len + 1..];
+                }
+                _ => out.push('%'),
+            }
+        }
+
+        out.push_str(rest);
+        Ok(out)
+    }
+
+    fn code_action(
+        &mut self,
+        action: &Action,
+        args: &Args,
+        locals: &mut Locals,
+    ) -> Result<Flow, RuntimeError> {
+        let text = |name: &str| args.text(name).map_err(|message| self.error(message));
+
+        match action.id {
+            ActionIdWants11Bits::CallFunction => {
+                let name = text("function_name")?;
+                let Some(&line) = self.functions.get(name.as_str()) else {
+                    return self.fail(format!("call to undefined function {name}"));
+                };
+
+                self.enter(line, locals)
+            }
+            ActionIdWants11Bits::StartProcess => {
+                let name = text("process_name")?;
+                let Some(&line) = self.processes.get(name.as_str()) else {
+                    return self.fail(format!("start of undefined process {name}"));
+                };
+
+                // Processes run to completion at the point they are started
+                match args.choice("local_variables_mode", "DontCopy").as_str() {
+                    "Share" => self.enter(line, locals)?,
+                    "Copy" => self.enter(line, &mut locals.clone())?,
+                    _ => self.enter(line, &mut Locals::new())?,
+                };
+
+                Ok(Flow::Next)
+            }
+            ActionIdWants11Bits::ControlCallException => Err(RuntimeError {
+                diagnostic: Diagnostic::error(self.line, &self.path, args.text_or("message", "")),
+                id: args.text_or("id", ""),
+                exception_type: args.choice("type", "Error"),
+            }),
+            ActionIdWants11Bits::ControlEndThread => Ok(Flow::End),
+            ActionIdWants11Bits::ControlReturnFunction => Ok(Flow::Return),
+            ActionIdWants11Bits::ControlSkipIteration => Ok(Flow::SkipIteration),
+            ActionIdWants11Bits::ControlStopRepeat => Ok(Flow::StopRepeat),
+            ActionIdWants11Bits::ControlWait => {
+                let duration = args
+                    .number_or("duration", 1.0)
+                    .map_err(|message| self.error(message))?;
+                let unit = match args.choice("time_unit", "Ticks").as_str() {
+                    "Seconds" => 20.0,
+                    "Minutes" => 1200.0,
+                    _ => 1.0,
+                };
+
+                self.ticks += (duration * unit).max(0.0) as u64;
+                Ok(Flow::Next)
+            }
+            _ => Ok(Flow::Next),
+        }
+    }
+
+    /// Runs a function or process body in place of the current line.
+    fn enter(&mut self, line: usize, locals: &mut Locals) -> Result<Flow, RuntimeError> {
+        let saved = (self.line, std::mem::take(&mut self.path));
+        self.line = line;
+        self.depth += 1;
+        let module = self.module;
+        let flow = self.exec_ops(&module.handlers[line].operations, locals);
+        self.depth -= 1;
+        (self.line, self.path) = saved;
+
+        match flow? {
+            Flow::End => Ok(Flow::End),
+            _ => Ok(Flow::Next),
+        }
+    }
+
+    /// Runs a loop body once. `Some` ends the loop with that flow.
+    fn iteration(
+        &mut self,
+        body: &'m [Op],
+        locals: &mut Locals,
+    ) -> Result<Option<Flow>, RuntimeError> {
+        self.step()?;
+
+        Ok(match self.exec_ops(body, locals)? {
+            Flow::Next | Flow::SkipIteration => None,
+            Flow::StopRepeat => Some(Flow::Next),
+            flow => Some(flow),
+        })
+    }
+
+    fn repeat_action(
+        &mut self,
+        action: &Action,
+        op: &'m Op,
+        args: Args,
+        locals: &mut Locals,
+    ) -> Result<Flow, RuntimeError> {
+        let body = &op.operations;
+
+        if action.id == ActionIdWants11Bits::RepeatWhile {
+            let Some(conditional) = op.conditional else {
+                return self.fail("repeat while needs a condition");
+            };
+            let inner = self.catalog.action(conditional.action);
+            let mut args = args;
+
+            let selection = op.selection.map(|selection| selection.selection_type);
+
+            while self.condition(inner, selection, &args, locals)? != conditional.is_inverted {
+                if let Some(flow) = self.iteration(body, locals)? {
+                    return Ok(flow);
+                }
+
+                args = self.args(op, locals)?;
+            }
+
+            return Ok(Flow::Next);
+        }
+
+        if action.id == ActionIdWants11Bits::RepeatForever {
+            loop {
+                if let Some(flow) = self.iteration(body, locals)? {
+                    return Ok(flow);
+                }
+            }
+        }
+
+        let items = self
+            .repeat_items(action, &args)
+            .map_err(|message| self.error(message))?;
+
+        for assignments in items {
+            for (name, value) in assignments {
+                if let Some(target) = args.targets.get(name) {
+                    self.set_var(target, Some(value), locals);
+                }
+            }
+
+            if let Some(flow) = self.iteration(body, locals)? {
+                return Ok(flow);
+            }
+        }
+
+        Ok(Flow::Next)
+    }
+
+    /// What each iteration of a bounded loop assigns to its lambda variables, made as the
+    /// loop goes so huge bounds only cost steps.
+    fn repeat_items(&mut self, action: &Action, args: &Args) -> Result<Items, String> {
+        fn each(values: impl Iterator<Item = Val> + 'static) -> Items {
+            Box::new(values.map(|value| vec![("variable", value)]))
+        }
+
+        Ok(match action.id {
+            ActionIdWants11Bits::RepeatMultiTimes => {
+                let amount = args.number("amount")?;
+                each((1..=amount.max(0.0) as u64).map(|i| Val::Number(i as f64)))
+            }
+            ActionIdWants11Bits::RepeatOnRange => {
+                let (start, end) = (args.number("start")?, args.number("end")?);
+                let interval = args.number_or("interval", 1.0)?.abs();
+
+                if interval == 0.0 {
+                    return Err("interval can't be 0".to_string());
+                }
+
+                let count = ((end - start).abs() / interval).floor() as u64;
+                let sign = if end < start { -1.0 } else { 1.0 };
+                each((0..=count).map(move |i| Val::Number(start + sign * interval * i as f64)))
+            }
+            ActionIdWants11Bits::RepeatForEachInList => {
+                Box::new(args.list("list").into_iter().enumerate().map(|(i, value)| {
+                    vec![
+                        ("index_variable", Val::Number(i as f64)),
+                        ("value_variable", value),
+                    ]
+                }))
+            }
+            ActionIdWants11Bits::RepeatForEachMapEntry => Box::new(
+                args.map("map")?
+                    .into_iter()
+                    .map(|(key, value)| vec![("key_variable", key), ("value_variable", value)]),
+            ),
+            ActionIdWants11Bits::RepeatOnCircle => {
+                let [x, y, z, ..] = args.location("center")?;
+                let radius = args.number("radius")?;
+                let points = args.number_or("circle_points", 16.0)?.max(1.0) as u64;
+                let mut start = args.number_or("start_angle", 0.0)?;
+
+                if args.choice("angle_unit", "Degrees") == "Degrees" {
+                    start = start.to_radians();
+                }
+
+                each((0..points).map(move |i| {
+                    let angle = start + std::f64::consts::TAU * i as f64 / points as f64;
+                    Val::location(x + radius * angle.cos(), y, z + radius * angle.sin())
+                }))
+            }
+            ActionIdWants11Bits::RepeatOnSphere => {
+                let [x, y, z, ..] = args.location("center")?;
+                let radius = args.number("radius")?;
+                let points = args.number_or("points", 16.0)?.max(1.0) as u64;
+                let golden = std::f64::consts::PI * (3.0 - 5f64.sqrt());
+
+                // Fibonacci lattice, evenly spread over the sphere
+                each((0..points).map(move |i| {
+                    let height = 1.0 - 2.0 * (i as f64 + 0.5) / points as f64;
+                    let ring = (1.0 - height * height).sqrt();
+                    let angle = golden * i as f64;
+                    Val::location(
+                        x + radius * ring * angle.cos(),
+                        y + radius * height,
+                        z + radius * ring * angle.sin(),
+                    )
+                }))
+            }
+            ActionIdWants11Bits::RepeatOnGrid => {
+                let [x1, y1, z1, ..] = args.location("start")?;
+                let [x2, y2, z2, ..] = args.location("end")?;
+                let range = |a: f64, b: f64| {
+                    let (low, high) = (a.min(b).floor() as i64, a.max(b).floor() as i64);
+                    low..=high
+                };
+                let (xs, ys, zs) = (range(x1, x2), range(y1, y2), range(z1, z2));
+
+                each(ys.flat_map(move |y| {
+                    let xs = xs.clone();
+                    zs.clone().flat_map(move |z| {
+                        xs.clone()
+                            .map(move |x| Val::location(x as f64, y as f64, z as f64))
+                    })
+                }))
+            }
+            ActionIdWants11Bits::RepeatOnPath => {
+                let step = args.number_or("step", 1.0)?;
+
+                if step <= 0.0 {
+                    return Err("step must be positive".to_string());
+                }
+
+                let points: Vec<[f64; 3]> = args
+                    .list("locations")
+                    .into_iter()
+                    .filter_map(|value| match value {
+                        Val::Location { x, y, z, .. } => Some([x, y, z]),
+                        _ => None,
+                    })
+                    .collect();
+                let segments: Vec<_> = points.windows(2).map(|pair| (pair[0], pair[1])).collect();
+                let last = points.last().map(|&[x, y, z]| Val::location(x, y, z));
+
+                each(
+                    segments
+                        .into_iter()
+                        .flat_map(move |(a, b)| {
+                            let length = ((b[0] - a[0]).powi(2)
+                                + (b[1] - a[1]).powi(2)
+                                + (b[2] - a[2]).powi(2))
+                            .sqrt();
+                            let steps = (length / step).floor() as u64;
+
+                            (0..steps.max(1)).map(move |i| {
+                                let t = if length == 0.0 {
+                                    0.0
+                                } else {
+                                    i as f64 * step / length
+                                };
+                                Val::location(
+                                    a[0] + (b[0] - a[0]) * t,
+                                    a[1] + (b[1] - a[1]) * t,
+                                    a[2] + (b[2] - a[2]) * t,
+                                )
+                            })
+                        })
+                        .chain(last),
+                )
+            }
+            ActionIdWants11Bits::RepeatAdjacently => {
+                let [x, y, z, ..] = args.location("origin")?;
+                let pattern = args.choice("pattern", "Adjacent");
+                let mut locations = Vec::new();
+
+                for dy in -1i32..=1 {
+                    for dz in -1i32..=1 {
+                        for dx in -1i32..=1 {
+                            let distance = dx.abs() + dy.abs() + dz.abs();
+                            let keep = match pattern.as_str() {
+                                "Cardinal" => dy == 0 && distance == 1,
+                                "Square" => dy == 0 && distance > 0,
+                                "Cube" => distance > 0,
+                                _ => distance == 1,
+                            } || (distance == 0 && args.flag("include_self"));
+
+                            if keep {
+                                locations.push(Val::location(
+                                    x.floor() + dx as f64,
+                                    y.floor() + dy as f64,
+                                    z.floor() + dz as f64,
+                                ));
+                            }
+                        }
+                    }
+                }
+
+                each(locations.into_iter())
+            }
+            _ => Box::new(std::iter::empty()),
+        })
+    }
+
+    fn controller_action(
+        &mut self,
+        action: &Action,
+        op: &'m Op,
+        args: &Args,
+        locals: &mut Locals,
+    ) -> Result<Flow, RuntimeError> {
+        let body = &op.operations;
+
+        match action.id {
+            ActionIdWants11Bits::ControllerAsyncRun => {
+                self.exec_ops(body, locals)?;
+                Ok(Flow::Next)
+            }
+            ActionIdWants11Bits::ControllerException => {
+                let catches = args.choice("exception_type", "All");
+
+                match self.exec_ops(body, locals) {
+                    Err(error)
+                        if error.exception_type != "Fatal"
+                            && (catches == "All" || catches == error.exception_type) =>
+                    {
+                        if let Some(target) = args.targets.get("variable") {
+                            let exception = Val::Map(vec![
+                                (Val::Text("id".to_string()), Val::Text(error.id)),
+                                (
+                                    Val::Text("message".to_string()),
+                                    Val::Text(error.diagnostic.message),
+                                ),
+                                (
+                                    Val::Text("type".to_string()),
+                                    Val::Enum(error.exception_type.to_shouty_snake_case()),
+                                ),
+                            ]);
+                            self.set_var(target, Some(exception), locals);
+                        }
+
+                        Ok(Flow::Next)
+                    }
+                    result => result,
+                }
+            }
+            ActionIdWants11Bits::ControllerMeasureTime => {
+                let start = self.ticks;
+                let flow = self.exec_ops(body, locals)?;
+                let millis = (self.ticks - start) as f64 * 50.0;
+                let elapsed = match args.choice("duration", "Milliseconds").as_str() {
+                    "Microseconds" => millis * 1e3,
+                    "Nanoseconds" => millis * 1e6,
+                    _ => millis,
+                };
+
+                if let Some(target) = args.targets.get("variable") {
+                    self.set_var(target, Some(Val::Number(elapsed)), locals);
+                }
+
+                Ok(flow)
+            }
+            _ => Ok(Flow::Next),
+        }
+    }
+}
+
+/// Evaluates the arithmetic inside `%math(..)`: numbers, `+ - * / %`, `^` and parentheses.
+pub fn eval_math(expression: &str) -> Result<f64, String> {
+    struct Parser<'a> {
+        chars: std::iter::Peekable<std::str::Chars<'a>>,
+    }
+
+    impl Parser<'_> {
+        fn skip(&mut self) {
+            while self.chars.peek().is_some_and(|c| c.is_whitespace()) {
+                self.chars.next();
+            }
+        }
+
+        fn eat(&mut self, c: char) -> bool {
+            self.skip();
+            let found = self.chars.peek() == Some(&c);
+            if found {
+                self.chars.next();
+            }
+            found
+        }
+
+        fn sum(&mut self) -> Result<f64, String> {
+            let mut value = self.product()?;
+
+            loop {
+                if self.eat('+') {
+                    value += self.product()?;
+                } else if self.eat('-') {
+                    value -= self.product()?;
+                } else {
+                    return Ok(value);
+                }
+            }
+        }
+
+        fn product(&mut self) -> Result<f64, String> {
+            let mut value = self.power()?;
+
+            loop {
+                if self.eat('*') {
+                    value *= self.power()?;
+                } else if self.eat('/') {
+                    value /= self.power()?;
+                } else if self.eat('%') {
+                    value %= self.power()?;
+                } else {
+                    return Ok(value);
+                }
+            }
+        }
+
+        fn power(&mut self) -> Result<f64, String> {
+            let base = self.unary()?;
+
+            if self.eat('^') {
+                Ok(base.powf(self.power()?))
+            } else {
+                Ok(base)
+            }
+        }
+
+        fn unary(&mut self) -> Result<f64, String> {
+            if self.eat('-') {
+                return Ok(-self.unary()?);
+            }
+
+            if self.eat('(') {
+                let value = self.sum()?;
+                return match self.eat(')') {
+                    true => Ok(value),
+                    false => Err("missing )".to_string()),
+                };
+            }
+
+            let mut number = String::new();
+
+            while let Some(c) = self
+                .chars
+                .peek()
+                .filter(|c| c.is_ascii_digit() || **c == '.')
+            {
+                number.push(*c);
+                self.chars.next();
+            }
+
+            number
+                .parse()
+                .map_err(|_| format!("expected a number, found {:?}", self.chars.peek()))
+        }
+    }
+
+    let mut parser = Parser {
+        chars: expression.chars().peekable(),
+    };
+    let value = parser.sum()?;
+    parser.skip();
+
+    match parser.chars.next() {
+        None => Ok(value),
+        Some(c) => Err(format!("unexpected {c:?}")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONVERT: &str = "
+function ToText {
+    local.result = variable.convert_number_to_text(number: local.number, radix: local.radix)
+}
+function ToNumber {
+    local.result = variable.convert_text_to_number(text: local.text, radix: local.radix)
+}
+function Repeat {
+    local.result = variable.repeat_text(text: local.text, repeat: local.times)
+}
+";
+
+    fn run(module: &Module, name: &str, locals: &[(&str, Val)]) -> Result<Locals, RuntimeError> {
+        let catalog = Catalog::load().unwrap();
+        let mut interpreter = Interpreter::new(module, &catalog);
+        let mut locals: Locals = (locals.iter())
+            .map(|(name, value)| (name.to_string(), value.clone()))
+            .collect();
+        interpreter.run_named(name, &mut locals)?;
+        Ok(locals)
+    }
+
+    fn compile(source: &str) -> Module {
+        crate::lang::compile_source(source, &Catalog::load().unwrap()).unwrap()
+    }
+
+    fn convert(name: &str, locals: &[(&str, Val)]) -> Result<Val, RuntimeError> {
+        Ok(run(&compile(CONVERT), name, locals)?["result"].clone())
+    }
+
+    fn gives_up(source: &str) {
+        let message = run(&compile(source), "Loop", &[])
+            .unwrap_err()
+            .diagnostic
+            .message;
+        assert!(message.starts_with("gave up after"), "{message}");
+    }
+
+    #[test]
+    fn death_points() {
+        let module: Module =
+            serde_json::from_str(&std::fs::read_to_string("pr1.json").unwrap()).unwrap();
+        let locals = run(
+            &module,
+            "DeathPointsGen",
+            &[("player_pts", Val::Number(100.0))],
+        );
+        let drops = [30.0, 15.0, 3.0, 1.0, 1.0].map(Val::Number).to_vec();
+
+        assert_eq!(locals.unwrap()["PointDropList"], Val::List(drops));
+    }
+
+    #[test]
+    fn radix_conversions() {
+        let to_text = [
+            ("number", Val::Number(-255.0)),
+            ("radix", Val::Number(16.0)),
+        ];
+        let to_number = [
+            ("text", Val::Text("zz".to_string())),
+            ("radix", Val::Number(36.0)),
+        ];
+
+        assert_eq!(
+            convert("ToText", &to_text).unwrap(),
+            Val::Text("-ff".to_string())
+        );
+        assert_eq!(
+            convert("ToNumber", &to_number).unwrap(),
+            Val::Number(1295.0)
+        );
+    }
+
+    #[test]
+    fn invalid_radix() {
+        for radix in [0.0, 1.0, 2.5, 37.0, 40.0] {
+            let to_text = [("number", Val::Number(5.0)), ("radix", Val::Number(radix))];
+            let to_number = [
+                ("text", Val::Text("5".to_string())),
+                ("radix", Val::Number(radix)),
+            ];
+
+            for result in [convert("ToText", &to_text), convert("ToNumber", &to_number)] {
+                let message = result.unwrap_err().diagnostic.message;
+                assert!(
+                    message.contains("radix should be a whole number"),
+                    "{message}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn huge_repeat_text() {
+        let text = ("text", Val::Text("ab".to_string()));
+
+        assert_eq!(
+            convert("Repeat", &[text.clone(), ("times", Val::Number(3.0))]).unwrap(),
+            Val::Text("ababab".to_string())
+        );
+
+        let message = convert("Repeat", &[text, ("times", Val::Number(1e18))])
+            .unwrap_err()
+            .diagnostic
+            .message;
+        assert!(message.contains("longer than"), "{message}");
+    }
+
+    #[test]
+    fn empty_forever_loop() {
+        gives_up("function Loop {\n    repeat forever() {\n    }\n}\n");
+    }
+
+    #[test]
+    fn huge_loop_bounds() {
+        let loops = [
+            "multi_times(variable: local.i, amount: 1e18)",
+            "on_range(variable: local.i, start: 0, end: 1e18)",
+            "on_grid(variable: local.i, start: loc(0, 0, 0, 0, 0), end: loc(1e6, 1e6, 1e6, 0, 0))",
+        ];
+
+        for repeat in loops {
+            gives_up(&format!(
+                "function Loop {{\n    repeat {repeat} {{\n    }}\n}}\n"
+            ));
+        }
+    }
+}
@@ -0,0 +1,1170 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::{self, Display},
+};
+
+use heck::ToShoutySnakeCase;
+
+use crate::{
+    assets::{Action, Catalog},
+    generated::{ActionIdWants11Bits, ActionType, EventId, SoundId},
+    module::{
+        Conditional, Line, LineTypeWants2Bits, Module, NamedValue, Number, Op, Selection,
+        SoundSourceWants4Bits, TextParsingWants2Bits, Value,
+    },
+    types::type_name,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Span {
+    pub line: usize,
+    pub column: usize,
+}
+
+#[derive(Debug, Clone)]
+pub struct SyntaxError {
+    pub span: Span,
+    pub message: String,
+}
+
+impl Display for SyntaxError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}:{}: {}",
+            self.span.line, self.span.column, self.message
+        )
+    }
+}
+
+impl std::error::Error for SyntaxError {}
+
+fn error<T>(span: Span, message: impl Into<String>) -> Result<T, SyntaxError> {
+    Err(SyntaxError {
+        span,
+        message: message.into(),
+    })
+}
+
+/// Parses and lowers source text into a module, checking every action against the catalog.
+pub fn compile_source(source: &str, catalog: &Catalog) -> Result<Module, SyntaxError> {
+    let tokens = lex(source)?;
+    let items = Parser { tokens, next: 0 }.items()?;
+    Lowering::new(catalog).module(items)
+}
+
+// Lexer
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TokenKind {
+    Ident(String),
+    Number(f64),
+    Text(String),
+    /// Placeholder expressions such as `%math(%var_local(len)-1)`, kept verbatim.
+    Calc(String),
+    Punct(&'static str),
+    Eof,
+}
+
+impl Display for TokenKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Ident(ident) => write!(f, "`{ident}`"),
+            Self::Number(number) => write!(f, "`{number}`"),
+            Self::Text(text) => write!(f, "{text:?}"),
+            Self::Calc(calc) => write!(f, "`{calc}`"),
+            Self::Punct(punct) => write!(f, "`{punct}`"),
+            Self::Eof => f.write_str("end of file"),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Token {
+    pub kind: TokenKind,
+    pub span: Span,
+}
+
+const PUNCTS: [&str; 17] = [
+    "==", "!=", ">=", "<=", ">", "<", "=", "{", "}", "(", ")", "[", "]", ",", ":", ".", "?",
+];
+
+pub fn lex(source: &str) -> Result<Vec<Token>, SyntaxError> {
+    let chars: Vec<char> = source.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    let mut span = Span { line: 1, column: 1 };
+
+    let advance = |i: &mut usize, span: &mut Span, count: usize| {
+        for _ in 0..count {
+            if chars[*i] == '\n' {
+                span.line += 1;
+                span.column = 1;
+            } else {
+                span.column += 1;
+            }
+            *i += 1;
+        }
+    };
+
+    while i < chars.len() {
+        let c = chars[i];
+        let start = span;
+
+        if c.is_whitespace() {
+            advance(&mut i, &mut span, 1);
+            continue;
+        }
+
+        if c == '/' && chars.get(i + 1) == Some(&'/') {
+            while i < chars.len() && chars[i] != '\n' {
+                advance(&mut i, &mut span, 1);
+            }
+            continue;
+        }
+
+        // Digits right after a dot of a dotted name, as in `music_disc.11`, are part of it
+        let in_name = i >= 2
+            && chars[i - 1] == '.'
+            && (chars[i - 2].is_alphanumeric() || chars[i - 2] == '_');
+
+        let kind = if c.is_alphabetic() || c == '_' || (c.is_ascii_digit() && in_name) {
+            let len = chars[i..]
+                .iter()
+                .take_while(|c| c.is_alphanumeric() || **c == '_')
+                .count();
+            let ident: String = chars[i..i + len].iter().collect();
+            advance(&mut i, &mut span, len);
+            TokenKind::Ident(ident)
+        } else if c.is_ascii_digit()
+            || (c == '-' && chars.get(i + 1).is_some_and(|c| c.is_ascii_digit()))
+        {
+            let mut len = 1;
+
+            // An exponent may have its own sign, as in `1e-3`
+            while let Some(&c) = chars.get(i + len) {
+                let sign = (c == '-' || c == '+') && chars[i + len - 1] == 'e';
+
+                if !(c.is_ascii_digit() || c == '.' || c == 'e' || sign) {
+                    break;
+                }
+
+                len += 1;
+            }
+            let text: String = chars[i..i + len].iter().collect();
+            let Ok(number) = text.parse() else {
+                return error(start, format!("invalid number {text}"));
+            };
+            advance(&mut i, &mut span, len);
+            TokenKind::Number(number)
+        } else if c == '"' {
+            let mut text = String::new();
+            advance(&mut i, &mut span, 1);
+
+            loop {
+                match chars.get(i) {
+                    None => return error(start, "unterminated text"),
+                    Some('"') => break,
+                    Some('\\') => {
+                        advance(&mut i, &mut span, 1);
+                        match chars.get(i) {
+                            Some('n') => text.push('\n'),
+                            Some(c) => text.push(*c),
+                            None => return error(start, "unterminated text"),
+                        }
+                    }
+                    Some(c) => text.push(*c),
+                }
+                advance(&mut i, &mut span, 1);
+            }
+
+            advance(&mut i, &mut span, 1);
+            TokenKind::Text(text)
+        } else if c == '%' {
+            let mut depth = 0usize;
+            let len = chars[i..]
+                .iter()
+                .take_while(|c| {
+                    match c {
+                        '(' => depth += 1,
+                        ')' if depth == 0 => return false,
+                        ')' => depth -= 1,
+                        ',' | '{' | '}' if depth == 0 => return false,
+                        c if c.is_whitespace() && depth == 0 => return false,
+                        _ => {}
+                    }
+                    true
+                })
+                .count();
+            let calc: String = chars[i..i + len].iter().collect();
+            advance(&mut i, &mut span, len);
+            TokenKind::Calc(calc)
+        } else {
+            let rest: String = chars[i..chars.len().min(i + 2)].iter().collect();
+            let Some(punct) = PUNCTS.iter().find(|punct| rest.starts_with(*punct)) else {
+                return error(start, format!("unexpected character {c:?}"));
+            };
+            advance(&mut i, &mut span, punct.len());
+            TokenKind::Punct(punct)
+        };
+
+        tokens.push(Token { kind, span: start });
+    }
+
+    tokens.push(Token {
+        kind: TokenKind::Eof,
+        span,
+    });
+    Ok(tokens)
+}
+
+// Parser
+
+#[derive(Debug, Clone)]
+pub struct Ident {
+    pub name: String,
+    pub span: Span,
+}
+
+#[derive(Debug)]
+pub struct Item {
+    pub line_type: LineTypeWants2Bits,
+    pub name: Ident,
+    pub body: Vec<Stmt>,
+}
+
+#[derive(Debug)]
+pub struct Arg {
+    pub name: Ident,
+    pub value: Value,
+}
+
+#[derive(Debug)]
+pub enum Cond {
+    Compare {
+        operator: &'static str,
+        left: Value,
+        right: Value,
+        span: Span,
+    },
+    Action(Call),
+}
+
+#[derive(Debug)]
+pub struct Call {
+    pub object: Ident,
+    pub name: Ident,
+    pub selection: Option<Ident>,
+    pub args: Vec<Arg>,
+}
+
+#[derive(Debug)]
+pub enum Stmt {
+    Call {
+        process: bool,
+        target: Value,
+        args: Vec<Arg>,
+        span: Span,
+    },
+    Else {
+        body: Vec<Stmt>,
+        span: Span,
+    },
+    If {
+        inverted: bool,
+        cond: Cond,
+        body: Vec<Stmt>,
+    },
+    /// `repeat while cond { .. }` and other actions taking a conditional.
+    Conditional {
+        object: Ident,
+        name: Ident,
+        inverted: bool,
+        cond: Cond,
+        body: Option<Vec<Stmt>>,
+    },
+    Action {
+        targets: Vec<Value>,
+        call: Call,
+        body: Option<Vec<Stmt>>,
+    },
+    Assign {
+        target: Value,
+        value: Value,
+        span: Span,
+    },
+}
+
+const SCOPES: [&str; 3] = ["local", "global", "save"];
+
+struct Parser {
+    tokens: Vec<Token>,
+    next: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> &Token {
+        &self.tokens[self.next]
+    }
+
+    fn peek_at(&self, offset: usize) -> &TokenKind {
+        &self.tokens[(self.next + offset).min(self.tokens.len() - 1)].kind
+    }
+
+    fn bump(&mut self) -> Token {
+        let token = self.tokens[self.next].clone();
+        self.next = (self.next + 1).min(self.tokens.len() - 1);
+        token
+    }
+
+    fn is(&self, punct: &'static str) -> bool {
+        self.peek().kind == TokenKind::Punct(punct)
+    }
+
+    fn is_ident(&self, ident: &str) -> bool {
+        matches!(&self.peek().kind, TokenKind::Ident(name) if name == ident)
+    }
+
+    fn eat(&mut self, punct: &'static str) -> bool {
+        let found = self.is(punct);
+        if found {
+            self.bump();
+        }
+        found
+    }
+
+    fn expect(&mut self, punct: &'static str) -> Result<Span, SyntaxError> {
+        let token = self.bump();
+
+        if token.kind == TokenKind::Punct(punct) {
+            Ok(token.span)
+        } else {
+            error(
+                token.span,
+                format!("expected `{punct}`, found {}", token.kind),
+            )
+        }
+    }
+
+    fn ident(&mut self) -> Result<Ident, SyntaxError> {
+        let token = self.bump();
+
+        match token.kind {
+            TokenKind::Ident(name) => Ok(Ident {
+                name,
+                span: token.span,
+            }),
+            kind => error(token.span, format!("expected a name, found {kind}")),
+        }
+    }
+
+    fn number(&mut self) -> Result<f64, SyntaxError> {
+        let token = self.bump();
+
+        match token.kind {
+            TokenKind::Number(number) => Ok(number),
+            kind => error(token.span, format!("expected a number, found {kind}")),
+        }
+    }
+
+    /// A whole number that fits in an `i16`, like potion amplifiers and durations.
+    fn short(&mut self) -> Result<i16, SyntaxError> {
+        let span = self.peek().span;
+        let number = self.number()?;
+
+        match i16::try_from(number as i64) {
+            Ok(short) if short as f64 == number => Ok(short),
+            _ => error(
+                span,
+                format!(
+                    "expected a whole number from {} to {}, found {number}",
+                    i16::MIN,
+                    i16::MAX
+                ),
+            ),
+        }
+    }
+
+    fn items(mut self) -> Result<Vec<Item>, SyntaxError> {
+        let mut items = Vec::new();
+
+        while self.peek().kind != TokenKind::Eof {
+            let keyword = self.ident()?;
+            let line_type = match keyword.name.as_str() {
+                "event" => LineTypeWants2Bits::Event,
+                "function" => LineTypeWants2Bits::Function,
+                "process" => LineTypeWants2Bits::Process,
+                other => {
+                    return error(
+                        keyword.span,
+                        format!("expected `event`, `function` or `process`, found `{other}`"),
+                    );
+                }
+            };
+
+            items.push(Item {
+                line_type,
+                name: self.ident()?,
+                body: self.block()?,
+            });
+        }
+
+        Ok(items)
+    }
+
+    fn block(&mut self) -> Result<Vec<Stmt>, SyntaxError> {
+        self.expect("{")?;
+        let mut stmts = Vec::new();
+
+        while !self.eat("}") {
+            if self.peek().kind == TokenKind::Eof {
+                return error(self.peek().span, "unclosed block");
+            }
+
+            stmts.push(self.stmt()?);
+        }
+
+        Ok(stmts)
+    }
+
+    fn optional_block(&mut self) -> Result<Option<Vec<Stmt>>, SyntaxError> {
+        if self.is("{") {
+            self.block().map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn is_variable(&self) -> bool {
+        matches!(self.peek_at(0), TokenKind::Ident(scope) if SCOPES.contains(&scope.as_str()))
+            && *self.peek_at(1) == TokenKind::Punct(".")
+    }
+
+    fn stmt(&mut self) -> Result<Stmt, SyntaxError> {
+        let span = self.peek().span;
+
+        if self.is_ident("call") || self.is_ident("start") {
+            let process = self.ident()?.name == "start";
+            let target = if self.eat("(") {
+                let target = self.value()?;
+                self.expect(")")?;
+                target
+            } else {
+                Value::Text {
+                    text: self.ident()?.name,
+                    parsing: TextParsingWants2Bits::Plain,
+                }
+            };
+            let args = if self.is("(") {
+                self.args()?
+            } else {
+                Vec::new()
+            };
+
+            return Ok(Stmt::Call {
+                process,
+                target,
+                args,
+                span,
+            });
+        }
+
+        if self.is_ident("else") {
+            self.bump();
+            return Ok(Stmt::Else {
+                body: self.block()?,
+                span,
+            });
+        }
+
+        if self.is_ident("if") {
+            self.bump();
+            let inverted = self.not();
+            return Ok(Stmt::If {
+                inverted,
+                cond: self.cond()?,
+                body: self.block()?,
+            });
+        }
+
+        if self.is_variable() {
+            let mut targets = vec![self.value()?];
+
+            while self.eat(",") {
+                targets.push(self.value()?);
+            }
+
+            self.expect("=")?;
+
+            if self.is_call() {
+                return Ok(Stmt::Action {
+                    targets,
+                    call: self.call()?,
+                    body: self.optional_block()?,
+                });
+            }
+
+            if targets.len() > 1 {
+                return error(span, "only actions can assign several variables");
+            }
+
+            return Ok(Stmt::Assign {
+                target: targets.remove(0),
+                value: self.value()?,
+                span,
+            });
+        }
+
+        // `repeat on_range(..) { .. }` and `repeat while cond { .. }`
+        if matches!(self.peek_at(1), TokenKind::Ident(_)) {
+            let object = self.ident()?;
+            let name = self.ident()?;
+
+            if self.is("(") {
+                let args = self.args()?;
+                return Ok(Stmt::Action {
+                    targets: Vec::new(),
+                    call: Call {
+                        object,
+                        name,
+                        selection: None,
+                        args,
+                    },
+                    body: self.optional_block()?,
+                });
+            }
+
+            let inverted = self.not();
+            return Ok(Stmt::Conditional {
+                object,
+                name,
+                inverted,
+                cond: self.cond()?,
+                body: self.optional_block()?,
+            });
+        }
+
+        Ok(Stmt::Action {
+            targets: Vec::new(),
+            call: self.call()?,
+            body: self.optional_block()?,
+        })
+    }
+
+    fn not(&mut self) -> bool {
+        let not = self.is_ident("not");
+        if not {
+            self.bump();
+        }
+        not
+    }
+
+    fn is_call(&self) -> bool {
+        matches!(self.peek_at(0), TokenKind::Ident(object) if !SCOPES.contains(&object.as_str()) && object != "value")
+            && *self.peek_at(1) == TokenKind::Punct(".")
+    }
+
+    fn call(&mut self) -> Result<Call, SyntaxError> {
+        let object = self.ident()?;
+        self.expect(".")?;
+        let name = self.ident()?;
+
+        let selection = if self.eat("[") {
+            let selection = self.ident()?;
+            self.expect("]")?;
+            Some(selection)
+        } else {
+            None
+        };
+
+        Ok(Call {
+            object,
+            name,
+            selection,
+            args: self.args()?,
+        })
+    }
+
+    fn cond(&mut self) -> Result<Cond, SyntaxError> {
+        if self.is_call() {
+            return Ok(Cond::Action(self.call()?));
+        }
+
+        let span = self.peek().span;
+        let left = self.value()?;
+        let token = self.bump();
+        let operator = match token.kind {
+            TokenKind::Punct(operator @ ("==" | "!=" | ">" | ">=" | "<" | "<=")) => operator,
+            kind => return error(token.span, format!("expected a comparison, found {kind}")),
+        };
+
+        Ok(Cond::Compare {
+            operator,
+            left,
+            right: self.value()?,
+            span,
+        })
+    }
+
+    fn args(&mut self) -> Result<Vec<Arg>, SyntaxError> {
+        self.expect("(")?;
+        let mut args = Vec::new();
+
+        while !self.eat(")") {
+            if !args.is_empty() {
+                self.expect(",")?;
+            }
+
+            let name = self.ident()?;
+            self.expect(":")?;
+            args.push(Arg {
+                name,
+                value: self.value()?,
+            });
+        }
+
+        Ok(args)
+    }
+
+    fn numbers<const N: usize>(&mut self) -> Result<[f64; N], SyntaxError> {
+        let mut numbers = [0.0; N];
+        self.expect("(")?;
+
+        for (i, number) in numbers.iter_mut().enumerate() {
+            if i > 0 {
+                self.expect(",")?;
+            }
+            *number = self.number()?;
+        }
+
+        self.expect(")")?;
+        Ok(numbers)
+    }
+
+    fn dotted(&mut self) -> Result<Ident, SyntaxError> {
+        let mut ident = self.ident()?;
+
+        while self.eat(".") {
+            ident.name.push('.');
+            ident.name.push_str(&self.ident()?.name);
+        }
+
+        Ok(ident)
+    }
+
+    fn value(&mut self) -> Result<Value, SyntaxError> {
+        let token = self.bump();
+
+        Ok(match token.kind {
+            TokenKind::Number(number) => Value::Number {
+                number: Number::Simple(number),
+            },
+            TokenKind::Text(text) => Value::Text {
+                text,
+                parsing: TextParsingWants2Bits::Plain,
+            },
+            TokenKind::Calc(calc) => Value::Number {
+                number: Number::Calc(calc),
+            },
+            TokenKind::Punct("?") => Value::empty(),
+            TokenKind::Punct("[") => {
+                let mut values = Vec::new();
+
+                while !self.eat("]") {
+                    if !values.is_empty() {
+                        self.expect(",")?;
+                    }
+                    values.push(self.value()?);
+                }
+
+                Value::Array { values }
+            }
+            TokenKind::Ident(ident) => match ident.as_str() {
+                "local" | "global" | "save" => {
+                    self.expect(".")?;
+                    Value::Variable {
+                        variable: self.ident()?.name,
+                        scope: serde_json::from_value(ident.into()).unwrap(),
+                    }
+                }
+                "legacy" if matches!(self.peek().kind, TokenKind::Text(_)) => {
+                    let TokenKind::Text(text) = self.bump().kind else {
+                        unreachable!()
+                    };
+                    Value::Text {
+                        text,
+                        parsing: TextParsingWants2Bits::Legacy,
+                    }
+                }
+                "value" if self.is(".") => {
+                    self.bump();
+                    let name = self.ident()?;
+                    let selection = if self.eat("[") {
+                        let selection = self.ident()?.name;
+                        self.expect("]")?;
+                        selection
+                    } else {
+                        "default".to_string()
+                    };
+
+                    Value::GameValue {
+                        game_value: serde_json::from_value(name.name.clone().into()).or_else(
+                            |_| error(name.span, format!("unknown game value `{}`", name.name)),
+                        )?,
+                        selection: serde_json::json!({ "type": selection }).to_string(),
+                    }
+                }
+                "loc" if self.is("(") => {
+                    let [x, y, z, yaw, pitch] = self.numbers()?;
+                    Value::Location {
+                        x,
+                        y,
+                        z,
+                        yaw,
+                        pitch,
+                    }
+                }
+                "vec" if self.is("(") => {
+                    let [x, y, z] = self.numbers()?;
+                    Value::Vector { x, y, z }
+                }
+                "item" | "block" if self.is("(") => {
+                    self.bump();
+                    let token = self.bump();
+                    let text = match token.kind {
+                        TokenKind::Text(text) | TokenKind::Ident(text) => text,
+                        kind => return error(token.span, format!("expected text, found {kind}")),
+                    };
+                    self.expect(")")?;
+
+                    if ident == "item" {
+                        Value::Item { item: text }
+                    } else {
+                        Value::Block { block: text }
+                    }
+                }
+                "potion" if self.is("(") => {
+                    self.bump();
+                    let potion = self.ident()?.name;
+                    self.expect(",")?;
+                    let amplifier = self.short()?;
+                    self.expect(",")?;
+                    let duration = self.short()?;
+                    self.expect(")")?;
+                    Value::Potion {
+                        potion,
+                        amplifier,
+                        duration,
+                    }
+                }
+                "sound" if self.is("(") => {
+                    self.bump();
+                    let sound = self.dotted()?;
+                    let sound: SoundId = serde_json::from_value(sound.name.clone().into())
+                        .or_else(|_| {
+                            error(sound.span, format!("unknown sound `{}`", sound.name))
+                        })?;
+                    self.expect(",")?;
+                    let volume = self.number()? as f32;
+                    self.expect(",")?;
+                    let pitch = self.number()? as f32;
+                    let mut source = SoundSourceWants4Bits::default();
+                    let mut variation = None;
+
+                    if self.eat(",") {
+                        let name = self.ident()?;
+                        source = serde_json::from_value(name.name.to_uppercase().into()).or_else(
+                            |_| error(name.span, format!("unknown sound source `{}`", name.name)),
+                        )?;
+                    }
+
+                    if self.eat(",") {
+                        let token = self.bump();
+                        let TokenKind::Text(text) = token.kind else {
+                            return error(token.span, "expected sound variation text");
+                        };
+                        variation = Some(text);
+                    }
+
+                    self.expect(")")?;
+                    Value::Sound {
+                        sound,
+                        pitch,
+                        volume,
+                        variation,
+                        source,
+                    }
+                }
+                "particle" => Value::Particle {
+                    raw: serde_json::Value::Null,
+                },
+                // Names the printer couldn't write in UpperCamelCase come back verbatim
+                _ if ident.chars().any(|c| c.is_ascii_lowercase()) => Value::Enum {
+                    value: ident.to_shouty_snake_case(),
+                },
+                _ => Value::Enum { value: ident },
+            },
+            kind => return error(token.span, format!("expected a value, found {kind}")),
+        })
+    }
+}
+
+// Lowering
+
+struct Lowering<'c> {
+    catalog: &'c Catalog,
+    actions: HashMap<(String, String), &'c Action>,
+}
+
+impl<'c> Lowering<'c> {
+    fn new(catalog: &'c Catalog) -> Self {
+        Self {
+            catalog,
+            actions: catalog
+                .actions
+                .values()
+                .map(|action| ((type_name(action.object), action.name.clone()), action))
+                .collect(),
+        }
+    }
+
+    fn module(&self, items: Vec<Item>) -> Result<Module, SyntaxError> {
+        let mut seen = HashSet::new();
+        let mut handlers = Vec::new();
+
+        if items.len() > u8::MAX as usize + 1 {
+            return error(items[256].name.span, "a module holds at most 256 lines");
+        }
+
+        for (position, item) in items.into_iter().enumerate() {
+            if item.line_type != LineTypeWants2Bits::Event
+                && !seen.insert((item.line_type, item.name.name.clone()))
+            {
+                return error(
+                    item.name.span,
+                    format!("{} `{}` is defined twice", item.line_type, item.name.name),
+                );
+            }
+
+            let (name, event) = match item.line_type {
+                LineTypeWants2Bits::Event => {
+                    let event: EventId = serde_json::from_value(item.name.name.clone().into())
+                        .or_else(|_| {
+                            error(
+                                item.name.span,
+                                format!("unknown event `{}`", item.name.name),
+                            )
+                        })?;
+                    (None, Some(event))
+                }
+                _ => (Some(item.name.name), None),
+            };
+
+            handlers.push(Line {
+                line_type: item.line_type,
+                name,
+                event,
+                position: position as u8,
+                operations: self.stmts(item.body)?,
+                values: Vec::new(),
+            });
+        }
+
+        Ok(Module {
+            handlers,
+            conflicts: Vec::new(),
+        })
+    }
+
+    fn stmts(&self, stmts: Vec<Stmt>) -> Result<Vec<Op>, SyntaxError> {
+        stmts.into_iter().map(|stmt| self.stmt(stmt)).collect()
+    }
+
+    fn action(&self, object: &Ident, name: &Ident) -> Result<&'c Action, SyntaxError> {
+        self.actions
+            .get(&(object.name.clone(), name.name.clone()))
+            .copied()
+            .ok_or_else(|| SyntaxError {
+                span: object.span,
+                message: format!("unknown action `{}.{}`", object.name, name.name),
+            })
+    }
+
+    fn op(action: &Action) -> Op {
+        Op {
+            action: action.id,
+            values: Vec::new(),
+            selection: None,
+            is_inverted: false,
+            conditional: None,
+            operations: Vec::new(),
+        }
+    }
+
+    fn args(&self, actions: &[&Action], args: Vec<Arg>) -> Result<Vec<NamedValue>, SyntaxError> {
+        args.into_iter()
+            .map(|arg| {
+                if !actions
+                    .iter()
+                    .any(|action| action.arg(&arg.name.name).is_some())
+                {
+                    return error(
+                        arg.name.span,
+                        format!("{} has no argument `{}`", actions[0].name, arg.name.name),
+                    );
+                }
+
+                Ok(NamedValue {
+                    name: arg.name.name,
+                    value: arg.value,
+                })
+            })
+            .collect()
+    }
+
+    fn body(
+        &self,
+        action: &Action,
+        span: Span,
+        body: Option<Vec<Stmt>>,
+    ) -> Result<Vec<Op>, SyntaxError> {
+        let container = matches!(
+            action.action_type,
+            ActionType::Container | ActionType::ContainerWithConditional
+        );
+
+        match body {
+            Some(_) if !container => error(span, format!("{} doesn't take a body", action.name)),
+            None if container => error(span, format!("{} needs a body", action.name)),
+            Some(body) => self.stmts(body),
+            None => Ok(Vec::new()),
+        }
+    }
+
+    /// Lowers a condition to the boolean action and its values.
+    fn cond(&self, cond: Cond) -> Result<(&'c Action, Vec<NamedValue>), SyntaxError> {
+        match cond {
+            Cond::Compare {
+                operator,
+                left,
+                right,
+                span,
+            } => {
+                let id = match operator {
+                    "==" => ActionIdWants11Bits::IfVariableEquals,
+                    "!=" => ActionIdWants11Bits::IfVariableNotEquals,
+                    ">" => ActionIdWants11Bits::IfVariableGreater,
+                    ">=" => ActionIdWants11Bits::IfVariableGreaterOrEquals,
+                    "<" => ActionIdWants11Bits::IfVariableLess,
+                    _ => ActionIdWants11Bits::IfVariableLessOrEquals,
+                };
+
+                if matches!(left, Value::Error { .. }) {
+                    return error(span, "missing left side of the comparison");
+                }
+
+                Ok((
+                    self.catalog.action(id),
+                    vec![
+                        NamedValue {
+                            name: "value".to_string(),
+                            value: left,
+                        },
+                        NamedValue {
+                            name: "compare".to_string(),
+                            value: right,
+                        },
+                    ],
+                ))
+            }
+            Cond::Action(call) => {
+                let action = self.action(&call.object, &call.name)?;
+
+                if !action.boolean {
+                    return error(
+                        call.name.span,
+                        format!("{} is not a condition", action.name),
+                    );
+                }
+
+                Ok((action, self.args(&[action], call.args)?))
+            }
+        }
+    }
+
+    fn stmt(&self, stmt: Stmt) -> Result<Op, SyntaxError> {
+        match stmt {
+            Stmt::Call {
+                process,
+                target,
+                args,
+                span,
+            } => {
+                let (id, arg) = if process {
+                    (ActionIdWants11Bits::StartProcess, "process_name")
+                } else {
+                    (ActionIdWants11Bits::CallFunction, "function_name")
+                };
+                let action = self.catalog.action(id);
+
+                if !process && !args.is_empty() {
+                    return error(span, "functions don't take arguments");
+                }
+
+                let mut op = Self::op(action);
+                op.values.push(NamedValue {
+                    name: arg.to_string(),
+                    value: target,
+                });
+                op.values.extend(self.args(&[action], args)?);
+                Ok(op)
+            }
+            Stmt::Else { body, span } => {
+                let action = self.catalog.action(ActionIdWants11Bits::Else);
+                let mut op = Self::op(action);
+                op.operations = self.body(action, span, Some(body))?;
+                Ok(op)
+            }
+            Stmt::If {
+                inverted,
+                cond,
+                body,
+            } => {
+                let (action, values) = self.cond(cond)?;
+                let mut op = Self::op(action);
+                op.values = values;
+                op.is_inverted = inverted;
+                op.operations = self.stmts(body)?;
+                Ok(op)
+            }
+            Stmt::Conditional {
+                object,
+                name,
+                inverted,
+                cond,
+                body,
+            } => {
+                let action = self.action(&object, &name)?;
+
+                if !matches!(
+                    action.action_type,
+                    ActionType::BasicWithConditional | ActionType::ContainerWithConditional
+                ) {
+                    return error(
+                        name.span,
+                        format!("{} doesn't take a condition", action.name),
+                    );
+                }
+
+                let (inner, values) = self.cond(cond)?;
+                let mut op = Self::op(action);
+                op.values = values;
+                op.conditional = Some(Conditional {
+                    action: inner.id,
+                    is_inverted: inverted,
+                });
+                op.operations = self.body(action, object.span, body)?;
+                Ok(op)
+            }
+            Stmt::Action {
+                targets,
+                call,
+                body,
+            } => {
+                let action = self.action(&call.object, &call.name)?;
+
+                if action.boolean {
+                    return error(
+                        call.name.span,
+                        format!("{} is a condition, use it with `if`", action.name),
+                    );
+                }
+
+                if targets.len() > action.assign.len() {
+                    return error(
+                        call.object.span,
+                        format!(
+                            "{} assigns {} variables, not {}",
+                            action.name,
+                            action.assign.len(),
+                            targets.len()
+                        ),
+                    );
+                }
+
+                let mut op = Self::op(action);
+                op.values = action
+                    .assign
+                    .iter()
+                    .zip(targets)
+                    .map(|(assign, target)| NamedValue {
+                        name: assign.id.clone(),
+                        value: target,
+                    })
+                    .collect();
+                op.values.extend(self.args(&[action], call.args)?);
+
+                if let Some(selection) = call.selection {
+                    op.selection = Some(Selection {
+                        selection_type: serde_json::from_value(selection.name.clone().into())
+                            .or_else(|_| {
+                                error(
+                                    selection.span,
+                                    format!("unknown selection `{}`", selection.name),
+                                )
+                            })?,
+                    });
+                }
+
+                op.operations = self.body(action, call.object.span, body)?;
+                Ok(op)
+            }
+            Stmt::Assign {
+                target,
+                value,
+                span,
+            } => {
+                if !matches!(target, Value::Variable { .. }) {
+                    return error(span, "only variables can be assigned");
+                }
+
+                let mut op = Self::op(self.catalog.action(ActionIdWants11Bits::SetVariableValue));
+                op.values = vec![
+                    NamedValue {
+                        name: "variable".to_string(),
+                        value: target,
+                    },
+                    NamedValue {
+                        name: "value".to_string(),
+                        value,
+                    },
+                ];
+                Ok(op)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sound_ids_with_number_segments() {
+        let catalog = Catalog::load().unwrap();
+
+        for id in ["music_disc.11", "item.goat_horn.sound.0"] {
+            let source = format!(
+                "function F {{\n    player.play_sound(sound: sound({id}, 1, 1, MASTER))\n}}\n"
+            );
+            let module = compile_source(&source, &catalog).unwrap();
+            let sound = module.handlers[0].operations[0].value("sound").unwrap();
+
+            assert!(matches!(sound, Value::Sound { .. }), "{sound:?}");
+            assert_eq!(
+                crate::pretty::render_value(sound),
+                format!("sound({id}, 1, 1, MASTER)")
+            );
+        }
+    }
+}
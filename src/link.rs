@@ -0,0 +1,221 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::{self, Display},
+};
+
+use crate::{
+    callgraph::CallKind,
+    generated::EventId,
+    module::{LineTypeWants2Bits, Module, Value, walk_ops_mut},
+    types::type_name,
+};
+
+const POSITIONS: usize = u8::MAX as usize + 1;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LinkError {
+    DuplicateName {
+        line_type: LineTypeWants2Bits,
+        name: String,
+        first: String,
+        second: String,
+    },
+    EventCollision {
+        event: EventId,
+        first: String,
+        second: String,
+    },
+    PositionsExhausted {
+        lines: usize,
+    },
+}
+
+impl Display for LinkError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::DuplicateName {
+                line_type,
+                name,
+                first,
+                second,
+            } => write!(
+                f,
+                "{line_type} {name} is defined in both {first} and {second}"
+            ),
+            Self::EventCollision {
+                event,
+                first,
+                second,
+            } => write!(
+                f,
+                "event {} is handled in both {first} and {second}",
+                type_name(event)
+            ),
+            Self::PositionsExhausted { lines } => write!(
+                f,
+                "linked module has {lines} lines but only {POSITIONS} positions exist"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for LinkError {}
+
+/// Merges named modules into one, giving every line a fresh position. With `namespace`,
+/// functions and processes become `<module>.<name>` and calls inside their module follow.
+pub fn link(modules: Vec<(String, Module)>, namespace: bool) -> Result<Module, Vec<LinkError>> {
+    let mut errors = Vec::new();
+    let mut names: HashMap<(LineTypeWants2Bits, String), String> = HashMap::new();
+    let mut events: HashMap<EventId, String> = HashMap::new();
+    let mut handlers = Vec::new();
+    let mut conflicts = Vec::new();
+
+    for (module_name, mut module) in modules {
+        if namespace {
+            prefix_names(&module_name, &mut module);
+        }
+
+        module.handlers.sort_by_key(|line| line.position);
+        conflicts.append(&mut module.conflicts);
+
+        for line in module.handlers {
+            if let Some(event) = line.event
+                && let Some(first) = events.insert(event, module_name.clone())
+            {
+                errors.push(LinkError::EventCollision {
+                    event,
+                    first,
+                    second: module_name.clone(),
+                });
+            }
+
+            if let Some(name) = &line.name
+                && let Some(first) =
+                    names.insert((line.line_type, name.clone()), module_name.clone())
+            {
+                errors.push(LinkError::DuplicateName {
+                    line_type: line.line_type,
+                    name: name.clone(),
+                    first,
+                    second: module_name.clone(),
+                });
+            }
+
+            handlers.push(line);
+        }
+    }
+
+    if handlers.len() > POSITIONS {
+        errors.push(LinkError::PositionsExhausted {
+            lines: handlers.len(),
+        });
+    }
+
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+
+    for (position, line) in handlers.iter_mut().enumerate() {
+        line.position = position as u8;
+    }
+
+    Ok(Module {
+        handlers,
+        conflicts,
+    })
+}
+
+fn prefix_names(prefix: &str, module: &mut Module) {
+    let defined: HashSet<(LineTypeWants2Bits, String)> = module
+        .handlers
+        .iter()
+        .filter_map(|line| Some((line.line_type, line.name.clone()?)))
+        .collect();
+
+    for line in &mut module.handlers {
+        if let Some(name) = &mut line.name {
+            *name = format!("{prefix}.{name}");
+        }
+
+        walk_ops_mut(&mut line.operations, &mut |_, op| {
+            let Some((kind, arg)) = CallKind::of(op) else {
+                return;
+            };
+
+            for value in &mut op.values {
+                // Calls into other modules keep their target
+                if let (true, Value::Text { text, .. }) = (value.name == arg, &mut value.value)
+                    && defined.contains(&(kind.line_type(), text.clone()))
+                {
+                    *text = format!("{prefix}.{text}");
+                }
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assets::Catalog, lang, pretty::render_op};
+
+    fn module(source: &str) -> Module {
+        lang::compile_source(source, &Catalog::load().unwrap()).unwrap()
+    }
+
+    #[test]
+    fn namespaces_lines_and_their_calls() {
+        let a = module(
+            "
+event player_join {
+    call F
+}
+function F {
+    call G
+}
+",
+        );
+        let b = module(
+            "
+function F {
+    local.x = 1
+}
+",
+        );
+        let linked = link(vec![("a".into(), a), ("b".into(), b)], true).unwrap();
+
+        let catalog = Catalog::load().unwrap();
+        let names: Vec<_> = (linked.handlers.iter())
+            .map(|line| line.name.as_deref())
+            .collect();
+        assert_eq!(names, [None, Some("a.F"), Some("b.F")]);
+        assert_eq!(
+            render_op(&linked.handlers[0].operations[0], &catalog),
+            "call a.F"
+        );
+        // G isn't defined in `a`, so it's left for another module to provide
+        assert_eq!(
+            render_op(&linked.handlers[1].operations[0], &catalog),
+            "call G"
+        );
+        let positions: Vec<_> = linked.handlers.iter().map(|line| line.position).collect();
+        assert_eq!(positions, [0, 1, 2]);
+    }
+
+    #[test]
+    fn fails_when_positions_run_out() {
+        let functions = || {
+            let source: String = (0..200)
+                .map(|i| format!("function F{i} {{\n}}\n"))
+                .collect();
+            module(&source)
+        };
+        let errors = link(
+            vec![("a".into(), functions()), ("b".into(), functions())],
+            true,
+        )
+        .unwrap_err();
+
+        assert_eq!(errors, [LinkError::PositionsExhausted { lines: 400 }]);
+    }
+}
@@ -0,0 +1,408 @@
+use crate::{
+    assets::{Action, Catalog},
+    callgraph::CallGraph,
+    diagnostic::Diagnostic,
+    generated::{ActionIdWants11Bits, ActionObject, ActionType},
+    module::{LineTypeWants2Bits, Module, Op, SelectionTypeWants5Bits, Value, walk_ops},
+    types::{Types, mismatch, type_name},
+};
+
+pub fn all(module: &Module, catalog: &Catalog) -> Vec<Diagnostic> {
+    let mut diagnostics = cancel_events(module, catalog);
+    diagnostics.extend(selections(module, catalog));
+    diagnostics.extend(arrays(module, catalog));
+    diagnostics.extend(structure(module, catalog));
+    diagnostics
+}
+
+fn touches_cancellation(action: ActionIdWants11Bits) -> bool {
+    matches!(
+        action,
+        ActionIdWants11Bits::GameCancelEvent
+            | ActionIdWants11Bits::GameUncancelEvent
+            | ActionIdWants11Bits::IfGameEventIsCanceled
+    )
+}
+
+/// Flags cancellation actions in handlers of events that can't be cancelled, including
+/// functions and processes that are only reachable from such handlers.
+pub fn cancel_events(module: &Module, catalog: &Catalog) -> Vec<Diagnostic> {
+    let calls = CallGraph::build(module);
+    let mut diagnostics = Vec::new();
+
+    let events: Vec<(usize, bool)> = module
+        .handlers
+        .iter()
+        .enumerate()
+        .filter_map(|(index, line)| {
+            let event = catalog.events.get(line.event.as_ref()?)?;
+            Some((index, event.cancellable))
+        })
+        .collect();
+    let reachable: Vec<_> = events
+        .iter()
+        .map(|(index, cancellable)| (*index, *cancellable, calls.reachable([*index])))
+        .collect();
+
+    for (index, line) in module.handlers.iter().enumerate() {
+        let callers: Vec<_> = reachable
+            .iter()
+            .filter(|(_, _, lines)| lines.contains(&index))
+            .collect();
+
+        if callers.is_empty() || callers.iter().any(|(_, cancellable, _)| *cancellable) {
+            continue;
+        }
+
+        let context = if line.line_type == LineTypeWants2Bits::Event {
+            format!("event {} can't be cancelled", line.label())
+        } else {
+            let names: Vec<_> = callers
+                .iter()
+                .map(|(event, _, _)| module.handlers[*event].label())
+                .collect();
+            format!("only reachable from non-cancellable {}", names.join(", "))
+        };
+
+        walk_ops(&line.operations, &mut |path, op| {
+            // A conditional like `repeat_while` checks its inner action each time round
+            let conditional = op.conditional.map(|conditional| conditional.action);
+
+            for action in [Some(op.action), conditional].into_iter().flatten() {
+                if touches_cancellation(action) {
+                    diagnostics.push(Diagnostic::warning(
+                        index,
+                        path,
+                        format!("{} has no effect, {context}", catalog.action(action).name),
+                    ));
+                }
+            }
+        });
+    }
+
+    diagnostics
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Targets {
+    Players,
+    Entities,
+    Any,
+}
+
+impl Targets {
+    fn of_selection(selection_type: SelectionTypeWants5Bits) -> Self {
+        use SelectionTypeWants5Bits::*;
+
+        match selection_type {
+            Killer | Damager | Victim | Shooter | RandomPlayer | AllPlayers => Self::Players,
+            DefaultEntity | KillerEntity | DamagerEntity | VictimEntity | ShooterEntity
+            | Projectile | LastEntity | LastMob | RandomEntity | RandomMob | AllEntities
+            | AllMobs => Self::Entities,
+            Current | Default | Selected => Self::Any,
+        }
+    }
+
+    /// What a `select_*` action picks, judged by its name.
+    fn of_select(name: &str) -> Self {
+        if name.contains("player") {
+            Self::Players
+        } else if name.contains("entit") || name.contains("mob") {
+            Self::Entities
+        } else {
+            Self::Any
+        }
+    }
+
+    fn describe(self) -> &'static str {
+        match self {
+            Self::Players => "players",
+            Self::Entities => "entities",
+            Self::Any => "targets",
+        }
+    }
+
+    fn fits(self, object: ActionObject) -> bool {
+        !matches!(
+            (self, object),
+            (Self::Entities, ActionObject::Player) | (Self::Players, ActionObject::Entity)
+        )
+    }
+}
+
+/// Checks op selections and `select_*` results against the player or entity action using them.
+pub fn selections(module: &Module, catalog: &Catalog) -> Vec<Diagnostic> {
+    let mut diagnostics = Vec::new();
+
+    for (index, line) in module.handlers.iter().enumerate() {
+        // The last `select_*` op, what it picks and whether an action has used it yet
+        let mut current: Option<(Vec<usize>, Targets, bool)> = None;
+
+        walk_ops(&line.operations, &mut |path, op| {
+            let action = catalog.action(op.action);
+            let targeted = matches!(action.object, ActionObject::Player | ActionObject::Entity);
+
+            if action.object == ActionObject::Select {
+                if action.name == "reset" {
+                    current = None;
+                    return;
+                }
+
+                let picks = match (Targets::of_select(&action.name), &current) {
+                    (Targets::Any, Some((_, previous, _))) => *previous,
+                    // Adding players to entities or the other way round picks both
+                    (picks, Some((_, previous, _)))
+                        if action.name.starts_with("add_") && picks != *previous =>
+                    {
+                        Targets::Any
+                    }
+                    (picks, _) => picks,
+                };
+
+                if let Some((previous, _, false)) = &current
+                    && !action.name.starts_with("add_")
+                    && !action.name.starts_with("filter_")
+                    && action.name != "invert"
+                {
+                    diagnostics.push(Diagnostic::warning(
+                        index,
+                        previous,
+                        "selection is replaced before any action uses it",
+                    ));
+                }
+
+                current = Some((path.to_vec(), picks, false));
+                return;
+            }
+
+            let Some(selection) = op.selection else {
+                if targeted && let Some((select, picks, used)) = &mut current {
+                    *used = true;
+
+                    if !picks.fits(action.object) {
+                        diagnostics.push(Diagnostic::error(
+                            index,
+                            path,
+                            format!(
+                                "{} targets {} but the selection made at op {} only holds {}",
+                                action.name,
+                                type_name(action.object),
+                                select
+                                    .iter()
+                                    .map(usize::to_string)
+                                    .collect::<Vec<_>>()
+                                    .join("/"),
+                                picks.describe()
+                            ),
+                        ));
+                    }
+                }
+
+                return;
+            };
+
+            if !targeted {
+                diagnostics.push(Diagnostic::error(
+                    index,
+                    path,
+                    format!(
+                        "{} action {} can't use a selection",
+                        type_name(action.object),
+                        action.name
+                    ),
+                ));
+                return;
+            }
+
+            let targets = Targets::of_selection(selection.selection_type);
+
+            if targets == Targets::Any {
+                if let Some((_, _, used)) = &mut current {
+                    *used = true;
+                }
+            } else if !targets.fits(action.object) {
+                diagnostics.push(Diagnostic::error(
+                    index,
+                    path,
+                    format!(
+                        "selection {} only holds {} but {} targets {}",
+                        type_name(selection.selection_type),
+                        targets.describe(),
+                        action.name,
+                        type_name(action.object)
+                    ),
+                ));
+            }
+        });
+
+        if let Some((select, _, false)) = current {
+            diagnostics.push(Diagnostic::warning(
+                index,
+                &select,
+                "selection is never used by a player or entity action",
+            ));
+        }
+    }
+
+    diagnostics
+}
+
+/// Checks array literals against the element count and type the catalog declares for the argument.
+pub fn arrays(module: &Module, catalog: &Catalog) -> Vec<Diagnostic> {
+    let types = Types::infer(module, catalog);
+    let mut diagnostics = Vec::new();
+
+    for (index, line) in module.handlers.iter().enumerate() {
+        walk_ops(&line.operations, &mut |path, op| {
+            for value in &op.values {
+                let action = catalog.arg_owner(op, &value.name);
+                let (Value::Array { values }, Some(arg)) = (&value.value, action.arg(&value.name))
+                else {
+                    continue;
+                };
+
+                let Some(limit) = arg.array else {
+                    continue;
+                };
+
+                if values.len() > limit as usize {
+                    diagnostics.push(Diagnostic::error(
+                        index,
+                        path,
+                        format!(
+                            "{}.{} takes at most {limit} values, got {}",
+                            action.name,
+                            arg.id,
+                            values.len()
+                        ),
+                    ));
+                }
+
+                for element in values {
+                    if let Some(message) = mismatch(&types, catalog, action, arg, element) {
+                        diagnostics.push(Diagnostic::error(index, path, message));
+                    }
+                }
+            }
+        });
+    }
+
+    diagnostics
+}
+
+/// Checks that bodies, conditionals and inversion only appear on actions that support them.
+pub fn structure(module: &Module, catalog: &Catalog) -> Vec<Diagnostic> {
+    let mut diagnostics = Vec::new();
+
+    for (index, line) in module.handlers.iter().enumerate() {
+        check_structure(
+            catalog,
+            index,
+            &line.operations,
+            &mut Vec::new(),
+            &mut diagnostics,
+        );
+    }
+
+    diagnostics
+}
+
+fn check_structure(
+    catalog: &Catalog,
+    line: usize,
+    ops: &[Op],
+    path: &mut Vec<usize>,
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    let mut previous: Option<&Action> = None;
+
+    for (index, op) in ops.iter().enumerate() {
+        path.push(index);
+
+        let action = catalog.action(op.action);
+        let mut error = |message: String| diagnostics.push(Diagnostic::error(line, path, message));
+
+        let (container, with_conditional) = match action.action_type {
+            ActionType::Basic => (false, false),
+            ActionType::BasicWithConditional => (false, true),
+            ActionType::Container => (true, false),
+            ActionType::ContainerWithConditional => (true, true),
+        };
+
+        if !container && !op.operations.is_empty() {
+            error(format!("{} is not a container but has a body", action.name));
+        }
+
+        match (&op.conditional, with_conditional) {
+            (Some(_), false) => error(format!("{} doesn't take a conditional", action.name)),
+            (None, true) => error(format!("{} needs a conditional", action.name)),
+            (Some(conditional), true) => {
+                let inner = catalog.action(conditional.action);
+
+                if !inner.boolean {
+                    error(format!(
+                        "{} is not a condition and can't be used as the conditional of {}",
+                        inner.name, action.name
+                    ));
+                }
+            }
+            (None, false) => {}
+        }
+
+        if op.is_inverted && !action.boolean {
+            error(format!(
+                "{} is not a condition and can't be inverted",
+                action.name
+            ));
+        }
+
+        if op.action == ActionIdWants11Bits::Else
+            && !previous.is_some_and(|previous| previous.boolean)
+        {
+            error("else must directly follow a condition".to_string());
+        }
+
+        check_structure(catalog, line, &op.operations, path, diagnostics);
+        previous = Some(action);
+        path.pop();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::diagnostic::Severity;
+
+    fn lint(actions: &[&str]) -> Vec<Diagnostic> {
+        let operations: Vec<_> = (actions.iter())
+            .map(|action| serde_json::json!({ "action": action, "values": [] }))
+            .collect();
+        let module = serde_json::json!({
+            "handlers": [{ "type": "function", "name": "F", "position": 0, "operations": operations }]
+        });
+
+        selections(
+            &serde_json::from_value(module).unwrap(),
+            &Catalog::load().unwrap(),
+        )
+    }
+
+    #[test]
+    fn mixed_selections_fit_both() {
+        let diagnostics = lint(&[
+            "select_all_entities",
+            "select_add_all_players",
+            "entity_remove",
+        ]);
+
+        assert!(diagnostics.is_empty(), "{diagnostics:?}");
+    }
+
+    #[test]
+    fn player_selections_dont_fit_entities() {
+        let diagnostics = lint(&["select_all_players", "entity_remove"]);
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].severity, Severity::Error);
+    }
+}
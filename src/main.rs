@@ -1,15 +1,287 @@
 pub mod assets;
+pub mod builtins;
 pub mod bytecode;
+pub mod callgraph;
+pub mod cost;
+pub mod debugger;
+pub mod diagnostic;
+pub mod diff;
+pub mod fold;
+pub mod format;
 pub mod generated;
+pub mod host;
+pub mod huffman;
+pub mod inline;
+pub mod interpreter;
+pub mod lang;
+pub mod link;
+pub mod lint;
+pub mod merge;
+pub mod minify;
 pub mod module;
+pub mod optimize;
+pub mod pretty;
+pub mod prune;
+pub mod simulate;
+pub mod testing;
+pub mod types;
+pub mod variables;
 use std::{fs::File, io::BufReader};
 
 use bytecode::compile;
 
+fn load(path: &str) -> module::Module {
+    serde_json::from_reader(BufReader::new(File::open(path).unwrap())).unwrap_or_else(|err| {
+        log::error!("{path}: {err}");
+        std::process::exit(2);
+    })
+}
+
 fn main() {
     simple_logger::init().unwrap();
-    let module: module::Module =
-        serde_json::from_reader(BufReader::new(File::open("pr1.json").unwrap())).unwrap();
+    let mut args = std::env::args().skip(1);
+    let command = args.next().unwrap_or_else(|| "compile".to_string());
+    let path = args.next().unwrap_or_else(|| "pr1.json".to_string());
+
+    match command.as_str() {
+        "compile" => {
+            let coding = match args.next().as_deref() {
+                None => bytecode::Coding::Fixed,
+                Some("--huffman") => bytecode::Coding::Static,
+                Some("--huffman=module") => bytecode::Coding::Module,
+                Some(flag) => {
+                    log::error!("unknown flag {flag}");
+                    return;
+                }
+            };
+            check_and_compile(load(&path), coding)
+        }
+        "print" => print!(
+            "{}",
+            pretty::render(&load(&path), &assets::Catalog::load().unwrap())
+        ),
+        "diff" => {
+            let Some(other) = args.next() else {
+                log::error!("diff needs two modules");
+                return;
+            };
+            let catalog = assets::Catalog::load().unwrap();
+
+            for change in diff::diff(&load(&path), &load(&other), &catalog) {
+                println!("{change}");
+            }
+        }
+        "link" => {
+            let (flags, inputs): (Vec<_>, Vec<_>) = args.partition(|arg| arg.starts_with("--"));
+            let modules = inputs
+                .iter()
+                .map(|input| {
+                    let name = std::path::Path::new(input)
+                        .file_stem()
+                        .map_or(input.clone(), |stem| stem.to_string_lossy().into_owned());
+                    (name, load(input))
+                })
+                .collect();
+
+            match link::link(modules, flags.iter().any(|flag| flag == "--namespace")) {
+                Ok(module) => {
+                    let catalog = assets::Catalog::load().unwrap();
+                    std::fs::write(&path, format::format(module, &catalog)).unwrap();
+                    log::info!("linked {} modules into {path}", inputs.len());
+                }
+                Err(errors) => {
+                    for error in errors {
+                        log::error!("{error}");
+                    }
+                }
+            }
+        }
+        "merge" => {
+            let inputs: Vec<_> = args.map(|input| load(&input)).collect();
+            let [base, ours, theirs] = inputs.as_slice() else {
+                log::error!("merge needs an output, a base, our and their module");
+                return;
+            };
+            let merged = match merge::merge(base, ours, theirs) {
+                Ok(merged) => merged,
+                Err(err) => {
+                    log::error!("{err}");
+                    std::process::exit(1);
+                }
+            };
+
+            for conflict in &merged.conflicts {
+                log::warn!("conflict in {}", diff::conflict_location(conflict));
+            }
+
+            let catalog = assets::Catalog::load().unwrap();
+            std::fs::write(&path, format::format(merged, &catalog)).unwrap();
+        }
+        "fmt" => {
+            let formatted = format::format(load(&path), &assets::Catalog::load().unwrap());
+
+            if std::fs::read_to_string(&path).is_ok_and(|old| old == formatted) {
+                log::info!("{path} is already formatted");
+            } else {
+                std::fs::write(&path, formatted).unwrap();
+                log::info!("formatted {path}");
+            }
+        }
+        "build" => {
+            let catalog = assets::Catalog::load().unwrap();
+
+            match lang::compile_source(&std::fs::read_to_string(&path).unwrap(), &catalog) {
+                Ok(module) => println!("{}", serde_json::to_string_pretty(&module).unwrap()),
+                Err(err) => log::error!("{path}:{err}"),
+            }
+        }
+        "optimize" => {
+            let report = args.any(|arg| arg == "--report");
+            let catalog = assets::Catalog::load().unwrap();
+            let mut module = load(&path);
+            optimize::optimize(&mut module, &catalog, report);
+            std::fs::write(&path, format::format(module, &catalog)).unwrap();
+        }
+        "minify" | "unminify" => {
+            let map_path = args.next().unwrap_or_else(|| format!("{path}.map.json"));
+            let catalog = assets::Catalog::load().unwrap();
+            let mut module = load(&path);
+
+            if command == "unminify" {
+                let map = serde_json::from_reader(BufReader::new(File::open(&map_path).unwrap()));
+                minify::restore(&mut module, &map.unwrap());
+            } else {
+                match minify::minify(&mut module) {
+                    Ok(map) => {
+                        let map = serde_json::to_string_pretty(&map).unwrap();
+                        std::fs::write(&map_path, map).unwrap();
+                        log::info!("wrote source map to {map_path}");
+                    }
+                    Err(diagnostics) => {
+                        for diagnostic in diagnostics {
+                            diagnostic.log(&module);
+                        }
+                        return;
+                    }
+                }
+            }
+
+            std::fs::write(&path, format::format(module, &catalog)).unwrap();
+        }
+        "calls" => print!("{}", callgraph::CallGraph::build(&load(&path)).to_dot()),
+        "cost" => {
+            let module = load(&path);
+            let report = cost::report(&module, &assets::Catalog::load().unwrap());
+
+            for diagnostic in &report.diagnostics {
+                diagnostic.log(&module);
+            }
+
+            print!("{}", report.display(&module));
+        }
+        "run" | "debug" => {
+            let Some(name) = args.next() else {
+                log::error!("{command} needs a module and a function or process");
+                return;
+            };
+            let (flags, args): (Vec<_>, Vec<_>) = args.partition(|arg| arg.starts_with("--"));
+            let module = load(&path);
+            let catalog = assets::Catalog::load().unwrap();
+            let mut interpreter = interpreter::Interpreter::new(&module, &catalog);
+            let player = interpreter.host.spawn(host::Entity::player("player"));
+            interpreter.host.default = Some(player);
+
+            let mut breakpoints = Vec::new();
+
+            for flag in &flags {
+                if let Some(point) = flag.strip_prefix("--break=") {
+                    match debugger::Breakpoint::parse(point) {
+                        Ok(point) => breakpoints.push(point),
+                        Err(err) => log::error!("{flag}: {err}"),
+                    }
+                }
+            }
+
+            let trace = flags.iter().any(|flag| flag == "--trace");
+
+            if command == "debug" || trace || !breakpoints.is_empty() {
+                let stepping = command == "debug" && breakpoints.is_empty();
+                let debugger = debugger::Debugger::new(breakpoints, debugger::console(&module));
+                interpreter.debugger = Some(match stepping {
+                    true => debugger.stepping(),
+                    false => debugger,
+                });
+            }
+
+            // Initial locals as name=value, numbers where they parse
+            let mut locals: interpreter::Locals = args
+                .into_iter()
+                .filter_map(|arg| {
+                    let (name, value) = arg.split_once('=')?;
+                    let value = value.parse().map_or_else(
+                        |_| interpreter::Val::Text(value.to_string()),
+                        interpreter::Val::Number,
+                    );
+                    Some((name.to_string(), value))
+                })
+                .collect();
+
+            if let Err(err) = interpreter.run_named(&name, &mut locals) {
+                log::error!("{}", err.display(&module));
+            }
+
+            if trace && let Some(debugger) = &interpreter.debugger {
+                for step in &debugger.trace {
+                    println!("{}", step.display(&module));
+                }
+            }
+
+            let mut names: Vec<_> = locals.keys().collect();
+            names.sort();
+
+            for name in names {
+                println!("local.{name} = {}", locals[name]);
+            }
+
+            for entity in &interpreter.host.entities {
+                let [x, y, z, ..] = entity.location;
+                println!(
+                    "{} {} at {x} {y} {z}, health {}, tags {:?}",
+                    entity.entity_type, entity.uuid, entity.health, entity.tags
+                );
+            }
+        }
+        "test" => {
+            let Some(tests) = args.next() else {
+                log::error!("test needs a module and a test file");
+                return;
+            };
+            let file: testing::TestFile =
+                match serde_json::from_str(&std::fs::read_to_string(&tests).unwrap()) {
+                    Ok(file) => file,
+                    Err(err) => {
+                        log::error!("{tests}: {err}");
+                        std::process::exit(2);
+                    }
+                };
+
+            if !testing::run(&load(&path), &assets::Catalog::load().unwrap(), &file) {
+                std::process::exit(1);
+            }
+        }
+        command => log::error!("unknown command {command}"),
+    }
+}
+
+fn check_and_compile(module: module::Module, coding: bytecode::Coding) {
+    if !module.conflicts.is_empty() {
+        log::error!(
+            "module has {} unresolved merge conflicts",
+            module.conflicts.len()
+        );
+        return;
+    }
+
     log::info!("{:?}", module);
     //println!("{:?}", get_assets());
 
@@ -19,6 +291,54 @@ fn main() {
         }
     }
 
+    let calls = callgraph::CallGraph::build(&module);
+
+    for call in calls.undefined_calls() {
+        log::warn!(
+            "{} calls undefined {:?} {}",
+            module.handlers[call.caller].label(),
+            call.kind,
+            call.target
+        );
+    }
+
+    for line in calls.unused() {
+        log::warn!("{} is never called", module.handlers[line].label());
+    }
+
+    for cycle in calls.cycles() {
+        let names: Vec<_> = cycle
+            .iter()
+            .map(|&line| module.handlers[line].label())
+            .collect();
+        log::warn!("recursion between {}", names.join(" -> "));
+    }
+
+    let catalog = assets::Catalog::load().unwrap();
+    let mut failed = false;
+
+    for diagnostic in variables::analyze(&module, &catalog)
+        .into_iter()
+        .chain(types::check(&module, &catalog))
+        .chain(lint::all(&module, &catalog))
+    {
+        diagnostic.log(&module);
+
+        failed |= diagnostic.severity == diagnostic::Severity::Error;
+    }
+
+    if failed {
+        log::error!("not compiling a module with errors");
+        std::process::exit(1);
+    }
+
     log::info!("batatacode go now");
-    log::info!("{:?}", compile(module));
+
+    match compile(module, coding) {
+        Ok(bytecode) => log::info!("{bytecode:?}"),
+        Err(err) => {
+            log::error!("{err}");
+            std::process::exit(1);
+        }
+    }
 }
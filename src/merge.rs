@@ -0,0 +1,494 @@
+use std::{
+    collections::HashSet,
+    fmt::{self, Display},
+};
+
+use crate::{
+    diff::{common_subsequence, find, line_location, same_line},
+    module::{Conflict, Line, Module, NamedValue, Op},
+};
+
+const POSITIONS: usize = u8::MAX as usize + 1;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MergeError {
+    PositionsExhausted { lines: usize },
+}
+
+impl Display for MergeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::PositionsExhausted { lines } => write!(
+                f,
+                "merged module has {lines} lines but only {POSITIONS} positions exist"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for MergeError {}
+
+/// Three-way merge of modules. Edits to different handlers or to different ops of a
+/// handler are combined; overlapping edits keep our version and are recorded in
+/// `Module::conflicts`.
+pub fn merge(base: &Module, ours: &Module, theirs: &Module) -> Result<Module, MergeError> {
+    let mut merged = Module {
+        handlers: Vec::new(),
+        conflicts: Vec::new(),
+    };
+
+    // Every handler in the order it first shows up: ours, then added by them
+    let mut lines: Vec<&Line> = Vec::new();
+
+    for line in ours
+        .handlers
+        .iter()
+        .chain(&theirs.handlers)
+        .chain(&base.handlers)
+    {
+        if !lines.iter().any(|other| same_line(other, line)) {
+            lines.push(line);
+        }
+    }
+
+    for key in lines {
+        let find = |module: &'_ Module| {
+            module
+                .handlers
+                .iter()
+                .find(|line| same_line(line, key))
+                .cloned()
+        };
+
+        if let Some(line) = merge_line(find(base), find(ours), find(theirs), &mut merged.conflicts)
+        {
+            merged.handlers.push(line);
+        }
+    }
+
+    resolve_positions(&mut merged.handlers)?;
+    Ok(merged)
+}
+
+fn merge_line(
+    base: Option<Line>,
+    ours: Option<Line>,
+    theirs: Option<Line>,
+    conflicts: &mut Vec<Conflict>,
+) -> Option<Line> {
+    let ops = |line: &Option<Line>| {
+        line.as_ref()
+            .map(|line| line.operations.clone())
+            .unwrap_or_default()
+    };
+
+    match (&base, &ours, &theirs) {
+        // Added on one side, or deleted on one side and untouched on the other
+        (None, Some(_), None) => ours,
+        (None, None, Some(_)) => theirs,
+        (Some(base), Some(ours), None) if base == ours => None,
+        (Some(base), None, Some(theirs)) if base == theirs => None,
+        (Some(_), None, None) => None,
+        // Deleted on one side but edited on the other: keep the edit, flag it
+        (Some(_), None, Some(kept)) | (Some(_), Some(kept), None) => {
+            conflicts.push(Conflict {
+                line: line_location(kept),
+                path: Vec::new(),
+                base: ops(&base),
+                ours: ops(&ours),
+                theirs: ops(&theirs),
+                value: None,
+            });
+            Some(kept.clone())
+        }
+        (_, Some(ours_line), Some(theirs_line)) => {
+            let mut line = ours_line.clone();
+            let base_position = base.as_ref().map(|base| base.position);
+
+            if base_position == Some(line.position) {
+                line.position = theirs_line.position;
+            }
+
+            line.values = merge_values(
+                &line_location(ours_line),
+                base.as_ref().map_or(&[], |base| &base.values),
+                &ours_line.values,
+                &theirs_line.values,
+                conflicts,
+            );
+            line.operations = merge_ops(
+                &line_location(ours_line),
+                &mut Vec::new(),
+                &ops(&base),
+                &ours_line.operations,
+                &theirs_line.operations,
+                conflicts,
+            );
+            Some(line)
+        }
+        (None, None, None) => None,
+    }
+}
+
+/// Merges line values by name. A value both sides changed keeps ours and is flagged.
+fn merge_values(
+    line: &str,
+    base: &[NamedValue],
+    ours: &[NamedValue],
+    theirs: &[NamedValue],
+    conflicts: &mut Vec<Conflict>,
+) -> Vec<NamedValue> {
+    let mut names: Vec<&str> = Vec::new();
+
+    for value in ours.iter().chain(theirs).chain(base) {
+        if !names.contains(&value.name.as_str()) {
+            names.push(&value.name);
+        }
+    }
+
+    let mut merged = Vec::new();
+
+    for name in names {
+        let (base_value, ours_value, theirs_value) =
+            (find(base, name), find(ours, name), find(theirs, name));
+
+        let value = if ours_value == theirs_value || theirs_value == base_value {
+            ours_value
+        } else if ours_value == base_value {
+            theirs_value
+        } else {
+            conflicts.push(Conflict {
+                line: line.to_string(),
+                path: Vec::new(),
+                base: Vec::new(),
+                ours: Vec::new(),
+                theirs: Vec::new(),
+                value: Some(name.to_string()),
+            });
+            ours_value
+        };
+
+        if let Some(value) = value {
+            merged.push(NamedValue {
+                name: name.to_string(),
+                value: value.clone(),
+            });
+        }
+    }
+
+    merged
+}
+
+/// Lines given the same position on both sides are moved to free positions.
+fn resolve_positions(lines: &mut [Line]) -> Result<(), MergeError> {
+    if lines.len() > POSITIONS {
+        return Err(MergeError::PositionsExhausted { lines: lines.len() });
+    }
+
+    let mut taken = HashSet::new();
+    let mut clashing = Vec::new();
+
+    for (index, line) in lines.iter().enumerate() {
+        if !taken.insert(line.position) {
+            clashing.push(index);
+        }
+    }
+
+    // There are at least as many free positions as clashing lines
+    let mut free = (0..=u8::MAX).filter(|position| !taken.contains(position));
+
+    for index in clashing {
+        lines[index].position = free.next().unwrap();
+    }
+
+    Ok(())
+}
+
+/// Merges op sequences diff3 style: stretches between ops untouched on both sides are
+/// resolved as a whole, single ops edited on both sides are merged recursively.
+fn merge_ops(
+    line: &str,
+    path: &mut Vec<usize>,
+    base: &[Op],
+    ours: &[Op],
+    theirs: &[Op],
+    conflicts: &mut Vec<Conflict>,
+) -> Vec<Op> {
+    let mut merged = Vec::new();
+
+    if let Some(resolved) = resolve(base, ours, theirs) {
+        merged.extend_from_slice(resolved);
+        return merged;
+    }
+
+    for (chunk, matched) in align(base, ours, theirs, Op::eq) {
+        merge_chunk(line, path, chunk, &mut merged, conflicts);
+
+        if let Some((b, _, _)) = matched {
+            merged.push(base[b].clone());
+        }
+    }
+
+    merged
+}
+
+type Chunk<'o> = (&'o [Op], &'o [Op], &'o [Op]);
+
+/// Indices of one op in base, ours and theirs.
+type Matched = (usize, usize, usize);
+
+/// Splits the sequences at base ops matched on both sides. Each item is the chunk before
+/// a matched op and the indices of that op in base, ours and theirs; the last has none.
+fn align<'o>(
+    base: &'o [Op],
+    ours: &'o [Op],
+    theirs: &'o [Op],
+    same: impl Fn(&Op, &Op) -> bool + Copy,
+) -> Vec<(Chunk<'o>, Option<Matched>)> {
+    let mut in_ours = vec![None; base.len()];
+    let mut in_theirs = vec![None; base.len()];
+
+    for (b, o) in common_subsequence(base, ours, same) {
+        in_ours[b] = Some(o);
+    }
+
+    for (b, t) in common_subsequence(base, theirs, same) {
+        in_theirs[b] = Some(t);
+    }
+
+    let stable = (0..base.len())
+        .filter_map(|b| Some((b, in_ours[b]?, in_theirs[b]?)))
+        .map(Some)
+        .chain([None]);
+
+    let (mut b, mut o, mut t) = (0, 0, 0);
+    let mut chunks = Vec::new();
+
+    for matched in stable {
+        let (next_b, next_o, next_t) = matched.unwrap_or((base.len(), ours.len(), theirs.len()));
+        chunks.push((
+            (&base[b..next_b], &ours[o..next_o], &theirs[t..next_t]),
+            matched,
+        ));
+        (b, o, t) = (next_b + 1, next_o + 1, next_t + 1);
+    }
+
+    chunks
+}
+
+/// The side to take when at most one side changed the base.
+fn resolve<'o>(base: &'o [Op], ours: &'o [Op], theirs: &'o [Op]) -> Option<&'o [Op]> {
+    if ours == theirs || theirs == base {
+        Some(ours)
+    } else if ours == base {
+        Some(theirs)
+    } else {
+        None
+    }
+}
+
+/// Resolves a stretch changed on both sides by lining up ops of the same action.
+fn merge_chunk(
+    line: &str,
+    path: &mut Vec<usize>,
+    (base, ours, theirs): Chunk,
+    merged: &mut Vec<Op>,
+    conflicts: &mut Vec<Conflict>,
+) {
+    if let Some(resolved) = resolve(base, ours, theirs) {
+        merged.extend_from_slice(resolved);
+        return;
+    }
+
+    for ((base_gap, ours_gap, theirs_gap), matched) in
+        align(base, ours, theirs, |a, b| a.action == b.action)
+    {
+        match resolve(base_gap, ours_gap, theirs_gap) {
+            Some(resolved) => merged.extend_from_slice(resolved),
+            None => conflict(
+                line,
+                path,
+                (base_gap, ours_gap, theirs_gap),
+                merged,
+                conflicts,
+            ),
+        }
+
+        let Some((b, o, t)) = matched else {
+            continue;
+        };
+
+        let (base_op, ours_op, theirs_op) = (&base[b], &ours[o], &theirs[t]);
+
+        match merge_head(base_op, ours_op, theirs_op) {
+            Some(mut op) => {
+                path.push(merged.len());
+                op.operations = merge_ops(
+                    line,
+                    path,
+                    &base_op.operations,
+                    &ours_op.operations,
+                    &theirs_op.operations,
+                    conflicts,
+                );
+                path.pop();
+                merged.push(op);
+            }
+            None => conflict(
+                line,
+                path,
+                (&base[b..=b], &ours[o..=o], &theirs[t..=t]),
+                merged,
+                conflicts,
+            ),
+        }
+    }
+}
+
+fn conflict(
+    line: &str,
+    path: &[usize],
+    (base, ours, theirs): Chunk,
+    merged: &mut Vec<Op>,
+    conflicts: &mut Vec<Conflict>,
+) {
+    let mut conflict_path = path.to_vec();
+    conflict_path.push(merged.len());
+    conflicts.push(Conflict {
+        line: line.to_string(),
+        path: conflict_path,
+        base: base.to_vec(),
+        ours: ours.to_vec(),
+        theirs: theirs.to_vec(),
+        value: None,
+    });
+    merged.extend_from_slice(ours);
+}
+
+/// The op without its body, if at most one side changed it.
+fn merge_head(base: &Op, ours: &Op, theirs: &Op) -> Option<Op> {
+    let head = |op: &Op| Op {
+        operations: Vec::new(),
+        ..op.clone()
+    };
+    let (base, ours, theirs) = (head(base), head(ours), head(theirs));
+
+    if ours == theirs || theirs == base {
+        Some(ours)
+    } else if ours == base {
+        Some(theirs)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assets::Catalog, lang, pretty::render_op};
+
+    fn module(source: &str) -> Module {
+        lang::compile_source(source, &Catalog::load().unwrap()).unwrap()
+    }
+
+    fn ops(module: &Module, line: usize) -> Vec<String> {
+        let catalog = Catalog::load().unwrap();
+        (module.handlers[line].operations.iter())
+            .map(|op| render_op(op, &catalog))
+            .collect()
+    }
+
+    const BASE: &str = "
+function F {
+    local.a = 1
+    local.b = 2
+}
+function G {
+    local.c = 3
+}
+";
+
+    #[test]
+    fn combines_separate_edits() {
+        let ours = module(&BASE.replace("local.a = 1", "local.a = 10"));
+        let theirs = module(&BASE.replace("local.b = 2", "local.b = 20"));
+        let merged = merge(&module(BASE), &ours, &theirs).unwrap();
+
+        assert!(merged.conflicts.is_empty(), "{:?}", merged.conflicts);
+        assert_eq!(ops(&merged, 0), ["local.a = 10", "local.b = 20"]);
+    }
+
+    #[test]
+    fn flags_ops_edited_on_both_sides() {
+        let ours = module(&BASE.replace("local.a = 1", "local.a = 10"));
+        let theirs = module(&BASE.replace("local.a = 1", "local.a = 100"));
+        let merged = merge(&module(BASE), &ours, &theirs).unwrap();
+
+        assert_eq!(ops(&merged, 0), ["local.a = 10", "local.b = 2"]);
+        assert_eq!(merged.conflicts.len(), 1);
+        assert_eq!(merged.conflicts[0].line, "function F");
+        assert_eq!(merged.conflicts[0].path, [0]);
+        assert_eq!(
+            merged.conflicts[0].theirs,
+            theirs.handlers[0].operations[..1]
+        );
+    }
+
+    #[test]
+    fn keeps_lines_edited_on_the_other_side_of_a_delete() {
+        let g = BASE.find("function G").unwrap();
+        let ours = module(&BASE[..g]);
+        let theirs = module(&BASE.replace("local.c = 3", "local.c = 30"));
+        let merged = merge(&module(BASE), &ours, &theirs).unwrap();
+
+        assert_eq!(merged.handlers.len(), 2);
+        assert_eq!(ops(&merged, 1), ["local.c = 30"]);
+        assert_eq!(merged.conflicts.len(), 1);
+        assert_eq!(merged.conflicts[0].line, "function G");
+        assert!(merged.conflicts[0].ours.is_empty());
+    }
+
+    #[test]
+    fn merges_line_values() {
+        let base = module(BASE);
+        let value = |name: &str, text: &str| NamedValue {
+            name: name.to_string(),
+            value: crate::module::Value::Text {
+                text: text.to_string(),
+                parsing: crate::module::TextParsingWants2Bits::Legacy,
+            },
+        };
+        let with = |values: Vec<NamedValue>| {
+            let mut module = base.clone();
+            module.handlers[0].values = values;
+            module
+        };
+
+        let ours = with(vec![value("description", "ours")]);
+        let theirs = with(vec![value("icon", "theirs")]);
+        let merged = merge(&base, &ours, &theirs).unwrap();
+        assert_eq!(
+            merged.handlers[0].values,
+            [value("description", "ours"), value("icon", "theirs")]
+        );
+        assert!(merged.conflicts.is_empty());
+
+        let theirs = with(vec![value("description", "theirs")]);
+        let merged = merge(&base, &ours, &theirs).unwrap();
+        assert_eq!(merged.handlers[0].values, [value("description", "ours")]);
+        assert_eq!(merged.conflicts[0].value.as_deref(), Some("description"));
+    }
+
+    #[test]
+    fn fails_when_positions_run_out() {
+        let functions = |prefix: &str| {
+            let source: String = (0..200)
+                .map(|i| format!("function {prefix}{i} {{\n}}\n"))
+                .collect();
+            module(&source)
+        };
+        let error = merge(&module(""), &functions("a"), &functions("b")).unwrap_err();
+
+        assert_eq!(error, MergeError::PositionsExhausted { lines: 400 });
+    }
+}
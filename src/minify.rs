@@ -0,0 +1,251 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    diagnostic::Diagnostic,
+    module::{
+        Module, NamedValue, Number, Op, Value, VariableScopeWants2Bits, walk_ops, walk_ops_mut,
+    },
+    variables::placeholders,
+};
+
+/// Original names of renamed local variables, by their new name.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SourceMap {
+    pub locals: BTreeMap<String, String>,
+}
+
+/// Renames local variables to short names, the most used ones first, and returns how to undo
+/// it. Locals are shared with called functions, so a name is renamed the same everywhere.
+/// Global and saved variables keep their names. Fails if a local's name is computed at
+/// runtime, as it could refer to any variable.
+pub fn minify(module: &mut Module) -> Result<SourceMap, Vec<Diagnostic>> {
+    let mut uses: HashMap<String, usize> = HashMap::new();
+    let mut reserved = HashSet::new();
+    let mut computed = Vec::new();
+
+    for (index, line) in module.handlers.iter().enumerate() {
+        let mut count = |path: &[usize], values: &[NamedValue]| {
+            for value in values {
+                count_value(&value.value, &mut |name, scope| match scope {
+                    VariableScopeWants2Bits::Local if name.contains('%') => {
+                        computed.push(Diagnostic::error(
+                            index,
+                            path,
+                            format!("local variable name {name} is computed at runtime"),
+                        ));
+                    }
+                    VariableScopeWants2Bits::Local => {
+                        *uses.entry(name.to_string()).or_default() += 1
+                    }
+                    _ => {
+                        reserved.insert(name.to_string());
+                    }
+                });
+            }
+        };
+
+        count(&[], &line.values);
+        walk_ops(&line.operations, &mut |path, op| count(path, &op.values));
+    }
+
+    if !computed.is_empty() {
+        return Err(computed);
+    }
+
+    let mut locals: Vec<_> = uses.into_iter().collect();
+    locals.sort_by(|(a, a_uses), (b, b_uses)| b_uses.cmp(a_uses).then(a.cmp(b)));
+
+    let mut short_names = (0..)
+        .map(short_name)
+        .filter(|name| !reserved.contains(name));
+    let names: HashMap<String, String> = locals
+        .into_iter()
+        .map(|(name, _)| (name, short_names.next().unwrap()))
+        .collect();
+
+    rename(module, &names);
+
+    Ok(SourceMap {
+        locals: names.into_iter().map(|(old, new)| (new, old)).collect(),
+    })
+}
+
+/// Gives minified locals their original names back.
+pub fn restore(module: &mut Module, map: &SourceMap) {
+    let names = map.locals.clone().into_iter().collect();
+    rename(module, &names);
+}
+
+/// `a` to `Z`, then `aa`, `ab` and so on.
+fn short_name(mut index: usize) -> String {
+    const LETTERS: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+    let mut name = Vec::new();
+
+    loop {
+        name.push(LETTERS[index % LETTERS.len()]);
+        index /= LETTERS.len();
+
+        if index == 0 {
+            break;
+        }
+
+        index -= 1;
+    }
+
+    name.reverse();
+    String::from_utf8(name).unwrap()
+}
+
+/// Calls `f` with every variable the value names, including `%var..(..)` placeholders.
+fn count_value(value: &Value, f: &mut impl FnMut(&str, VariableScopeWants2Bits)) {
+    match value {
+        Value::Array { values } => {
+            for value in values {
+                count_value(value, f);
+            }
+        }
+        Value::Variable { variable, scope } => {
+            f(variable, *scope);
+            count_text(variable, f);
+        }
+        Value::Text { text, .. }
+        | Value::Number {
+            number: Number::Calc(text),
+        } => count_text(text, f),
+        _ => {}
+    }
+}
+
+fn count_text(text: &str, f: &mut impl FnMut(&str, VariableScopeWants2Bits)) {
+    for placeholder in placeholders(text) {
+        f(placeholder.name, placeholder.scope);
+    }
+}
+
+/// Renames locals everywhere in the module, including unresolved merge conflicts.
+fn rename(module: &mut Module, names: &HashMap<String, String>) {
+    let rename_ops = |ops: &mut [Op]| {
+        walk_ops_mut(ops, &mut |_, op| {
+            for value in &mut op.values {
+                rename_value(&mut value.value, names);
+            }
+        });
+    };
+
+    for line in &mut module.handlers {
+        for value in &mut line.values {
+            rename_value(&mut value.value, names);
+        }
+
+        rename_ops(&mut line.operations);
+    }
+
+    for conflict in &mut module.conflicts {
+        rename_ops(&mut conflict.base);
+        rename_ops(&mut conflict.ours);
+        rename_ops(&mut conflict.theirs);
+    }
+}
+
+fn rename_value(value: &mut Value, names: &HashMap<String, String>) {
+    match value {
+        Value::Array { values } => {
+            for value in values {
+                rename_value(value, names);
+            }
+        }
+        Value::Variable { variable, scope } => {
+            *variable = rename_text(variable, names);
+
+            if *scope == VariableScopeWants2Bits::Local
+                && let Some(name) = names.get(variable.as_str())
+            {
+                variable.clone_from(name);
+            }
+        }
+        Value::Text { text, .. }
+        | Value::Number {
+            number: Number::Calc(text),
+        } => *text = rename_text(text, names),
+        _ => {}
+    }
+}
+
+fn rename_text(text: &str, names: &HashMap<String, String>) -> String {
+    let mut renamed = text.to_string();
+
+    // Back to front, so earlier ranges stay valid
+    for placeholder in placeholders(text).into_iter().rev() {
+        if placeholder.scope == VariableScopeWants2Bits::Local
+            && let Some(name) = names.get(placeholder.name)
+        {
+            renamed.replace_range(placeholder.range, name);
+        }
+    }
+
+    renamed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assets::Catalog, lang, pretty::render_op};
+
+    #[test]
+    fn restores_what_it_minified() {
+        let catalog = Catalog::load().unwrap();
+        let original = lang::compile_source(
+            r#"
+function F {
+    local.counter = 1
+    local.counter = variable.add(value: [local.counter, local.step])
+    local.message = "count %var_local(counter)"
+    global.message = "%var_local(step) of %var(counter)"
+}
+"#,
+            &catalog,
+        )
+        .unwrap();
+        let mut module = original.clone();
+        let map = minify(&mut module).unwrap();
+
+        let ops: Vec<_> = (module.handlers[0].operations.iter())
+            .map(|op| render_op(op, &catalog))
+            .collect();
+        assert_eq!(
+            ops,
+            [
+                "local.a = 1",
+                "local.a = variable.add(value: [local.a, local.b])",
+                r#"local.c = "count %var_local(a)""#,
+                r#"global.message = "%var_local(b) of %var(counter)""#,
+            ]
+        );
+
+        restore(&mut module, &map);
+        assert_eq!(module, original);
+    }
+
+    #[test]
+    fn refuses_computed_local_names() {
+        let catalog = Catalog::load().unwrap();
+        let mut module = lang::compile_source(
+            "
+function F {
+    local.x = 1
+}
+",
+            &catalog,
+        )
+        .unwrap();
+        let op = &mut module.handlers[0].operations[0];
+        op.values[0].value = Value::Variable {
+            variable: "%var_local(name)".to_string(),
+            scope: VariableScopeWants2Bits::Local,
+        };
+
+        assert!(minify(&mut module).is_err());
+    }
+}
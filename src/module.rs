@@ -1,23 +1,72 @@
+use std::fmt::Display;
+
 use heck::ToUpperCamelCase;
-use serde::{Deserialize, Serialize};
+use serde::{
+    Deserialize, Serialize, Serializer,
+    ser::{SerializeMap, SerializeSeq, SerializeStruct},
+};
 
-use crate::generated::{ActionIdWants11Bits, GameValueId};
+use crate::generated::{ActionIdWants11Bits, EventId, GameValueId, SoundId};
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Module {
     #[serde(deserialize_with = "deserialize_handlers")]
     pub handlers: Vec<Line>,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        serialize_with = "serialize_present"
+    )]
+    pub conflicts: Vec<Conflict>,
+}
+
+/// An unresolved merge conflict. The merged line holds `ours` starting at `path`, the
+/// other versions are kept here until someone picks one.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Conflict {
+    pub line: String,
+    #[serde(default)]
+    pub path: Vec<usize>,
+    pub base: Vec<Op>,
+    pub ours: Vec<Op>,
+    pub theirs: Vec<Op>,
+    /// The line value both sides changed, when the conflict isn't in the ops.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Line {
     #[serde(rename = "type")]
     pub line_type: LineTypeWants2Bits,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub event: Option<EventId>,
     pub position: u8,
     pub operations: Vec<Op>,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        serialize_with = "serialize_present"
+    )]
+    pub values: Vec<NamedValue>,
+}
+
+impl Line {
+    pub fn label(&self) -> String {
+        match (&self.name, &self.event) {
+            (Some(name), _) => name.clone(),
+            (None, Some(event)) => serde_json::to_value(event)
+                .ok()
+                .and_then(|event| event.as_str().map(str::to_string))
+                .unwrap_or_else(|| format!("{event:?}")),
+            (None, None) => format!("#{}", self.position),
+        }
+    }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 #[repr(u8)]
 pub enum LineTypeWants2Bits {
@@ -26,31 +75,221 @@ pub enum LineTypeWants2Bits {
     Event,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+impl Display for LineTypeWants2Bits {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Process => "process",
+            Self::Function => "function",
+            Self::Event => "event",
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 pub struct Op {
     pub action: ActionIdWants11Bits,
     pub values: Vec<NamedValue>,
+    #[serde(default)]
+    pub selection: Option<Selection>,
+    #[serde(default)]
+    pub is_inverted: bool,
+    #[serde(default)]
+    pub conditional: Option<Conditional>,
+    #[serde(default)]
+    pub operations: Vec<Op>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Conditional {
+    pub action: ActionIdWants11Bits,
+    #[serde(default)]
+    pub is_inverted: bool,
+}
+
+impl Serialize for Op {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("Op", 6)?;
+        state.serialize_field("action", &self.action)?;
+        state.serialize_field("values", &OpValues(self))?;
+
+        // Fields that can be skipped are written as `Some`, which the binary output marks
+        // with a presence bit
+        match &self.selection {
+            Some(_) => state.serialize_field("selection", &self.selection)?,
+            None => state.skip_field("selection")?,
+        }
+
+        if self.is_inverted {
+            state.serialize_field("is_inverted", &Some(true))?;
+        } else {
+            state.skip_field("is_inverted")?;
+        }
+
+        match &self.conditional {
+            Some(_) => state.serialize_field("conditional", &self.conditional)?,
+            None => state.skip_field("conditional")?,
+        }
+
+        if self.operations.is_empty() {
+            state.skip_field("operations")?;
+        } else {
+            state.serialize_field("operations", &Some(&self.operations))?;
+        }
+
+        state.end()
+    }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+/// Binary output sizes array lengths by the maximum the catalog allows for the argument.
+struct OpValues<'a>(&'a Op);
+
+impl Serialize for OpValues<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            return self.0.values.serialize(serializer);
+        }
+
+        let mut seq = serializer.serialize_seq(Some(self.0.values.len()))?;
+
+        for value in &self.0.values {
+            let limit = self.0.action.array_limit(&value.name).or_else(|| {
+                let conditional = self.0.conditional?;
+                conditional.action.array_limit(&value.name)
+            });
+
+            match (&value.value, limit) {
+                (Value::Array { values }, Some(limit)) => seq.serialize_element(&BoundedArray {
+                    name: &value.name,
+                    values,
+                    limit,
+                })?,
+                _ => seq.serialize_element(value)?,
+            }
+        }
+
+        seq.end()
+    }
+}
+
+struct BoundedArray<'a> {
+    name: &'a str,
+    values: &'a [Value],
+    limit: u8,
+}
+
+impl Serialize for BoundedArray<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        const LENGTHS: [&str; 9] = [
+            "ArrayWants0Bits",
+            "ArrayWants1Bits",
+            "ArrayWants2Bits",
+            "ArrayWants3Bits",
+            "ArrayWants4Bits",
+            "ArrayWants5Bits",
+            "ArrayWants6Bits",
+            "ArrayWants7Bits",
+            "ArrayWants8Bits",
+        ];
+
+        struct Tagged<'a>(&'a BoundedArray<'a>);
+
+        impl Serialize for Tagged<'_> {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                let bits = self.0.limit.checked_ilog2().map_or(0, |bits| bits + 1);
+                let mut state = serializer.serialize_struct("Value", 2)?;
+                state.serialize_field("type", "array")?;
+                state.serialize_field(
+                    "values",
+                    &NewtypeArray(LENGTHS[bits as usize], self.0.values),
+                )?;
+                state.end()
+            }
+        }
+
+        struct NewtypeArray<'a>(&'static str, &'a [Value]);
+
+        impl Serialize for NewtypeArray<'_> {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_newtype_struct(self.0, self.1)
+            }
+        }
+
+        let mut state = serializer.serialize_struct("NamedValue", 2)?;
+        state.serialize_field("name", self.name)?;
+        state.serialize_field("value", &Tagged(self))?;
+        state.end()
+    }
+}
+
+impl Op {
+    pub fn value(&self, name: &str) -> Option<&Value> {
+        self.values
+            .iter()
+            .find(|value| value.name == name)
+            .map(|value| &value.value)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Selection {
+    #[serde(rename = "type")]
+    pub selection_type: SelectionTypeWants5Bits,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[repr(u8)]
+pub enum SelectionTypeWants5Bits {
+    Current,
+    Default,
+    DefaultEntity,
+    Selected,
+    Killer,
+    Damager,
+    Victim,
+    Shooter,
+    KillerEntity,
+    DamagerEntity,
+    VictimEntity,
+    ShooterEntity,
+    Projectile,
+    LastEntity,
+    LastMob,
+    RandomPlayer,
+    RandomEntity,
+    RandomMob,
+    AllPlayers,
+    AllEntities,
+    AllMobs,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub struct NamedValue {
-    name: String,
-    #[serde(deserialize_with = "deserialize_value")]
-    value: Value,
+    pub name: String,
+    #[serde(
+        serialize_with = "serialize_value",
+        deserialize_with = "deserialize_value"
+    )]
+    pub value: Value,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case", tag = "type")]
 pub enum Value {
     Array {
+        #[serde(
+            serialize_with = "serialize_array",
+            deserialize_with = "deserialize_array"
+        )]
         values: Vec<Value>,
     },
     Block {
         block: String,
     },
+    /// Kept as exported, `fmt` writes them in SCREAMING_SNAKE_CASE.
     Enum {
-        #[serde(rename = "enum", deserialize_with = "deserialize_upper_camel")]
+        #[serde(rename = "enum", serialize_with = "serialize_enum")]
         value: String,
     },
     Item {
@@ -66,18 +305,24 @@ pub enum Value {
     Number {
         number: Number,
     },
-    Particle,
+    /// Particle fields aren't modelled yet, so the exported value is kept to write back.
+    Particle {
+        #[serde(skip)]
+        raw: serde_json::Value,
+    },
     Potion {
         potion: String,
         amplifier: i16,
         duration: i16,
     },
     Sound {
-        sound: String,
+        sound: SoundId,
         pitch: f32,
         volume: f32,
-        variaton: String,
-        source: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        variation: Option<String>,
+        #[serde(default)]
+        source: SoundSourceWants4Bits,
     },
     Text {
         text: String,
@@ -97,10 +342,14 @@ pub enum Value {
         selection: String,
     },
 
-    Error,
+    /// A value that didn't load, kept as exported to write back unchanged.
+    Error {
+        #[serde(skip)]
+        raw: serde_json::Value,
+    },
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[serde(untagged)]
 #[repr(u8)]
 pub enum Number {
@@ -108,7 +357,24 @@ pub enum Number {
     Calc(String),
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+impl Serialize for Number {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            // Whole numbers are written the way the editor exports them
+            Self::Simple(number)
+                if serializer.is_human_readable()
+                    && number.fract() == 0.0
+                    && number.abs() < i64::MAX as f64 =>
+            {
+                serializer.serialize_i64(*number as i64)
+            }
+            Self::Simple(number) => serializer.serialize_f64(*number),
+            Self::Calc(expression) => serializer.serialize_str(expression),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 #[repr(u8)]
 pub enum TextParsingWants2Bits {
@@ -116,7 +382,24 @@ pub enum TextParsingWants2Bits {
     Plain,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[repr(u8)]
+pub enum SoundSourceWants4Bits {
+    #[default]
+    Master,
+    Music,
+    Record,
+    Weather,
+    Block,
+    Hostile,
+    Neutral,
+    Player,
+    Ambient,
+    Voice,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 #[repr(u8)]
 pub enum VariableScopeWants2Bits {
@@ -125,19 +408,123 @@ pub enum VariableScopeWants2Bits {
     Save,
 }
 
+/// Visits every op depth-first, passing the indices leading to it from the top of the line.
+pub fn walk_ops<'o>(ops: &'o [Op], f: &mut impl FnMut(&[usize], &'o Op)) {
+    fn walk<'o>(ops: &'o [Op], path: &mut Vec<usize>, f: &mut impl FnMut(&[usize], &'o Op)) {
+        for (index, op) in ops.iter().enumerate() {
+            path.push(index);
+            f(path, op);
+            walk(&op.operations, path, f);
+            path.pop();
+        }
+    }
+
+    walk(ops, &mut Vec::new(), f);
+}
+
+/// Like [`walk_ops`] but with mutable access to every op.
+pub fn walk_ops_mut(ops: &mut [Op], f: &mut impl FnMut(&[usize], &mut Op)) {
+    fn walk(ops: &mut [Op], path: &mut Vec<usize>, f: &mut impl FnMut(&[usize], &mut Op)) {
+        for (index, op) in ops.iter_mut().enumerate() {
+            path.push(index);
+            f(path, op);
+            walk(&mut op.operations, path, f);
+            path.pop();
+        }
+    }
+
+    walk(ops, &mut Vec::new(), f);
+}
+
+impl Value {
+    /// An error standing for the editor's empty `{}` slot.
+    pub fn empty() -> Self {
+        Self::Error {
+            raw: serde_json::Value::Object(serde_json::Map::new()),
+        }
+    }
+
+    pub fn is_empty_slot(&self) -> bool {
+        matches!(self, Self::Error { raw } if raw.as_object().is_some_and(|raw| raw.is_empty()))
+    }
+
+    /// Loads a value, keeping the exported JSON of values that aren't fully modelled.
+    fn load(json: serde_json::Value) -> Self {
+        match serde_json::from_value(json.clone()) {
+            Ok(Self::Particle { .. }) => Self::Particle { raw: json },
+            Ok(value) => value,
+            Err(_) => Self::Error { raw: json },
+        }
+    }
+}
+
+/// Values kept as exported JSON are written back the same way.
+fn serialize_value<S: Serializer>(value: &Value, serializer: S) -> Result<S::Ok, S::Error> {
+    match value {
+        Value::Error { raw } | Value::Particle { raw }
+            if serializer.is_human_readable() && !raw.is_null() =>
+        {
+            raw.serialize(serializer)
+        }
+        Value::Error { .. } if serializer.is_human_readable() => {
+            serializer.serialize_map(Some(0))?.end()
+        }
+        value => value.serialize(serializer),
+    }
+}
+
 fn deserialize_value<'de, D>(deserializer: D) -> Result<Value, D::Error>
 where
     D: serde::Deserializer<'de>,
 {
-    Ok(Value::deserialize(deserializer).unwrap_or(Value::Error))
+    Ok(Value::load(Deserialize::deserialize(deserializer)?))
+}
+
+/// Elements are written like argument values, so empty slots go back out as `{}`.
+fn serialize_array<S: Serializer>(values: &[Value], serializer: S) -> Result<S::Ok, S::Error> {
+    struct Slot<'a>(&'a Value);
+
+    impl Serialize for Slot<'_> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serialize_value(self.0, serializer)
+        }
+    }
+
+    let mut seq = serializer.serialize_seq(Some(values.len()))?;
+
+    for value in values {
+        seq.serialize_element(&Slot(value))?;
+    }
+
+    seq.end()
 }
 
-fn deserialize_upper_camel<'de, D>(deserializer: D) -> Result<String, D::Error>
+/// Elements that don't parse, like the `{}` editor exports pad empty slots with, become
+/// errors on their own instead of turning the whole array into one. Slots keep their place.
+fn deserialize_array<'de, D>(deserializer: D) -> Result<Vec<Value>, D::Error>
 where
     D: serde::Deserializer<'de>,
 {
-    let s: String = Deserialize::deserialize(deserializer)?;
-    Ok(s.to_upper_camel_case())
+    let values: Vec<serde_json::Value> = Deserialize::deserialize(deserializer)?;
+
+    Ok(values.into_iter().map(Value::load).collect())
+}
+
+/// Writes a field that may be skipped as `Some`, so binary output marks it present.
+fn serialize_present<T: Serialize, S: Serializer>(
+    value: &T,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.serialize_some(value)
+}
+
+/// The compiled module has always taken enums in UpperCamelCase.
+fn serialize_enum<S: Serializer>(value: &str, serializer: S) -> Result<S::Ok, S::Error> {
+    if serializer.is_human_readable() {
+        serializer.serialize_str(value)
+    } else {
+        serializer.serialize_str(&value.to_upper_camel_case())
+    }
 }
 
 fn deserialize_handlers<'de, D>(deserializer: D) -> Result<Vec<Line>, D::Error>
@@ -0,0 +1,66 @@
+use std::fmt::{self, Display};
+
+use crate::{assets::Catalog, fold, inline, module::Module, prune};
+
+/// Something a pass rewrote, at the op it started from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Change {
+    pub line: usize,
+    pub path: Vec<usize>,
+    pub message: String,
+}
+
+impl Change {
+    pub fn new(line: usize, path: &[usize], message: impl Into<String>) -> Self {
+        Self {
+            line,
+            path: path.to_vec(),
+            message: message.into(),
+        }
+    }
+
+    pub fn display<'a>(&'a self, module: &'a Module) -> impl Display + 'a {
+        DisplayChange {
+            change: self,
+            module,
+        }
+    }
+}
+
+struct DisplayChange<'a> {
+    change: &'a Change,
+    module: &'a Module,
+}
+
+impl Display for DisplayChange<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let line = &self.module.handlers[self.change.line];
+        write!(f, "{} {}", line.line_type, line.label())?;
+
+        for index in &self.change.path {
+            write!(f, " / op {index}")?;
+        }
+
+        write!(f, ": {}", self.change.message)
+    }
+}
+
+/// Runs every pass over the module, logging what each one changed when `report` is set.
+pub fn optimize(module: &mut Module, catalog: &Catalog, report: bool) {
+    run(module, report, |module| fold::fold(module, catalog));
+    run(module, report, |module| prune::prune(module, catalog));
+    run(module, report, inline::inline);
+    run(module, report, inline::outline);
+}
+
+fn run(module: &mut Module, report: bool, pass: impl FnOnce(&mut Module) -> Vec<Change>) {
+    // Changes point into the module as it was before the pass
+    let before = report.then(|| module.clone());
+    let changes = pass(module);
+
+    if let Some(before) = before {
+        for change in changes {
+            log::info!("{}", change.display(&before));
+        }
+    }
+}
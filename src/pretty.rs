@@ -0,0 +1,252 @@
+use std::fmt::Write;
+
+use heck::{ToShoutySnakeCase, ToUpperCamelCase};
+
+use crate::{
+    assets::{Action, Catalog},
+    generated::{ActionIdWants11Bits, ActionObject},
+    module::{Line, Module, NamedValue, Number, Op, TextParsingWants2Bits, Value},
+    types::type_name,
+};
+
+const INDENT: &str = "    ";
+
+/// Renders every line of the module as indented pseudocode.
+pub fn render(module: &Module, catalog: &Catalog) -> String {
+    let mut lines: Vec<&Line> = module.handlers.iter().collect();
+    lines.sort_by_key(|line| line.position);
+
+    lines
+        .into_iter()
+        .map(|line| render_line(line, catalog))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+pub fn render_line(line: &Line, catalog: &Catalog) -> String {
+    let mut out = format!("{} {} {{\n", line.line_type, line.label());
+    render_ops(&mut out, &line.operations, catalog, 1);
+    out.push_str("}\n");
+    out
+}
+
+fn render_ops(out: &mut String, ops: &[Op], catalog: &Catalog, depth: usize) {
+    for op in ops {
+        let indent = INDENT.repeat(depth);
+        out.push_str(&indent);
+        out.push_str(&render_op(op, catalog));
+
+        if op.operations.is_empty() && !is_container(catalog.action(op.action)) {
+            out.push('\n');
+            continue;
+        }
+
+        out.push_str(" {\n");
+        render_ops(out, &op.operations, catalog, depth + 1);
+        writeln!(out, "{indent}}}").unwrap();
+    }
+}
+
+fn is_container(action: &Action) -> bool {
+    type_name(action.action_type).starts_with("container")
+}
+
+/// The head of an op, without its body.
+pub fn render_op(op: &Op, catalog: &Catalog) -> String {
+    let action = catalog.action(op.action);
+
+    match op.action {
+        ActionIdWants11Bits::CallFunction => {
+            return format!("call {}", render_target(op, "function_name"));
+        }
+        ActionIdWants11Bits::StartProcess => {
+            let rest = render_args(op.values.iter().filter(|v| v.name != "process_name"));
+            return format!("start {}{rest}", render_target(op, "process_name"));
+        }
+        ActionIdWants11Bits::Else => return "else".to_string(),
+        ActionIdWants11Bits::SetVariableValue => {
+            if let (Some(variable), Some(value)) = (op.value("variable"), op.value("value")) {
+                return format!("{} = {}", render_value(variable), render_value(value));
+            }
+        }
+        _ => {}
+    }
+
+    if let Some(conditional) = op.conditional {
+        let inner = catalog.action(conditional.action);
+        let not = if conditional.is_inverted { "not " } else { "" };
+
+        return format!(
+            "{} {} {not}{}",
+            type_name(action.object),
+            action.name,
+            render_condition(inner, &op.values)
+        );
+    }
+
+    if action.boolean {
+        let not = if op.is_inverted { "not " } else { "" };
+        return format!("if {not}{}", render_condition(action, &op.values));
+    }
+
+    let (assigned, args): (Vec<_>, Vec<_>) = op
+        .values
+        .iter()
+        .partition(|value| action.assign.iter().any(|arg| arg.id == value.name));
+
+    let selection = op
+        .selection
+        .map(|selection| format!("[{}]", type_name(selection.selection_type)))
+        .unwrap_or_default();
+
+    let call = match action.object {
+        ActionObject::Repeat => format!("repeat {}{}", action.name, render_args(args)),
+        object => format!(
+            "{}.{}{selection}{}",
+            type_name(object),
+            action.name,
+            render_args(args)
+        ),
+    };
+
+    if assigned.is_empty() {
+        call
+    } else {
+        let targets: Vec<_> = assigned
+            .iter()
+            .map(|value| render_value(&value.value))
+            .collect();
+        format!("{} = {call}", targets.join(", "))
+    }
+}
+
+fn render_condition(action: &Action, values: &[NamedValue]) -> String {
+    let operator = match action.id {
+        ActionIdWants11Bits::IfVariableEquals => Some("=="),
+        ActionIdWants11Bits::IfVariableNotEquals => Some("!="),
+        ActionIdWants11Bits::IfVariableGreater => Some(">"),
+        ActionIdWants11Bits::IfVariableGreaterOrEquals => Some(">="),
+        ActionIdWants11Bits::IfVariableLess => Some("<"),
+        ActionIdWants11Bits::IfVariableLessOrEquals => Some("<="),
+        _ => None,
+    };
+
+    let find = |name: &str| values.iter().find(|value| value.name == name);
+
+    match (operator, find("value"), find("compare")) {
+        (Some(operator), Some(value), Some(compare)) => format!(
+            "{} {operator} {}",
+            render_value(&value.value),
+            render_value(&compare.value)
+        ),
+        _ => format!(
+            "{}.{}{}",
+            type_name(action.object),
+            action.name,
+            render_args(values)
+        ),
+    }
+}
+
+fn render_target(op: &Op, arg: &str) -> String {
+    match op.value(arg) {
+        Some(Value::Text { text, .. }) if !text.contains('%') => text.clone(),
+        Some(value) => format!("({})", render_value(value)),
+        None => "?".to_string(),
+    }
+}
+
+fn render_args<'a>(values: impl IntoIterator<Item = &'a NamedValue>) -> String {
+    let args: Vec<_> = values
+        .into_iter()
+        .map(|value| format!("{}: {}", value.name, render_value(&value.value)))
+        .collect();
+
+    format!("({})", args.join(", "))
+}
+
+/// 32 bit FNV-1a, so digests stay the same across Rust versions.
+fn fnv1a(bytes: &[u8]) -> u32 {
+    bytes.iter().fold(0x811c9dc5, |hash, &byte| {
+        (hash ^ byte as u32).wrapping_mul(0x01000193)
+    })
+}
+
+pub fn render_value(value: &Value) -> String {
+    match value {
+        Value::Array { values } => {
+            // Empty slots the editor pads arrays with aren't worth showing
+            let values: Vec<_> = (values.iter())
+                .filter(|value| !value.is_empty_slot())
+                .map(render_value)
+                .collect();
+            format!("[{}]", values.join(", "))
+        }
+        Value::Block { block } => format!("block({block})"),
+        // UpperCamelCase reads better, unless it wouldn't convert back to the same name
+        Value::Enum { value } => match value.to_upper_camel_case() {
+            camel if camel.to_shouty_snake_case() == *value => camel,
+            _ => value.clone(),
+        },
+        // Items are long base64 blobs, a short digest is enough to notice changes
+        Value::Item { item } => format!("item#{:08x}", fnv1a(item.as_bytes())),
+        Value::Location {
+            x,
+            y,
+            z,
+            yaw,
+            pitch,
+        } => format!("loc({x}, {y}, {z}, {yaw}, {pitch})"),
+        Value::Number {
+            number: Number::Simple(number),
+        } => number.to_string(),
+        Value::Number {
+            number: Number::Calc(expression),
+        } => expression.clone(),
+        Value::Particle { .. } => "particle".to_string(),
+        Value::Potion {
+            potion,
+            amplifier,
+            duration,
+        } => format!("potion({potion}, {amplifier}, {duration})"),
+        Value::Sound {
+            sound,
+            pitch,
+            volume,
+            variation,
+            source,
+        } => {
+            let variation = variation
+                .as_ref()
+                .map(|variation| format!(", {variation:?}"))
+                .unwrap_or_default();
+            format!(
+                "sound({}, {volume}, {pitch}, {}{variation})",
+                type_name(sound),
+                type_name(source)
+            )
+        }
+        Value::Text {
+            text,
+            parsing: TextParsingWants2Bits::Plain,
+        } => format!("{text:?}"),
+        Value::Text {
+            text,
+            parsing: TextParsingWants2Bits::Legacy,
+        } => format!("legacy{text:?}"),
+        Value::Variable { variable, scope } => format!("{}.{variable}", type_name(scope)),
+        Value::Vector { x, y, z } => format!("vec({x}, {y}, {z})"),
+        Value::GameValue {
+            game_value,
+            selection,
+        } => {
+            let selection = serde_json::from_str::<serde_json::Value>(selection)
+                .ok()
+                .and_then(|selection| selection["type"].as_str().map(str::to_string))
+                .map(|selection| format!("[{selection}]"))
+                .unwrap_or_default();
+            format!("value.{}{selection}", type_name(game_value))
+        }
+        Value::Error { .. } => "?".to_string(),
+    }
+}
@@ -0,0 +1,244 @@
+use std::collections::HashSet;
+
+use crate::{
+    assets::Catalog,
+    callgraph::CallGraph,
+    generated::{ActionIdWants11Bits, ActionType, ArgType},
+    module::{LineTypeWants2Bits, Module, Op},
+    optimize::Change,
+};
+
+/// Ops that always leave the list they are in.
+fn exits(op: &Op) -> bool {
+    matches!(
+        op.action,
+        ActionIdWants11Bits::ControlReturnFunction
+            | ActionIdWants11Bits::ControlEndThread
+            | ActionIdWants11Bits::ControlStopRepeat
+            | ActionIdWants11Bits::ControlSkipIteration
+            | ActionIdWants11Bits::ControlCallException
+    )
+}
+
+/// Removes ops after unconditional exits, empty containers and functions and processes
+/// that no event reaches. Modules without any event keep all their lines.
+pub fn prune(module: &mut Module, catalog: &Catalog) -> Vec<Change> {
+    let mut changes = Vec::new();
+
+    for (index, line) in module.handlers.iter_mut().enumerate() {
+        prune_ops(
+            catalog,
+            index,
+            &mut line.operations,
+            &mut Vec::new(),
+            &mut changes,
+        );
+
+        // Functions and processes return at their end anyway
+        if line.line_type != LineTypeWants2Bits::Event
+            && line
+                .operations
+                .last()
+                .is_some_and(|op| op.action == ActionIdWants11Bits::ControlReturnFunction)
+        {
+            let last = line.operations.len() - 1;
+            changes.push(Change::new(
+                index,
+                &[last],
+                "removed return_function at the end",
+            ));
+            line.operations.pop();
+        }
+    }
+
+    let events: Vec<usize> = (module.handlers.iter().enumerate())
+        .filter(|(_, line)| line.line_type == LineTypeWants2Bits::Event)
+        .map(|(index, _)| index)
+        .collect();
+
+    // Without events the module is a library, called from elsewhere
+    if events.is_empty() {
+        return changes;
+    }
+
+    let calls = CallGraph::build(module);
+    let reachable = calls.reachable(events);
+    // Computed names could reach any line of their kind
+    let dynamic: HashSet<LineTypeWants2Bits> = (calls.calls.iter())
+        .filter(|call| call.is_dynamic())
+        .map(|call| call.kind.line_type())
+        .collect();
+    let unreachable: HashSet<usize> = (module.handlers.iter().enumerate())
+        .filter(|(index, line)| !reachable.contains(index) && !dynamic.contains(&line.line_type))
+        .map(|(index, _)| index)
+        .collect();
+
+    for &index in &unreachable {
+        changes.push(Change::new(index, &[], "removed, no event reaches it"));
+    }
+
+    changes.sort_by_key(|change| change.line);
+    let lines = std::mem::take(&mut module.handlers).into_iter().enumerate();
+    module.handlers = lines
+        .filter(|(index, _)| !unreachable.contains(index))
+        .map(|(_, line)| line)
+        .collect();
+
+    changes
+}
+
+fn prune_ops(
+    catalog: &Catalog,
+    line: usize,
+    ops: &mut Vec<Op>,
+    path: &mut Vec<usize>,
+    changes: &mut Vec<Change>,
+) {
+    if let Some(exit) = ops.iter().position(exits)
+        && exit + 1 < ops.len()
+    {
+        path.push(exit + 1);
+        changes.push(Change::new(
+            line,
+            path,
+            format!(
+                "removed {} ops after {}",
+                ops.len() - exit - 1,
+                catalog.action(ops[exit].action).name
+            ),
+        ));
+        path.pop();
+        ops.truncate(exit + 1);
+    }
+
+    let mut old = std::mem::take(ops).into_iter().enumerate().peekable();
+
+    while let Some((index, mut op)) = old.next() {
+        path.push(index);
+        prune_ops(catalog, line, &mut op.operations, path, changes);
+        let action = catalog.action(op.action);
+
+        if !op.operations.is_empty()
+            || !matches!(
+                action.action_type,
+                ActionType::Container | ActionType::ContainerWithConditional
+            )
+        {
+            ops.push(op);
+        } else if action.boolean {
+            let otherwise = old.next_if(|(_, next)| next.action == ActionIdWants11Bits::Else);
+
+            match otherwise {
+                Some((else_index, mut otherwise)) => {
+                    *path.last_mut().unwrap() = else_index;
+                    prune_ops(catalog, line, &mut otherwise.operations, path, changes);
+                    *path.last_mut().unwrap() = index;
+
+                    if otherwise.operations.is_empty() {
+                        changes.push(Change::new(
+                            line,
+                            path,
+                            format!("removed empty {} and its else", action.name),
+                        ));
+                    } else {
+                        changes.push(Change::new(
+                            line,
+                            path,
+                            format!("inverted empty {} to run the else body", action.name),
+                        ));
+                        op.is_inverted = !op.is_inverted;
+                        op.operations = otherwise.operations;
+                        ops.push(op);
+                    }
+                }
+                None => changes.push(Change::new(
+                    line,
+                    path,
+                    format!("removed empty {}", action.name),
+                )),
+            }
+        } else if op.action == ActionIdWants11Bits::Else
+            || !(matches!(
+                op.action,
+                ActionIdWants11Bits::RepeatForever | ActionIdWants11Bits::RepeatWhile
+            ) || sets_variables(catalog, &op))
+        {
+            changes.push(Change::new(
+                line,
+                path,
+                format!("removed empty {}", action.name),
+            ));
+        } else {
+            ops.push(op);
+        }
+
+        path.pop();
+    }
+}
+
+/// Whether running the op writes a variable, like a loop variable or a measured time.
+fn sets_variables(catalog: &Catalog, op: &Op) -> bool {
+    op.values.iter().any(|value| {
+        let owner = catalog.arg_owner(op, &value.name);
+
+        owner.assigns(&value.name)
+            || owner
+                .arg(&value.name)
+                .is_some_and(|arg| arg.arg_type == ArgType::Variable)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{lang, pretty::render_op};
+
+    fn pruned(source: &str) -> (Module, Vec<Change>) {
+        let catalog = Catalog::load().unwrap();
+        let mut module = lang::compile_source(source, &catalog).unwrap();
+        let changes = prune(&mut module, &catalog);
+        (module, changes)
+    }
+
+    #[test]
+    fn drops_ops_after_an_exit() {
+        let (module, changes) = pruned(
+            "
+function F {
+    local.a = 1
+    code.break()
+    local.b = 2
+    local.c = 3
+}
+",
+        );
+
+        let ops = &module.handlers[0].operations;
+        assert_eq!(ops.len(), 2);
+        assert_eq!(ops[1].action, ActionIdWants11Bits::ControlEndThread);
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].path, [2]);
+    }
+
+    #[test]
+    fn runs_the_else_body_of_an_empty_if() {
+        let (module, _) = pruned(
+            "
+function F {
+    if local.x == 1 {
+    }
+    else {
+        local.y = 2
+    }
+}
+",
+        );
+
+        let catalog = Catalog::load().unwrap();
+        let ops = &module.handlers[0].operations;
+        assert_eq!(ops.len(), 1);
+        assert!(ops[0].is_inverted);
+        assert_eq!(ops[0].operations.len(), 1);
+        assert_eq!(render_op(&ops[0].operations[0], &catalog), "local.y = 2");
+    }
+}
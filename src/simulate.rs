@@ -0,0 +1,116 @@
+use std::collections::HashMap;
+
+use crate::{
+    assets::Catalog,
+    generated::{EventId, GameValueId},
+    interpreter::{Interpreter, Locals, RuntimeError, Traced, Val},
+    module::{LineTypeWants2Bits, Module, SelectionTypeWants5Bits},
+};
+
+/// One event to fire and the data the handlers see while it runs.
+#[derive(Debug, Clone)]
+pub struct Event {
+    pub id: EventId,
+    /// Uuid of the player or entity the event is about.
+    pub default: Option<String>,
+    pub roles: HashMap<SelectionTypeWants5Bits, String>,
+    /// Event game values such as `event_damage`.
+    pub values: HashMap<GameValueId, Val>,
+}
+
+impl Event {
+    pub fn new(id: EventId) -> Self {
+        Self {
+            id,
+            default: None,
+            roles: HashMap::new(),
+            values: HashMap::new(),
+        }
+    }
+}
+
+/// What firing one event did.
+#[derive(Debug, Clone)]
+pub struct Fired {
+    pub event: EventId,
+    /// Indices of the event lines that ran.
+    pub handlers: Vec<usize>,
+    /// Only cancellable events can end up cancelled.
+    pub cancelled: bool,
+    pub trace: Vec<Traced>,
+    pub errors: Vec<RuntimeError>,
+}
+
+/// Fires events at a module running on the stub world. Globals, saved variables and the
+/// world carry over from one event to the next.
+pub struct Simulation<'m> {
+    pub interpreter: Interpreter<'m>,
+}
+
+impl<'m> Simulation<'m> {
+    pub fn new(module: &'m Module, catalog: &'m Catalog) -> Self {
+        Self {
+            interpreter: Interpreter::new(module, catalog),
+        }
+    }
+
+    pub fn fire(&mut self, event: &Event) -> Fired {
+        let interpreter = &mut self.interpreter;
+        let world = &mut interpreter.host;
+
+        world.default = event.default.clone();
+        world.roles = event.roles.clone();
+        world.selected.clear();
+        world.cancelled = false;
+
+        // Event values only last while the event runs
+        let previous: Vec<_> = event
+            .values
+            .iter()
+            .map(|(id, value)| (*id, world.values.insert(*id, value.clone())))
+            .collect();
+
+        let handlers: Vec<usize> = (interpreter.module.handlers.iter().enumerate())
+            .filter(|(_, line)| {
+                line.line_type == LineTypeWants2Bits::Event && line.event == Some(event.id)
+            })
+            .map(|(index, _)| index)
+            .collect();
+        let start = interpreter.trace.len();
+        let mut errors = Vec::new();
+
+        for &line in &handlers {
+            if let Err(error) = interpreter.run(line, &mut Locals::new()) {
+                errors.push(error);
+            }
+        }
+
+        let world = &mut interpreter.host;
+
+        for (id, value) in previous {
+            match value {
+                Some(value) => world.values.insert(id, value),
+                None => world.values.remove(&id),
+            };
+        }
+
+        let cancellable = interpreter
+            .catalog
+            .events
+            .get(&event.id)
+            .is_some_and(|event| event.cancellable);
+
+        Fired {
+            event: event.id,
+            handlers,
+            cancelled: cancellable && world.cancelled,
+            trace: interpreter.trace.split_off(start),
+            errors,
+        }
+    }
+
+    /// Fires the events in order.
+    pub fn run(&mut self, events: &[Event]) -> Vec<Fired> {
+        events.iter().map(|event| self.fire(event)).collect()
+    }
+}
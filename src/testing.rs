@@ -0,0 +1,288 @@
+use std::collections::{BTreeMap, HashMap};
+
+use serde::Deserialize;
+
+use crate::{
+    assets::Catalog,
+    diff::common_subsequence,
+    generated::{EventId, GameValueId},
+    host::Entity,
+    interpreter::{Locals, Val},
+    module::{Module, SelectionTypeWants5Bits, Value, VariableScopeWants2Bits},
+    simulate::{Event, Simulation},
+};
+
+/// A file of test cases for one module, written in JSON.
+#[derive(Debug, Deserialize)]
+pub struct TestFile {
+    pub tests: Vec<TestCase>,
+}
+
+/// Variable values are plain JSON: numbers, strings, arrays and objects for maps. Objects
+/// with a `type` are read as module values, e.g. `{"type": "location", ...}`.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TestCase {
+    pub name: String,
+    /// Players spawned into the world, by name.
+    #[serde(default)]
+    pub players: Vec<String>,
+    #[serde(default)]
+    pub locals: BTreeMap<String, serde_json::Value>,
+    #[serde(default)]
+    pub globals: BTreeMap<String, serde_json::Value>,
+    #[serde(default)]
+    pub saved: BTreeMap<String, serde_json::Value>,
+    /// Function or process run with `locals` before any event.
+    pub call: Option<String>,
+    #[serde(default)]
+    pub events: Vec<EventSpec>,
+    #[serde(default)]
+    pub expect: Expect,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct EventSpec {
+    pub event: EventId,
+    /// Name of the player the event is about.
+    pub player: Option<String>,
+    #[serde(default)]
+    pub roles: HashMap<SelectionTypeWants5Bits, String>,
+    #[serde(default)]
+    pub values: HashMap<GameValueId, serde_json::Value>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Expect {
+    #[serde(default)]
+    pub locals: BTreeMap<String, serde_json::Value>,
+    #[serde(default)]
+    pub globals: BTreeMap<String, serde_json::Value>,
+    #[serde(default)]
+    pub saved: BTreeMap<String, serde_json::Value>,
+    /// Host actions of the whole test, as printed by the tracer.
+    pub trace: Option<Vec<String>>,
+    /// Whether each event ended up cancelled.
+    pub cancelled: Option<Vec<bool>>,
+}
+
+/// Runs one case. Returns what didn't match, empty when the case passes.
+pub fn run_case(module: &Module, catalog: &Catalog, case: &TestCase) -> Vec<String> {
+    let mut failures = Vec::new();
+    let mut simulation = Simulation::new(module, catalog);
+
+    for name in &case.players {
+        simulation.interpreter.host.spawn(Entity::player(name));
+    }
+
+    let mut locals = Locals::new();
+
+    for (scope, variables) in [
+        (VariableScopeWants2Bits::Local, &case.locals),
+        (VariableScopeWants2Bits::Global, &case.globals),
+        (VariableScopeWants2Bits::Save, &case.saved),
+    ] {
+        for (name, json) in variables {
+            match to_val(json) {
+                Ok(value) => {
+                    simulation
+                        .interpreter
+                        .set_var(&(scope, name.clone()), Some(value), &mut locals)
+                }
+                Err(message) => failures.push(format!("{name}: {message}")),
+            }
+        }
+    }
+
+    if let Some(name) = &case.call
+        && let Err(error) = simulation.interpreter.run_named(name, &mut locals)
+    {
+        failures.push(error.display(module).to_string());
+    }
+
+    let mut trace = Vec::new();
+    let mut cancelled = Vec::new();
+
+    for spec in &case.events {
+        let mut event = Event::new(spec.event);
+        let world = &simulation.interpreter.host;
+        let uuid = |name: &String| world.find(name).map(|entity| entity.uuid.clone());
+
+        event.default = spec.player.as_ref().and_then(uuid);
+        event.roles = (spec.roles.iter())
+            .filter_map(|(role, name)| Some((*role, uuid(name)?)))
+            .collect();
+
+        for (id, json) in &spec.values {
+            match to_val(json) {
+                Ok(value) => {
+                    event.values.insert(*id, value);
+                }
+                Err(message) => failures.push(format!("event value: {message}")),
+            }
+        }
+
+        let fired = simulation.fire(&event);
+        failures.extend(
+            fired
+                .errors
+                .iter()
+                .map(|error| error.display(module).to_string()),
+        );
+        trace.extend(
+            fired
+                .trace
+                .iter()
+                .map(|traced| traced.display(catalog).to_string()),
+        );
+        cancelled.push(fired.cancelled);
+    }
+
+    let interpreter = &simulation.interpreter;
+
+    for (scope, expected) in [
+        ("local", &case.expect.locals),
+        ("global", &case.expect.globals),
+        ("save", &case.expect.saved),
+    ] {
+        for (name, json) in expected {
+            let actual = match scope {
+                "local" => locals.get(name),
+                "global" => interpreter.globals.get(name),
+                _ => interpreter.saved.get(name),
+            };
+
+            match (to_val(json), actual) {
+                (Ok(expected), Some(actual)) if expected == *actual => {}
+                (Ok(expected), Some(actual)) => {
+                    failures.push(format!("{scope}.{name}: expected {expected}, got {actual}"))
+                }
+                (Ok(expected), None) => {
+                    failures.push(format!("{scope}.{name}: expected {expected}, but unset"))
+                }
+                (Err(message), _) => failures.push(format!("{scope}.{name}: {message}")),
+            }
+        }
+    }
+
+    if let Some(expected) = &case.expect.trace
+        && *expected != trace
+    {
+        failures.push("trace differs:".to_string());
+        failures.extend(trace_diff(expected, &trace));
+    }
+
+    if let Some(expected) = &case.expect.cancelled
+        && *expected != cancelled
+    {
+        failures.push(format!(
+            "cancelled: expected {expected:?}, got {cancelled:?}"
+        ));
+    }
+
+    failures
+}
+
+/// Runs every case, printing a line per case and what failed. Returns whether all passed.
+pub fn run(module: &Module, catalog: &Catalog, file: &TestFile) -> bool {
+    let mut passed = 0;
+
+    for case in &file.tests {
+        let failures = run_case(module, catalog, case);
+
+        if failures.is_empty() {
+            passed += 1;
+            println!("PASS {}", case.name);
+        } else {
+            println!("FAIL {}", case.name);
+
+            for failure in failures {
+                println!("    {failure}");
+            }
+        }
+    }
+
+    println!("{passed}/{} passed", file.tests.len());
+    passed == file.tests.len()
+}
+
+fn trace_diff(expected: &[String], actual: &[String]) -> Vec<String> {
+    let anchors = common_subsequence(expected, actual, String::eq)
+        .into_iter()
+        .chain([(expected.len(), actual.len())]);
+    let (mut i, mut j) = (0, 0);
+    let mut lines = Vec::new();
+
+    for (next_i, next_j) in anchors {
+        lines.extend(expected[i..next_i].iter().map(|line| format!("  - {line}")));
+        lines.extend(actual[j..next_j].iter().map(|line| format!("  + {line}")));
+
+        if let Some(line) = expected.get(next_i) {
+            lines.push(format!("    {line}"));
+        }
+
+        (i, j) = (next_i + 1, next_j + 1);
+    }
+
+    lines
+}
+
+fn to_val(json: &serde_json::Value) -> Result<Val, String> {
+    Ok(match json {
+        serde_json::Value::Number(number) => Val::Number(number.as_f64().unwrap_or_default()),
+        serde_json::Value::String(text) => Val::Text(text.clone()),
+        serde_json::Value::Bool(flag) => {
+            Val::Enum(if *flag { "TRUE" } else { "FALSE" }.to_string())
+        }
+        serde_json::Value::Array(values) => {
+            Val::List(values.iter().map(to_val).collect::<Result<_, _>>()?)
+        }
+        serde_json::Value::Object(object) if object.contains_key("type") => {
+            let value: Value =
+                serde_json::from_value(json.clone()).map_err(|err| err.to_string())?;
+            constant(value)?
+        }
+        serde_json::Value::Object(object) => Val::Map(
+            object
+                .iter()
+                .map(|(key, value)| Ok((Val::Text(key.clone()), to_val(value)?)))
+                .collect::<Result<_, String>>()?,
+        ),
+        serde_json::Value::Null => return Err("null is not a value".to_string()),
+    })
+}
+
+/// Module values that don't depend on variables or the world.
+fn constant(value: Value) -> Result<Val, String> {
+    Ok(match value {
+        Value::Location {
+            x,
+            y,
+            z,
+            yaw,
+            pitch,
+        } => Val::Location {
+            x,
+            y,
+            z,
+            yaw,
+            pitch,
+        },
+        Value::Vector { x, y, z } => Val::Vector { x, y, z },
+        Value::Item { item } => Val::Item {
+            item,
+            amount: 1.0,
+            tags: Vec::new(),
+        },
+        Value::Enum { value } => Val::Enum(value),
+        Value::Variable { .. } | Value::GameValue { .. } | Value::Error { .. } => {
+            return Err("expected a constant value".to_string());
+        }
+        Value::Array { .. } | Value::Number { .. } | Value::Text { .. } => {
+            return Err("write numbers, text and lists as plain JSON".to_string());
+        }
+        other => Val::Other(other),
+    })
+}
@@ -0,0 +1,255 @@
+use std::{collections::HashMap, fmt::Debug};
+
+use heck::ToSnakeCase;
+use serde::Serialize;
+
+use crate::{
+    assets::{Action, Arg, Catalog},
+    diagnostic::Diagnostic,
+    generated::{ActionIdWants11Bits, ArgType, ValueType},
+    module::{Module, Op, Value, walk_ops},
+    variables::Var,
+};
+
+pub fn from_value_type(value_type: ValueType) -> ArgType {
+    match value_type {
+        ValueType::Array => ArgType::Array,
+        ValueType::Item => ArgType::Item,
+        ValueType::Location => ArgType::Location,
+        ValueType::Map => ArgType::Map,
+        ValueType::Number => ArgType::Number,
+        ValueType::Potion => ArgType::Potion,
+        ValueType::Text => ArgType::Text,
+        ValueType::Vector => ArgType::Vector,
+    }
+}
+
+/// The name a catalog enum has in the JSON assets.
+pub fn type_name<T: Serialize + Debug>(value: T) -> String {
+    serde_json::to_value(&value)
+        .ok()
+        .and_then(|name| name.as_str().map(str::to_string))
+        .unwrap_or_else(|| format!("{value:?}"))
+}
+
+/// Inferred types of every variable written in a module. Variables assigned values of
+/// different types are typed as `any`.
+#[derive(Debug, Default)]
+pub struct Types<'m> {
+    pub variables: HashMap<Var<'m>, ArgType>,
+}
+
+impl<'m> Types<'m> {
+    pub fn infer(module: &'m Module, catalog: &Catalog) -> Self {
+        let mut types = Self::default();
+
+        // Assignments may copy other variables, so repeat until nothing new is learned
+        loop {
+            let mut changed = false;
+
+            for line in &module.handlers {
+                walk_ops(&line.operations, &mut |_, op| {
+                    for (var, arg_type) in types.assignments(catalog, op) {
+                        let merged = match types.variables.get(&var) {
+                            None => arg_type,
+                            Some(known) if *known == arg_type => continue,
+                            Some(_) => ArgType::Any,
+                        };
+
+                        if types.variables.insert(var, merged) != Some(merged) {
+                            changed = true;
+                        }
+                    }
+                });
+            }
+
+            if !changed {
+                return types;
+            }
+        }
+    }
+
+    pub fn of(&self, value: &Value, catalog: &Catalog) -> Option<ArgType> {
+        Some(match value {
+            Value::Array { .. } => ArgType::Array,
+            Value::Block { .. } => ArgType::Block,
+            Value::Enum { .. } => ArgType::Enum,
+            Value::Item { .. } => ArgType::Item,
+            Value::Location { .. } => ArgType::Location,
+            Value::Number { .. } => ArgType::Number,
+            Value::Particle { .. } => ArgType::Particle,
+            Value::Potion { .. } => ArgType::Potion,
+            Value::Sound { .. } => ArgType::Sound,
+            Value::Text { .. } => ArgType::Text,
+            Value::Vector { .. } => ArgType::Vector,
+            Value::Variable { variable, scope } => {
+                *self.variables.get(&(variable.as_str(), *scope))?
+            }
+            Value::GameValue { game_value, .. } => {
+                from_value_type(catalog.game_values.get(game_value)?.value_type)
+            }
+            Value::Error { .. } => return None,
+        })
+    }
+
+    fn assignments(&self, catalog: &Catalog, op: &'m Op) -> Vec<(Var<'m>, ArgType)> {
+        let action = catalog.action(op.action);
+
+        action
+            .assign
+            .iter()
+            .chain(&action.lambda)
+            .filter_map(|assign| {
+                let Some(Value::Variable { variable, scope }) = op.value(&assign.id) else {
+                    return None;
+                };
+
+                let arg_type = match assign.arg_type {
+                    ArgType::Any => copied_type(action, op)
+                        .and_then(|value| self.of(value, catalog))
+                        .unwrap_or(ArgType::Any),
+                    arg_type => arg_type,
+                };
+
+                Some(((variable.as_str(), *scope), arg_type))
+            })
+            .collect()
+    }
+}
+
+/// The value an untyped assignment copies, as with `set_variable_value`.
+fn copied_type<'o>(action: &Action, op: &'o Op) -> Option<&'o Value> {
+    let mut inputs = action.args.iter().filter(|arg| !action.assigns(&arg.id));
+
+    match (inputs.next(), inputs.next()) {
+        (Some(arg), None) if arg.arg_type == ArgType::Any => op.value(&arg.id),
+        _ => None,
+    }
+}
+
+/// Describes the problem when `value` can't be passed to `arg`.
+pub fn mismatch(
+    types: &Types,
+    catalog: &Catalog,
+    action: &Action,
+    arg: &Arg,
+    value: &Value,
+) -> Option<String> {
+    let actual = types.of(value, catalog)?;
+
+    if accepts(arg.arg_type, arg.array.is_some(), actual) {
+        return None;
+    }
+
+    let what = match value {
+        Value::Variable { variable, .. } => format!("variable {variable}"),
+        _ => "value".to_string(),
+    };
+
+    Some(format!(
+        "{what} of type {} is passed to {}.{} expecting {}",
+        type_name(actual),
+        action.name,
+        arg.id,
+        type_name(arg.arg_type)
+    ))
+}
+
+fn accepts(expected: ArgType, array: bool, actual: ArgType) -> bool {
+    match (expected, actual) {
+        (ArgType::Any | ArgType::Variable, _) | (_, ArgType::Any) => true,
+        (expected, actual) if expected == actual => true,
+        // Anything can be formatted into text
+        (ArgType::Text, _) => true,
+        // List variables are spread into array arguments
+        (_, ArgType::Array) => array,
+        _ => false,
+    }
+}
+
+/// Reports values whose known type does not match the argument they are passed to.
+pub fn check(module: &Module, catalog: &Catalog) -> Vec<Diagnostic> {
+    let mut types = Types::infer(module, catalog);
+    let mut diagnostics = Vec::new();
+
+    for (index, line) in module.handlers.iter().enumerate() {
+        check_ops(
+            catalog,
+            &mut types,
+            index,
+            &line.operations,
+            &mut Vec::new(),
+            &mut diagnostics,
+        );
+    }
+
+    diagnostics
+}
+
+fn check_ops<'m>(
+    catalog: &Catalog,
+    types: &mut Types<'m>,
+    line: usize,
+    ops: &'m [Op],
+    path: &mut Vec<usize>,
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    for (index, op) in ops.iter().enumerate() {
+        path.push(index);
+        check_op(catalog, types, line, op, path, diagnostics);
+
+        // Inside `if_variable_is_type` the checked variable is known to have that type
+        let narrowed = match (op.action, op.value("value"), op.value("variable_type")) {
+            (
+                ActionIdWants11Bits::IfVariableIsType,
+                Some(Value::Variable { variable, scope }),
+                Some(Value::Enum { value }),
+            ) => serde_json::from_value(value.to_snake_case().into())
+                .ok()
+                .map(|arg_type| {
+                    let var = (variable.as_str(), *scope);
+                    (var, types.variables.insert(var, arg_type))
+                }),
+            _ => None,
+        };
+
+        check_ops(catalog, types, line, &op.operations, path, diagnostics);
+
+        match narrowed {
+            Some((var, Some(previous))) => {
+                types.variables.insert(var, previous);
+            }
+            Some((var, None)) => {
+                types.variables.remove(&var);
+            }
+            None => {}
+        }
+
+        path.pop();
+    }
+}
+
+fn check_op(
+    catalog: &Catalog,
+    types: &Types,
+    line: usize,
+    op: &Op,
+    path: &[usize],
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    for value in &op.values {
+        let action = catalog.arg_owner(op, &value.name);
+        let Some(arg) = action.arg(&value.name) else {
+            continue;
+        };
+
+        // Array literals are checked element by element in `lint::arrays`
+        if matches!(value.value, Value::Array { .. }) && arg.array.is_some() {
+            continue;
+        }
+
+        if let Some(message) = mismatch(types, catalog, action, arg, &value.value) {
+            diagnostics.push(Diagnostic::error(line, path, message));
+        }
+    }
+}
@@ -0,0 +1,305 @@
+use std::{
+    collections::{HashMap, HashSet},
+    ops::Range,
+};
+
+use crate::{
+    assets::Catalog,
+    callgraph::{CallGraph, CallKind},
+    diagnostic::Diagnostic,
+    generated::ArgType,
+    module::{Module, Number, Op, Value, VariableScopeWants2Bits, walk_ops},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AccessKind {
+    Read,
+    Write,
+    /// Variable args without an `assign` entry are changed in place, e.g. `set_variable_append_value`.
+    Modify,
+}
+
+impl AccessKind {
+    pub fn reads(self) -> bool {
+        matches!(self, Self::Read | Self::Modify)
+    }
+
+    pub fn writes(self) -> bool {
+        matches!(self, Self::Write | Self::Modify)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Placeholder<'a> {
+    /// Byte range of the variable name inside the text.
+    pub range: Range<usize>,
+    pub name: &'a str,
+    pub scope: VariableScopeWants2Bits,
+}
+
+/// Finds `%var(..)`, `%var_local(..)` and `%var_save(..)` references in text and `%math` expressions.
+pub fn placeholders(text: &str) -> Vec<Placeholder<'_>> {
+    const PREFIXES: [(&str, VariableScopeWants2Bits); 3] = [
+        ("%var_local(", VariableScopeWants2Bits::Local),
+        ("%var_save(", VariableScopeWants2Bits::Save),
+        ("%var(", VariableScopeWants2Bits::Global),
+    ];
+
+    let mut found = Vec::new();
+    let mut rest = 0;
+
+    while let Some(offset) = text[rest..].find('%') {
+        let start = rest + offset;
+        rest = start + 1;
+
+        let Some((prefix, scope)) = PREFIXES
+            .iter()
+            .find(|(prefix, _)| text[start..].starts_with(prefix))
+        else {
+            continue;
+        };
+
+        let name_start = start + prefix.len();
+        let Some(len) = text[name_start..].find(')') else {
+            break;
+        };
+
+        found.push(Placeholder {
+            range: name_start..name_start + len,
+            name: &text[name_start..name_start + len],
+            scope: *scope,
+        });
+        rest = name_start + len;
+    }
+
+    found
+}
+
+/// Reports every variable the op touches, in argument order.
+pub fn op_accesses<'o>(
+    catalog: &Catalog,
+    op: &'o Op,
+    f: &mut impl FnMut(&'o str, VariableScopeWants2Bits, AccessKind),
+) {
+    for value in &op.values {
+        let action = catalog.arg_owner(op, &value.name);
+        let kind = if action.assigns(&value.name) {
+            AccessKind::Write
+        } else if action
+            .arg(&value.name)
+            .is_some_and(|arg| arg.arg_type == ArgType::Variable)
+        {
+            AccessKind::Modify
+        } else {
+            AccessKind::Read
+        };
+
+        value_accesses(&value.value, kind, f);
+    }
+}
+
+fn value_accesses<'o>(
+    value: &'o Value,
+    kind: AccessKind,
+    f: &mut impl FnMut(&'o str, VariableScopeWants2Bits, AccessKind),
+) {
+    match value {
+        Value::Variable { variable, scope } => f(variable, *scope, kind),
+        Value::Array { values } => {
+            for value in values {
+                value_accesses(value, AccessKind::Read, f);
+            }
+        }
+        Value::Text { text, .. }
+        | Value::Number {
+            number: Number::Calc(text),
+        } => {
+            for placeholder in placeholders(text) {
+                f(placeholder.name, placeholder.scope, AccessKind::Read);
+            }
+        }
+        _ => {}
+    }
+}
+
+pub fn levenshtein(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, ca) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+
+        for (j, cb) in b.iter().enumerate() {
+            let above = row[j + 1];
+            row[j + 1] = if ca == *cb {
+                diagonal
+            } else {
+                1 + diagonal.min(above).min(row[j])
+            };
+            diagonal = above;
+        }
+    }
+
+    row[b.len()]
+}
+
+fn suggest<'a>(name: &str, candidates: impl IntoIterator<Item = &'a str>) -> Option<&'a str> {
+    candidates
+        .into_iter()
+        .filter(|candidate| *candidate != name)
+        .map(|candidate| (levenshtein(name, candidate), candidate))
+        .filter(|(distance, candidate)| {
+            *distance <= 2.min(name.len().min(candidate.len()) / 3)
+                || name.eq_ignore_ascii_case(candidate)
+        })
+        .min()
+        .map(|(_, candidate)| candidate)
+}
+
+pub type Var<'m> = (&'m str, VariableScopeWants2Bits);
+
+/// Reports locals read before being set, locals written but never read and names used in several scopes.
+///
+/// Locals are shared with called functions, so a call counts as setting everything the callee
+/// sets, and a function may read whatever its callers set.
+pub fn analyze(module: &Module, catalog: &Catalog) -> Vec<Diagnostic> {
+    let calls = CallGraph::build(module);
+    let mut diagnostics = Vec::new();
+
+    let mut writes: Vec<HashSet<&str>> = vec![HashSet::new(); module.handlers.len()];
+    let mut first_write: HashMap<Var, (usize, Vec<usize>)> = HashMap::new();
+    let mut first_use: HashMap<Var, (usize, Vec<usize>)> = HashMap::new();
+    let mut read: HashSet<Var> = HashSet::new();
+
+    for (index, line) in module.handlers.iter().enumerate() {
+        walk_ops(&line.operations, &mut |path, op| {
+            op_accesses(catalog, op, &mut |name, scope, kind| {
+                first_use
+                    .entry((name, scope))
+                    .or_insert_with(|| (index, path.to_vec()));
+
+                if kind.reads() {
+                    read.insert((name, scope));
+                }
+
+                if kind.writes() {
+                    first_write
+                        .entry((name, scope))
+                        .or_insert_with(|| (index, path.to_vec()));
+
+                    if scope == VariableScopeWants2Bits::Local {
+                        writes[index].insert(name);
+                    }
+                }
+            });
+        });
+    }
+
+    let sets_through = |line: usize| -> HashSet<&str> {
+        calls
+            .reachable([line])
+            .into_iter()
+            .flat_map(|line| writes[line].iter().copied())
+            .collect()
+    };
+
+    for (index, line) in module.handlers.iter().enumerate() {
+        let mut set: HashSet<&str> = calls
+            .callers(index)
+            .filter(|caller| *caller != index)
+            .flat_map(sets_through)
+            .collect();
+        let mut reported = HashSet::new();
+
+        walk_ops(&line.operations, &mut |path, op| {
+            op_accesses(catalog, op, &mut |name, scope, kind| {
+                if scope != VariableScopeWants2Bits::Local
+                    || !kind.reads()
+                    || set.contains(name)
+                    || !reported.insert(name)
+                {
+                    return;
+                }
+
+                let mut message = format!("local variable {name} is read before being set");
+
+                if let Some(candidate) = suggest(name, writes.iter().flatten().copied()) {
+                    message.push_str(&format!(", did you mean {candidate}?"));
+                }
+
+                diagnostics.push(Diagnostic::warning(index, path, message));
+            });
+
+            op_accesses(catalog, op, &mut |name, scope, kind| {
+                if scope == VariableScopeWants2Bits::Local && kind.writes() {
+                    set.insert(name);
+                }
+            });
+
+            if let Some((kind, arg)) = CallKind::of(op)
+                && let Some(Value::Text { text, .. }) = op.value(arg)
+                && let Some(callee) = calls.find(kind, text)
+            {
+                set.extend(sets_through(callee));
+            }
+        });
+    }
+
+    let mut never_read: Vec<_> = first_write
+        .iter()
+        .filter(|((_, scope), _)| *scope == VariableScopeWants2Bits::Local)
+        .filter(|(var, _)| !read.contains(var))
+        .collect();
+    never_read.sort_by_key(|(_, location)| *location);
+
+    for ((name, scope), (line, path)) in never_read {
+        let mut message = format!("local variable {name} is set but never read");
+
+        if let Some(candidate) = suggest(
+            name,
+            read.iter()
+                .filter(|(_, other)| other == scope)
+                .map(|(name, _)| *name),
+        ) {
+            message.push_str(&format!(", did you mean {candidate}?"));
+        }
+
+        diagnostics.push(Diagnostic::warning(*line, path, message));
+    }
+
+    let mut scopes: HashMap<&str, Vec<VariableScopeWants2Bits>> = HashMap::new();
+
+    for (name, scope) in first_use.keys() {
+        scopes.entry(name).or_default().push(*scope);
+    }
+
+    let mut shadowed: Vec<_> = scopes
+        .into_iter()
+        .filter(|(_, scopes)| scopes.len() > 1)
+        .filter_map(|(name, scopes)| {
+            let location = first_use.get(&(name, VariableScopeWants2Bits::Local))?;
+            Some((location, name, scopes))
+        })
+        .collect();
+    shadowed.sort_by_key(|(location, _, _)| *location);
+
+    for ((line, path), name, scopes) in shadowed {
+        let others: Vec<_> = scopes
+            .iter()
+            .filter(|scope| **scope != VariableScopeWants2Bits::Local)
+            .map(|scope| format!("{scope:?}").to_lowercase())
+            .collect();
+
+        diagnostics.push(Diagnostic::warning(
+            *line,
+            path,
+            format!(
+                "local variable {name} shadows the {} variable of the same name",
+                others.join(" and ")
+            ),
+        ));
+    }
+
+    diagnostics
+}